@@ -9,6 +9,24 @@ use std::fmt::Write;
 use icu_datetime::date::MockDateTime;
 use icu_datetime::DateTimeFormat;
 
+/// Returns a batch of 100,000 date times, a few hours apart.
+#[cfg(feature = "bench")]
+fn get_batch() -> Vec<MockDateTime> {
+    (0..100_000)
+        .map(|idx| {
+            MockDateTime::try_new(
+                2000 + idx / 10_000,
+                (idx % 12) as usize,
+                (idx % 28) as usize,
+                (idx % 24) as usize,
+                (idx % 60) as usize,
+                0,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
 fn datetime_benches(c: &mut Criterion) {
     let fxs = fixtures::get_fixture("styles").unwrap();
 
@@ -71,6 +89,27 @@ fn datetime_benches(c: &mut Criterion) {
             })
         });
 
+        group.bench_function("DateTimeFormat/format_rfc3339_to_write", |b| {
+            // The output doesn't depend on the locale nor the options.
+            let langid = "en".parse().unwrap();
+            let dtf = DateTimeFormat::try_new(langid, &provider, &Default::default()).unwrap();
+            let datetimes: Vec<MockDateTime> = fxs
+                .0
+                .iter()
+                .flat_map(|fx| fx.values.iter())
+                .map(|value| value.parse().unwrap())
+                .collect();
+
+            b.iter(|| {
+                let mut result = String::new();
+
+                for dt in &datetimes {
+                    let _ = dtf.format_rfc3339_to_write(&mut result, dt);
+                    result.clear();
+                }
+            })
+        });
+
         group.bench_function("DateTimeFormat/format_to_string", |b| {
             b.iter(|| {
                 for fx in &fxs.0 {
@@ -93,6 +132,76 @@ fn datetime_benches(c: &mut Criterion) {
             })
         });
 
+        group.bench_function("DateTimeFormat/format_all_into", |b| {
+            let langid = "en".parse().unwrap();
+            let options = Default::default();
+            let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+            let datetimes = get_batch();
+            let mut out = Vec::with_capacity(datetimes.len());
+
+            b.iter(|| dtf.format_all_into(&datetimes, &mut out).unwrap())
+        });
+
+        group.bench_function("DateTimeFormat/format_all_joined_into", |b| {
+            let langid = "en".parse().unwrap();
+            let options = Default::default();
+            let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+            let datetimes = get_batch();
+            let mut out = String::new();
+
+            b.iter(|| {
+                dtf.format_all_joined_into(&datetimes, "\n", &mut out)
+                    .unwrap();
+                out.clear();
+            })
+        });
+
+        // The pattern is parsed once by `try_new` and formatting iterates over its items,
+        // compared with constructing the `DateTimeFormat`, and parsing the pattern, per value.
+        group.bench_function("DateTimeFormat/format_to_write/preparsed", |b| {
+            let langid = "en".parse().unwrap();
+            let options = Default::default();
+            let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+            let datetimes = &get_batch()[..1000];
+            let mut result = String::new();
+
+            b.iter(|| {
+                for dt in datetimes {
+                    let _ = dtf.format_to_write(&mut result, dt);
+                    result.clear();
+                }
+            })
+        });
+
+        group.bench_function("DateTimeFormat/format_to_write/per_call", |b| {
+            let langid: icu_locid::LanguageIdentifier = "en".parse().unwrap();
+            let options = Default::default();
+            let datetimes = &get_batch()[..1000];
+            let mut result = String::new();
+
+            b.iter(|| {
+                for dt in datetimes {
+                    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options).unwrap();
+                    let _ = dtf.format_to_write(&mut result, dt);
+                    result.clear();
+                }
+            })
+        });
+
+        group.bench_function("DateTimeFormat/format_to_string/batch", |b| {
+            let langid = "en".parse().unwrap();
+            let options = Default::default();
+            let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+            let datetimes = get_batch();
+
+            b.iter(|| {
+                datetimes
+                    .iter()
+                    .map(|dt| dtf.format_to_string(dt))
+                    .collect::<Vec<_>>()
+            })
+        });
+
         group.bench_function("FormattedDateTime/format", |b| {
             b.iter(|| {
                 for fx in &fxs.0 {
@@ -0,0 +1,58 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::options::preferences::Calendar;
+use icu_locid::LanguageIdentifier;
+
+// The regions of the CLDR `calendarPreferenceData` whose preferred calendar is supported.
+//
+// Regions not listed here use the Gregorian calendar, the default for the `001` region, as do
+// the regions whose preferred calendar isn't supported yet, such as `IR` with the Persian one.
+const DEFAULTS: &[(&str, Calendar)] = &[("TH", Calendar::Buddhist)];
+
+// The regions of the CLDR `likelySubtags` data for the languages whose likely region has a
+// supported calendar other than the Gregorian one.
+const LIKELY_REGIONS: &[(&str, &str)] = &[("th", "TH")];
+
+/// Returns the calendar used by default in the region of the given `LanguageIdentifier`,
+/// when neither the locale nor the preferences select one.
+///
+/// If the `LanguageIdentifier` has no region, the likely region of its language is used, such
+/// as `TH` for `th`, or the `001` (world) default if it has none.
+pub fn default_calendar(langid: &LanguageIdentifier) -> Calendar {
+    let region = match langid.region {
+        Some(ref region) => region.as_str(),
+        None => match LIKELY_REGIONS
+            .iter()
+            .find(|(language, _)| *language == langid.language.as_str())
+        {
+            Some((_, region)) => region,
+            None => return Calendar::Gregorian,
+        },
+    };
+    DEFAULTS
+        .iter()
+        .find(|(id, _)| *id == region)
+        .map_or(Calendar::Gregorian, |(_, calendar)| *calendar)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_calendar() {
+        let samples = &[
+            ("th-TH", Calendar::Buddhist),
+            ("en-TH", Calendar::Buddhist),
+            ("th", Calendar::Buddhist),
+            ("th-US", Calendar::Gregorian),
+            ("en-US", Calendar::Gregorian),
+            ("fa-IR", Calendar::Gregorian),
+        ];
+        for (langid, expected) in samples {
+            let langid: LanguageIdentifier = langid.parse().unwrap();
+            assert_eq!(default_calendar(&langid), *expected, "{}", langid);
+        }
+    }
+}
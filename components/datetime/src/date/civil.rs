@@ -0,0 +1,118 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+//! Conversions between proleptic Gregorian dates and day counts, using Howard Hinnant's
+//! [`chrono`-compatible algorithms](http://howardhinnant.github.io/date_algorithms.html).
+//!
+//! The days are counted from 1970-01-01, which is day `0`. The algorithms count in 400-year
+//! eras which start on March 1st, so that the leap day is the last day of an era's year.
+
+/// The number of days in a 400-year era.
+const DAYS_IN_ERA: i64 = 146_097;
+
+/// The number of days from 0000-03-01, the start of an era, to 1970-01-01.
+const EPOCH_OFFSET: i64 = 719_468;
+
+/// Returns the number of days from 1970-01-01 to the one-based `month` and `day` of
+/// the `year`.
+pub fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    debug_assert!((1..=12).contains(&month) && (1..=31).contains(&day));
+    let (year, month) = if month <= 2 {
+        (year - 1, i64::from(month) + 9)
+    } else {
+        (year, i64::from(month) - 3)
+    };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * month + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * DAYS_IN_ERA + day_of_era - EPOCH_OFFSET
+}
+
+/// Returns the year and the one-based month and day of the date `days` after 1970-01-01,
+/// the inverse of [`days_from_civil`].
+pub fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + EPOCH_OFFSET;
+    let era = days.div_euclid(DAYS_IN_ERA);
+    let day_of_era = days.rem_euclid(DAYS_IN_ERA);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // The month, where March is `0`.
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let year = year_of_era + era * 400;
+    if month < 10 {
+        (year, (month + 3) as u8, day as u8)
+    } else {
+        (year + 1, (month - 9) as u8, day as u8)
+    }
+}
+
+/// Returns the day of the week of the date `days` after 1970-01-01, where Sunday is `0`.
+pub fn weekday_from_days(days: i64) -> u8 {
+    // 1970-01-01 is a Thursday.
+    (days + 4).rem_euclid(7) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_dates() {
+        let samples = &[
+            ((1970, 1, 1), 0),
+            ((1969, 12, 31), -1),
+            ((1970, 3, 1), 59),
+            ((2000, 1, 1), 10_957),
+            ((2000, 2, 29), 11_016),
+            ((2000, 3, 1), 11_017),
+            ((2020, 10, 14), 18_549),
+            ((2038, 1, 19), 24_855),
+            ((1900, 3, 1), -25_508),
+            ((1600, 1, 1), -135_140),
+            ((0, 3, 1), -719_468),
+            ((0, 1, 1), -719_528),
+            ((-1, 12, 31), -719_529),
+            ((-44, 3, 15), -735_525),
+            ((9999, 12, 31), 2_932_896),
+        ];
+        for ((year, month, day), days) in samples {
+            assert_eq!(days_from_civil(*year, *month, *day), *days, "{}", days);
+            assert_eq!(civil_from_days(*days), (*year, *month, *day), "{}", days);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut previous = civil_from_days(-800_000);
+        for days in -799_999..=3_000_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+            // Each day follows the previous one.
+            let (previous_year, previous_month, previous_day) = previous;
+            if day == 1 {
+                assert!(
+                    (month == 1 && previous_month == 12 && year == previous_year + 1)
+                        || (month == previous_month + 1 && year == previous_year)
+                );
+            } else {
+                assert_eq!(
+                    (year, month, day - 1),
+                    (previous_year, previous_month, previous_day)
+                );
+            }
+            previous = (year, month, day);
+        }
+    }
+
+    #[test]
+    fn test_weekday_from_days() {
+        // Thursday, Wednesday, Saturday and Sunday.
+        assert_eq!(weekday_from_days(0), 4);
+        assert_eq!(weekday_from_days(18_549), 3);
+        assert_eq!(weekday_from_days(10_957), 6);
+        assert_eq!(weekday_from_days(-4), 0);
+    }
+}
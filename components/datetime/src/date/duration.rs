@@ -0,0 +1,220 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use super::{civil, days_in_month, DateTimeError, Day, Hour, Minute, MockDateTime, Month, Second};
+use std::convert::TryFrom;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// A span of time in the ISO 8601 duration format, such as `P1DT2H`, which is subtracted
+/// when it is negative, such as `-PT30M`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{Duration, MockDateTime};
+///
+/// let duration: Duration = "-PT1H".parse()
+///     .expect("Failed to parse a duration.");
+/// assert!(duration.negative);
+/// assert_eq!(duration.hours, 1);
+///
+/// let dt: MockDateTime = "2020-10-14T00:30:00".parse()
+///     .expect("Failed to parse a date time.");
+/// assert_eq!(dt + duration, "2020-10-13T23:30:00".parse().unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Duration {
+    /// Whether the duration is subtracted rather than added.
+    pub negative: bool,
+    pub years: u32,
+    pub months: u32,
+    pub weeks: u32,
+    pub days: u32,
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl FromStr for Duration {
+    type Err = DateTimeError;
+
+    /// Parse a `Duration` from a string.
+    ///
+    /// The string must be an optional sign followed by `P`, the years, months, weeks and
+    /// days, then `T` and the hours, minutes and seconds, each written as a whole number
+    /// followed by its designator, such as `P1Y2M3W4DT5H6M7S`. The components which are
+    /// zero may be left out, but at least one of them must be present.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (negative, rest) = match input.as_bytes().first() {
+            Some(b'-') => (true, &input[1..]),
+            Some(b'+') => (false, &input[1..]),
+            _ => (false, input),
+        };
+        let rest = rest.strip_prefix('P').ok_or(DateTimeError::InvalidFormat)?;
+        let (date, time) = match rest.find('T') {
+            Some(idx) => (&rest[..idx], &rest[idx + 1..]),
+            None => (rest, ""),
+        };
+        // The `T` must be followed by a component, and the duration must have one.
+        if (time.is_empty() && rest.contains('T')) || (date.is_empty() && time.is_empty()) {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let date = parse_components(date, b"YMWD")?;
+        let time = parse_components(time, b"HMS")?;
+        Ok(Self {
+            negative,
+            years: date[0],
+            months: date[1],
+            weeks: date[2],
+            days: date[3],
+            hours: time[0],
+            minutes: time[1],
+            seconds: time[2],
+        })
+    }
+}
+
+/// Parses the numbers followed by the `designators`, which must appear in order, and
+/// returns the value for each of them, which is zero if it is left out.
+fn parse_components(input: &str, designators: &[u8]) -> Result<Vec<u32>, DateTimeError> {
+    let mut values = vec![0; designators.len()];
+    // The index of the first designator which may still appear.
+    let mut next = 0;
+    let mut rest = input;
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let designator = match rest.as_bytes().get(digits) {
+            Some(designator) if digits > 0 => designator,
+            _ => return Err(DateTimeError::InvalidFormat),
+        };
+        let idx = designators[next..]
+            .iter()
+            .position(|d| d == designator)
+            .ok_or(DateTimeError::InvalidFormat)?
+            + next;
+        values[idx] = rest[..digits].parse()?;
+        next = idx + 1;
+        rest = &rest[digits + 1..];
+    }
+    Ok(values)
+}
+
+impl Add<Duration> for MockDateTime {
+    type Output = Self;
+
+    /// Moves the date time forward by the `duration`, or backward if it is negative.
+    ///
+    /// The years and months are applied first, keeping the day of the month unless the
+    /// resulting month is too short, in which case its last day is used. The other components
+    /// are then applied as a fixed number of seconds, ignoring leap seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year is out of the range of `i32`.
+    fn add(self, duration: Duration) -> Self {
+        let sign = if duration.negative { -1 } else { 1 };
+
+        let months = i64::from(self.year) * 12
+            + i64::from(u8::from(self.month))
+            + sign * (i64::from(duration.years) * 12 + i64::from(duration.months));
+        let year = i32::try_from(months.div_euclid(12)).expect("The year is out of range.");
+        let month = Month::new_unchecked(months.rem_euclid(12) as u8);
+        let day = Day::new_unchecked(u8::from(self.day).min(days_in_month(year, month) - 1));
+        let moved = Self {
+            year,
+            month,
+            day,
+            ..self
+        };
+
+        let days = i64::from(duration.weeks) * 7 + i64::from(duration.days);
+        let hours = days * 24 + i64::from(duration.hours);
+        let minutes = hours * 60 + i64::from(duration.minutes);
+        let seconds =
+            moved.seconds_since_epoch() + sign * (minutes * 60 + i64::from(duration.seconds));
+        let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+        let (year, month, day) = civil::civil_from_days(days);
+        Self {
+            year: i32::try_from(year).expect("The year is out of range."),
+            month: Month::new_unchecked(month - 1),
+            day: Day::new_unchecked(day - 1),
+            hour: Hour::new_unchecked((seconds / 3600) as u8),
+            minute: Minute::new_unchecked((seconds / 60 % 60) as u8),
+            second: Second::new_unchecked((seconds % 60) as u8),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let duration: Duration = "P1Y2M3W4DT5H6M7S".parse().unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                negative: false,
+                years: 1,
+                months: 2,
+                weeks: 3,
+                days: 4,
+                hours: 5,
+                minutes: 6,
+                seconds: 7,
+            }
+        );
+
+        let duration: Duration = "-P1D".parse().unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                negative: true,
+                days: 1,
+                ..Default::default()
+            }
+        );
+
+        let duration: Duration = "+PT1M".parse().unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                minutes: 1,
+                ..Default::default()
+            }
+        );
+
+        for input in &[
+            "", "P", "-P", "PT", "P1DT", "1D", "P1", "PD", "P1D1Y", "P1H", "--P1D",
+        ] {
+            assert!(input.parse::<Duration>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        let samples = &[
+            ("2020-10-14T13:21:00", "-P1D", "2020-10-13T13:21:00"),
+            ("2020-03-01T13:21:00", "-P1D", "2020-02-29T13:21:00"),
+            ("2020-10-14T00:30:00", "-PT1H", "2020-10-13T23:30:00"),
+            ("2021-01-01T00:00:00", "-PT1S", "2020-12-31T23:59:59"),
+            ("2020-10-13T23:30:00", "PT1H", "2020-10-14T00:30:00"),
+            ("2020-01-31T00:00:00", "P1M", "2020-02-29T00:00:00"),
+            ("2020-03-31T00:00:00", "-P1M1D", "2020-02-28T00:00:00"),
+            ("2020-02-29T12:00:00", "P1Y1W", "2021-03-07T12:00:00"),
+        ];
+        for (input, duration, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let duration: Duration = duration.parse().unwrap();
+            assert_eq!(
+                dt + duration,
+                expected.parse().unwrap(),
+                "{} {:?}",
+                input,
+                duration
+            );
+        }
+    }
+}
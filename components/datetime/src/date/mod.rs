@@ -0,0 +1,3597 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+//! APIs for Date and Time handling
+pub(crate) mod civil;
+mod duration;
+mod offset;
+mod time_zone;
+mod zoned;
+
+pub use duration::Duration;
+pub use offset::GmtOffset;
+pub use time_zone::{
+    contains_transition, is_local_time_representable, FixedOffsetProvider, FixedTransitionRules,
+    OffsetProvider, RecurringTransition, Transition, TransitionRules,
+};
+pub use zoned::MockZonedDateTime;
+
+use crate::fields;
+use crate::pattern;
+use icu_locid::LanguageIdentifier;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::ops::{Add, Range, RangeInclusive, Sub};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum DateTimeError {
+    Parse(std::num::ParseIntError),
+    Overflow {
+        field: &'static str,
+        max: usize,
+    },
+    /// The input does not match the expected format.
+    InvalidFormat,
+    /// The pattern used for parsing is invalid.
+    Pattern(pattern::Error),
+    /// None of the patterns matched the input. Contains the error for each of the patterns.
+    NoMatchingPattern(Vec<(String, DateTimeError)>),
+    /// The GMT offset, in seconds, is outside of the supported range.
+    InvalidOffset(i32),
+    /// The input has an unexpected character where a separator was expected.
+    InvalidSeparator {
+        position: usize,
+        expected: char,
+    },
+    /// The weekday given in the input is not the weekday of the date.
+    WeekdayMismatch {
+        expected: WeekDay,
+        found: WeekDay,
+    },
+    /// The Unix timestamp is not finite, or too large to be represented.
+    InvalidTimestamp(f64),
+    /// The pattern has a field which the calendar doesn't define.
+    UnsupportedField(fields::FieldSymbol),
+    /// The input starts with the name of several values, such as the narrow month name `J`
+    /// of January, June and July.
+    AmbiguousSymbol(String),
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse(err) => write!(f, "{}", err),
+            Self::Overflow { field, max } => write!(f, "{} must be between 0-{}", field, max),
+            Self::InvalidFormat => write!(f, "input does not match the expected format"),
+            Self::Pattern(err) => write!(f, "invalid pattern: {}", err),
+            Self::NoMatchingPattern(errors) => {
+                write!(f, "no pattern matched the input")?;
+                for (pattern, err) in errors {
+                    write!(f, "; `{}`: {}", pattern, err)?;
+                }
+                Ok(())
+            }
+            Self::InvalidOffset(seconds) => write!(
+                f,
+                "GMT offset of {} seconds is outside of the -18:00 to +18:00 range",
+                seconds
+            ),
+            Self::InvalidSeparator { position, expected } => {
+                write!(f, "expected `{}` at position {}", expected, position)
+            }
+            Self::WeekdayMismatch { expected, found } => write!(
+                f,
+                "weekday {} does not match the date, expected {}",
+                u8::from(*found),
+                u8::from(*expected)
+            ),
+            Self::InvalidTimestamp(timestamp) => {
+                write!(f, "invalid Unix timestamp: {}", timestamp)
+            }
+            Self::UnsupportedField(symbol) => {
+                write!(f, "the calendar doesn't define the {:?} field", symbol)
+            }
+            Self::AmbiguousSymbol(symbol) => {
+                write!(f, "`{}` is the name of several values", symbol)
+            }
+        }
+    }
+}
+
+impl From<std::num::ParseIntError> for DateTimeError {
+    fn from(input: std::num::ParseIntError) -> Self {
+        Self::Parse(input)
+    }
+}
+
+impl From<pattern::Error> for DateTimeError {
+    fn from(input: pattern::Error) -> Self {
+        Self::Pattern(input)
+    }
+}
+
+/// Temporary trait used to represent the input data for [`DateTimeFormat`].
+///
+/// This type represents all data that the formatted needs in order to produced formatted string.
+///
+/// *Note*: At the moment we support only `gregorian` calendar, and plan to extend support to
+/// other calendars in the upcoming releases. See <https://github.com/unicode-org/icu4x/issues/355>
+///
+/// [`DateTimeFormat`]: super::DateTimeFormat
+pub trait DateTimeType: FromStr {
+    fn year(&self) -> i32;
+    fn month(&self) -> Month;
+    fn day(&self) -> Day;
+    fn hour(&self) -> Hour;
+    fn minute(&self) -> Minute;
+    fn second(&self) -> Second;
+
+    /// Returns the offset of the value from GMT, or `None` if the value is not associated
+    /// with an offset.
+    fn gmt_offset(&self) -> Option<GmtOffset> {
+        None
+    }
+}
+
+/// Temporary implementation of [`DateTimeType`],
+/// which is used in tests, benchmarks and examples of this component.
+///
+/// *Notice:* Rust at the moment does not have a canonical way to represent date and time. We are introducing
+/// `MockDateTime` as an example of the data necessary for ICU [`DateTimeFormat`] to work, and
+/// [we hope to work with the community](https://github.com/unicode-org/icu4x/blob/master/docs/research/date_time.md)
+/// to develop core date and time APIs that will work as an input for this component.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::MockDateTime;
+///
+/// let dt1 = MockDateTime::try_new(2020, 9, 24, 13, 21, 0)
+///     .expect("Failed to construct DateTime.");
+///
+/// let dt2: MockDateTime = "2020-10-14T13:21:00".parse()
+///     .expect("Failed to parse a date time.");
+/// ```
+///
+/// Since a `MockDateTime` has no offset, it is ordered by its wall-clock fields. Use
+/// [`MockZonedDateTime::cmp_instant`] to compare date times with different offsets.
+///
+/// [`DateTimeFormat`]: super::DateTimeFormat
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MockDateTime {
+    /// The year in the astronomical numbering of ISO 8601, where `0` is 1 BC and `-43` is
+    /// 44 BC.
+    pub year: i32,
+    pub month: Month,
+    pub day: Day,
+    pub hour: Hour,
+    pub minute: Minute,
+    pub second: Second,
+}
+
+impl MockDateTime {
+    /// The default maximum length in bytes of the inputs of [`MockDateTime::parse_lenient`],
+    /// which is far above the length of any date time it accepts without leading zeros.
+    pub const DEFAULT_MAX_LENIENT_LEN: usize = 64;
+
+    /// Creates a new `MockDateTime` from a list of already validated date/time parameters.
+    pub const fn new(
+        year: i32,
+        month: Month,
+        day: Day,
+        hour: Hour,
+        minute: Minute,
+        second: Second,
+    ) -> Self {
+        Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Constructor for the `MockDateTime`.
+    ///
+    /// The `month` and the `day` are zero-based, like the stored [`Month`] and [`Day`],
+    /// so `try_new(2020, 9, 24, ...)` is October 25th. Use [`MockDateTime::try_new_iso`]
+    /// to pass them one-based, as they are written in ISO 8601.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::try_new(2020, 9, 24, 13, 21, 0)
+    ///     .expect("Failed to construct a DateTime");
+    /// assert_eq!(dt, "2020-10-25T13:21:00".parse().unwrap());
+    /// ```
+    pub fn try_new(
+        year: i32,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+    ) -> Result<Self, DateTimeError> {
+        Ok(Self {
+            year,
+            month: month.try_into()?,
+            day: day.try_into()?,
+            hour: hour.try_into()?,
+            minute: minute.try_into()?,
+            second: second.try_into()?,
+        })
+    }
+
+    /// Constructor for the `MockDateTime` taking a one-based `month` and `day`, such as
+    /// `try_new_iso(2020, 1, 1, ...)` for January 1st.
+    ///
+    /// Unlike [`MockDateTime::try_new`], the day is also validated against the length
+    /// of the month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::try_new_iso(2020, 10, 25, 13, 21, 0)
+    ///     .expect("Failed to construct a DateTime");
+    /// assert_eq!(dt, "2020-10-25T13:21:00".parse().unwrap());
+    ///
+    /// assert!(MockDateTime::try_new_iso(2021, 2, 29, 0, 0, 0).is_err());
+    /// ```
+    pub fn try_new_iso(
+        year: i32,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+    ) -> Result<Self, DateTimeError> {
+        let month: Month = match month.checked_sub(1) {
+            Some(month) if month < 12 => month.try_into()?,
+            _ => {
+                return Err(DateTimeError::Overflow {
+                    field: "Month",
+                    max: 12,
+                })
+            }
+        };
+        let days = days_in_month(year, month).into();
+        let day = match day.checked_sub(1) {
+            Some(day) if day < days => day,
+            _ => {
+                return Err(DateTimeError::Overflow {
+                    field: "Day",
+                    max: days,
+                })
+            }
+        };
+        Self::try_new(year, month.into(), day, hour, minute, second)
+    }
+
+    /// Creates a new `MockDateTime` at midnight from a year and a one-based day of the year,
+    /// such as `60` for February 29th in a leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_ordinal(2020, 60)
+    ///     .expect("Failed to construct a DateTime.");
+    /// assert_eq!(dt, "2020-02-29T00:00:00".parse().unwrap());
+    /// ```
+    pub fn from_ordinal(year: i32, day_of_year: u16) -> Result<Self, DateTimeError> {
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if day_of_year == 0 || day_of_year > days_in_year {
+            return Err(DateTimeError::Overflow {
+                field: "DayOfYear",
+                max: days_in_year.into(),
+            });
+        }
+        let mut day = day_of_year - 1;
+        let mut month = Month::new_unchecked(0);
+        loop {
+            let days = u16::from(days_in_month(year, month));
+            if day < days {
+                break;
+            }
+            day -= days;
+            month = month + 1;
+        }
+        Ok(Self {
+            year,
+            month,
+            day: Day::new_unchecked(day as u8),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new `MockDateTime` in UTC from a Unix timestamp, the number of seconds since
+    /// 1970-01-01T00:00:00Z, which may have a fractional part, such as `1602681660.25`.
+    ///
+    /// Since `MockDateTime` stores whole seconds, the fractional part is dropped, rounding
+    /// toward the past, so `-0.5` is one second before the epoch. Timestamps which are not
+    /// finite, or larger in magnitude than 2<sup>53</sup>, are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_unix_timestamp_f64(1602681660.25)
+    ///     .expect("Failed to construct a DateTime.");
+    /// assert_eq!(dt, "2020-10-14T13:21:00".parse().unwrap());
+    ///
+    /// assert!(MockDateTime::from_unix_timestamp_f64(f64::NAN).is_err());
+    /// ```
+    pub fn from_unix_timestamp_f64(timestamp: f64) -> Result<Self, DateTimeError> {
+        // The largest magnitude at which every whole number of seconds is representable.
+        const MAX_TIMESTAMP: f64 = 9_007_199_254_740_992.0;
+
+        if !timestamp.is_finite() || timestamp.abs() > MAX_TIMESTAMP {
+            return Err(DateTimeError::InvalidTimestamp(timestamp));
+        }
+        let seconds = timestamp.floor() as i64;
+        let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+        let (year, month, day) = civil::civil_from_days(days);
+
+        let year = i32::try_from(year).map_err(|_| DateTimeError::InvalidTimestamp(timestamp))?;
+        Ok(Self {
+            year,
+            month: Month::new_unchecked(month - 1),
+            day: Day::new_unchecked(day - 1),
+            hour: Hour::new_unchecked((seconds / 3600) as u8),
+            minute: Minute::new_unchecked((seconds / 60 % 60) as u8),
+            second: Second::new_unchecked((seconds % 60) as u8),
+        })
+    }
+
+    /// Returns a copy of this `MockDateTime` with each of the `Some` fields replaced.
+    ///
+    /// The fields are validated the same way as in [`MockDateTime::try_new`], and the
+    /// first invalid one is returned as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let dt = dt.with_fields(None, Some(11), Some(24), None, None, None)
+    ///     .expect("Failed to update a date time.");
+    /// assert_eq!(dt, "2020-12-25T13:21:00".parse().unwrap());
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_fields(
+        self,
+        year: Option<i32>,
+        month: Option<usize>,
+        day: Option<usize>,
+        hour: Option<usize>,
+        minute: Option<usize>,
+        second: Option<usize>,
+    ) -> Result<Self, DateTimeError> {
+        Ok(Self {
+            year: year.unwrap_or(self.year),
+            month: month.map_or(Ok(self.month), TryInto::try_into)?,
+            day: day.map_or(Ok(self.day), TryInto::try_into)?,
+            hour: hour.map_or(Ok(self.hour), TryInto::try_into)?,
+            minute: minute.map_or(Ok(self.minute), TryInto::try_into)?,
+            second: second.map_or(Ok(self.second), TryInto::try_into)?,
+        })
+    }
+
+    /// Parses the input using each of the patterns in order, and returns the result of the
+    /// first one that matches.
+    ///
+    /// The patterns use the [`UTS #35`] date field symbols. Since no locale data is involved,
+    /// only numeric fields are supported.
+    ///
+    /// If none of the patterns match, the returned [`DateTimeError::NoMatchingPattern`] lists
+    /// the error encountered for each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let patterns = &["y-MM-dd", "MM/dd/y"];
+    ///
+    /// let dt1 = MockDateTime::parse_any("2020-10-14", patterns)
+    ///     .expect("Failed to parse a date time.");
+    /// let dt2 = MockDateTime::parse_any("10/14/2020", patterns)
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt1, dt2);
+    ///
+    /// assert!(MockDateTime::parse_any("14.10.2020", patterns).is_err());
+    /// ```
+    ///
+    /// [`UTS #35`]: https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
+    pub fn parse_any(input: &str, patterns: &[&str]) -> Result<Self, DateTimeError> {
+        let mut errors = vec![];
+        for pattern in patterns {
+            let result = pattern::Pattern::from_bytes(pattern)
+                .map_err(DateTimeError::from)
+                .and_then(|pattern| crate::parse::parse_pattern(&pattern, None, input));
+            match result {
+                Ok(date_time) => return Ok(date_time),
+                Err(err) => errors.push((pattern.to_string(), err)),
+            }
+        }
+        Err(DateTimeError::NoMatchingPattern(errors))
+    }
+
+    /// Returns the day of the week as the `tm_wday` field of C's `struct tm`, from `0` for
+    /// Sunday to `6` for Saturday, regardless of the first day of the week of any locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// // Wednesday, October 14th, 2020.
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.tm_wday(), 3);
+    /// ```
+    pub fn tm_wday(&self) -> u8 {
+        u8::from(self.weekday())
+    }
+
+    /// Returns the zero-based day of the year as the `tm_yday` field of C's `struct tm`,
+    /// from `0` for January 1st to `365` for December 31st of a leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.tm_yday(), 287);
+    /// ```
+    pub fn tm_yday(&self) -> u16 {
+        (0..u8::from(self.month))
+            .map(|month| u16::from(days_in_month(self.year, Month::new_unchecked(month))))
+            .sum::<u16>()
+            + u16::from(u8::from(self.day))
+    }
+
+    /// Returns whether the year of this date is a leap year, along with its number of days.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, YearInfo};
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.year_info(), YearInfo { is_leap: true, days: 366 });
+    /// ```
+    pub fn year_info(&self) -> YearInfo {
+        let is_leap = is_leap_year(self.year);
+        YearInfo {
+            is_leap,
+            days: if is_leap { 366 } else { 365 },
+        }
+    }
+
+    /// Returns the number of seconds since the midnight starting the day of this date time,
+    /// from `0` to `86399`, or `86400` for a leap second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.seconds_of_day(), 48060);
+    /// ```
+    pub fn seconds_of_day(&self) -> u32 {
+        u32::from(u8::from(self.hour)) * 3600
+            + u32::from(u8::from(self.minute)) * 60
+            + u32::from(u8::from(self.second))
+    }
+
+    /// Returns the number of seconds from this date time to the next occurrence of the
+    /// wall-clock time `hour:minute:second`, which is later today if the time is still
+    /// ahead, or tomorrow otherwise, including when it is the current time.
+    ///
+    /// The days are assumed to last `86400` seconds, and a leap second is counted as the
+    /// last second of its day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{Hour, Minute, MockDateTime, Second};
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let seconds = dt.seconds_until_time(
+    ///     Hour::new_unchecked(14),
+    ///     Minute::new_unchecked(0),
+    ///     Second::new_unchecked(0),
+    /// );
+    /// assert_eq!(seconds, 2340);
+    /// ```
+    pub fn seconds_until_time(&self, hour: Hour, minute: Minute, second: Second) -> u32 {
+        let now = self.seconds_of_day().min(86399);
+        let target = u32::from(u8::from(hour)) * 3600
+            + u32::from(u8::from(minute)) * 60
+            + u32::from(u8::from(second));
+        if target > now {
+            target - now
+        } else {
+            86400 - now + target
+        }
+    }
+
+    /// Returns the Julian Date of this date time, the number of days since noon UTC on
+    /// November 24th, 4714 BC in the proleptic Gregorian calendar, with the time of day
+    /// as the fraction.
+    ///
+    /// The date time is taken as UTC. Julian days start at noon, so the Julian Day Number of
+    /// a date applies from its noon, and its midnight is half a day earlier, such as
+    /// `2451544.5` for `2000-01-01T00:00:00`. A leap second is counted as the start of the
+    /// next day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2000-01-01T12:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.julian_date(), 2451545.0);
+    /// ```
+    pub fn julian_date(&self) -> f64 {
+        // The Julian Date of 1970-01-01T00:00:00.
+        const UNIX_EPOCH: f64 = 2_440_587.5;
+
+        UNIX_EPOCH + self.days_since_epoch() as f64 + f64::from(self.seconds_of_day()) / 86400.0
+    }
+
+    /// Returns a hash of this date time which, unlike the ones of the `Hasher`s of the
+    /// standard library, is the same in every run and on every platform.
+    ///
+    /// The hash is the 64-bit FNV-1a of the year as a big-endian `i32`, followed by the
+    /// zero-based month and day, the hour, the minute and the second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:05".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.stable_hash(), 0xfe37_b461_3ff1_dfd1);
+    /// ```
+    pub fn stable_hash(&self) -> u64 {
+        const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let fields = [
+            u8::from(self.month),
+            u8::from(self.day),
+            u8::from(self.hour),
+            u8::from(self.minute),
+            u8::from(self.second),
+        ];
+        self.year
+            .to_be_bytes()
+            .iter()
+            .chain(fields.iter())
+            .fold(FNV_OFFSET_BASIS, |hash, byte| {
+                (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+            })
+    }
+
+    /// Maps this date time to one of `shards` shards, from `0` to `shards - 1`, with its
+    /// [`MockDateTime::stable_hash`], so that a date time is always assigned to the same
+    /// shard.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:05".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.shard_hash(16), 1);
+    /// ```
+    pub fn shard_hash(&self, shards: u32) -> u32 {
+        (self.stable_hash() % u64::from(shards)) as u32
+    }
+
+    /// Returns `true` if this date falls on the weekend in the region of the given
+    /// `LanguageIdentifier`, such as Saturday and Sunday in `en-US` or Friday and Saturday
+    /// in `ar-EG`.
+    ///
+    /// The weekend of a `LanguageIdentifier` without a region is Saturday and Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    /// use icu_locid_macros::langid;
+    ///
+    /// // Friday, October 16th, 2020.
+    /// let dt: MockDateTime = "2020-10-16T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert!(!dt.is_weekend(&langid!("en-US")));
+    /// assert!(dt.is_weekend(&langid!("ar-EG")));
+    /// ```
+    pub fn is_weekend(&self, langid: &LanguageIdentifier) -> bool {
+        crate::week_data::is_weekend(langid, self.weekday())
+    }
+
+    /// Returns the nearest date strictly after this one which falls on the `target`
+    /// day of the week, keeping the same time of day.
+    ///
+    /// If this date already falls on the `target` day, the result is a week later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, WeekDay};
+    ///
+    /// // Wednesday, October 14th, 2020.
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let monday: MockDateTime = "2020-10-19T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.next_weekday(WeekDay::new_unchecked(1)), monday);
+    /// ```
+    pub fn next_weekday(&self, target: WeekDay) -> Self {
+        let mut days = (u8::from(target) + 7 - u8::from(self.weekday())) % 7;
+        if days == 0 {
+            days = 7;
+        }
+        let mut result = self.clone();
+        for _ in 0..days {
+            result.increment_day();
+        }
+        result
+    }
+
+    /// Returns the nearest date strictly before this one which falls on the `target`
+    /// day of the week, keeping the same time of day.
+    ///
+    /// If this date already falls on the `target` day, the result is a week earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, WeekDay};
+    ///
+    /// // Wednesday, October 14th, 2020.
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let monday: MockDateTime = "2020-10-12T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.previous_weekday(WeekDay::new_unchecked(1)), monday);
+    /// ```
+    pub fn previous_weekday(&self, target: WeekDay) -> Self {
+        let mut days = (u8::from(self.weekday()) + 7 - u8::from(target)) % 7;
+        if days == 0 {
+            days = 7;
+        }
+        let mut result = self.clone();
+        for _ in 0..days {
+            result.decrement_day();
+        }
+        result
+    }
+
+    /// Returns the midnight of the most recent date, up to this one, which falls on the
+    /// `start_of_week`, the start of the week containing this date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, WeekDay};
+    ///
+    /// // Wednesday, October 14th, 2020.
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let monday: MockDateTime = "2020-10-12T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.floor_to_weekday(WeekDay::new_unchecked(1)), monday);
+    /// ```
+    pub fn floor_to_weekday(&self, start_of_week: WeekDay) -> Self {
+        let days = (u8::from(self.weekday()) + 7 - u8::from(start_of_week)) % 7;
+        let mut result = Self {
+            hour: Hour::new_unchecked(0),
+            minute: Minute::new_unchecked(0),
+            second: Second::new_unchecked(0),
+            ..self.clone()
+        };
+        for _ in 0..days {
+            result.decrement_day();
+        }
+        result
+    }
+
+    /// Returns the number of days from the date of this date time to the date of the `other`
+    /// one, ignoring the time of day, which is negative if the `other` one is earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let start: MockDateTime = "2020-10-14T23:59:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let end: MockDateTime = "2020-10-15T00:01:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(start.days_between(&end), 1);
+    /// assert_eq!(end.days_between(&start), -1);
+    /// ```
+    pub fn days_between(&self, other: &Self) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Returns the season of the date in the `hemisphere`, where the seasons of the Southern
+    /// hemisphere are the opposite of those of the Northern one.
+    ///
+    /// The astronomical seasons start on March 20th, June 21st, September 22nd and
+    /// December 21st, ignoring the variations of the equinoxes and solstices across years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{Hemisphere, MockDateTime, Season, SeasonKind};
+    ///
+    /// let dt: MockDateTime = "2020-12-10T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let kind = SeasonKind::default();
+    /// assert_eq!(dt.season(Hemisphere::Northern, kind), Season::Winter);
+    /// assert_eq!(dt.season(Hemisphere::Southern, kind), Season::Summer);
+    /// assert_eq!(dt.season(Hemisphere::Northern, SeasonKind::Astronomical), Season::Autumn);
+    /// ```
+    pub fn season(&self, hemisphere: Hemisphere, kind: SeasonKind) -> Season {
+        let month = u8::from(self.month) + 1;
+        let day = u8::from(self.day) + 1;
+        let northern = match kind {
+            SeasonKind::Meteorological => match month {
+                3..=5 => Season::Spring,
+                6..=8 => Season::Summer,
+                9..=11 => Season::Autumn,
+                _ => Season::Winter,
+            },
+            SeasonKind::Astronomical => match (month, day) {
+                (3, 20..=31) | (4..=5, _) | (6, 1..=20) => Season::Spring,
+                (6, _) | (7..=8, _) | (9, 1..=21) => Season::Summer,
+                (9, _) | (10..=11, _) | (12, 1..=20) => Season::Autumn,
+                _ => Season::Winter,
+            },
+        };
+        match (hemisphere, northern) {
+            (Hemisphere::Northern, season) => season,
+            (Hemisphere::Southern, Season::Spring) => Season::Autumn,
+            (Hemisphere::Southern, Season::Summer) => Season::Winter,
+            (Hemisphere::Southern, Season::Autumn) => Season::Spring,
+            (Hemisphere::Southern, Season::Winter) => Season::Summer,
+        }
+    }
+
+    /// Returns `true` if all of the fields have the value of [`MockDateTime::default`],
+    /// midnight of January 1st of the year `0`, which callers may treat as unset.
+    ///
+    /// Since the default is a valid date time, an actual `0000-01-01T00:00:00` can't be
+    /// distinguished from an unset value, so an `Option<MockDateTime>` should be preferred
+    /// when that date may occur.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// assert!(MockDateTime::default().is_default());
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert!(!dt.is_default());
+    /// ```
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns the date `n` business days after this one, keeping the same time of day,
+    /// where the days for which `is_weekend` returns `true` are skipped. A negative `n`
+    /// moves backward.
+    ///
+    /// If `n` is zero, or if every day of the week is a weekend, the date is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year is out of the range of `i32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, WeekDay};
+    ///
+    /// // Thursday, October 15th, 2020.
+    /// let dt: MockDateTime = "2020-10-15T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let is_weekend = |day: WeekDay| u8::from(day) == 0 || u8::from(day) == 6;
+    ///
+    /// let tuesday: MockDateTime = "2020-10-20T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.add_business_days(3, is_weekend), tuesday);
+    /// ```
+    pub fn add_business_days(&self, n: i64, is_weekend: impl Fn(WeekDay) -> bool) -> Self {
+        let mut result = self.clone();
+        let business_days = (0..7)
+            .filter(|day| !is_weekend(WeekDay::new_unchecked(*day)))
+            .count() as u64;
+        if business_days == 0 || n == 0 {
+            return result;
+        }
+        // Seven consecutive days contain each day of the week once, so the whole weeks are
+        // skipped at once, leaving between one and `business_days` days to step through.
+        let weeks = (n.unsigned_abs() - 1) / business_days;
+        result.add_days(n.signum().saturating_mul(weeks as i64).saturating_mul(7));
+        let mut remaining = n.unsigned_abs() - weeks * business_days;
+        while remaining > 0 {
+            if n > 0 {
+                result.increment_day();
+            } else {
+                result.decrement_day();
+            }
+            if !is_weekend(result.weekday()) {
+                remaining -= 1;
+            }
+        }
+        result
+    }
+
+    /// Returns the number of complete `unit`s between this date time and the `other` one,
+    /// in either order.
+    ///
+    /// A month is complete once the same day of the month and time of day is reached, or
+    /// the next day if the month is too short, so there is one complete month from
+    /// January 31st to March 1st. Years are counted in complete months, and the other units
+    /// have a fixed length, ignoring leap seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, TimeUnit};
+    ///
+    /// let start: MockDateTime = "2020-01-15T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let end: MockDateTime = "2020-04-10T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// assert_eq!(start.complete_periods(&end, TimeUnit::Month), 2);
+    /// assert_eq!(start.complete_periods(&end, TimeUnit::Week), 12);
+    /// assert_eq!(end.complete_periods(&start, TimeUnit::Day), 86);
+    /// ```
+    pub fn complete_periods(&self, other: &Self, unit: TimeUnit) -> u64 {
+        fn time_in_month(dt: &MockDateTime) -> (u8, u8, u8, u8) {
+            (
+                dt.day.into(),
+                dt.hour.into(),
+                dt.minute.into(),
+                dt.second.into(),
+            )
+        }
+
+        let (start, end) = if self.seconds_since_epoch() <= other.seconds_since_epoch() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let seconds = match unit {
+            TimeUnit::Year | TimeUnit::Month => {
+                let mut months = i64::from(end.year - start.year) * 12
+                    + i64::from(u8::from(end.month))
+                    - i64::from(u8::from(start.month));
+                if time_in_month(end) < time_in_month(start) {
+                    months -= 1;
+                }
+                let months = months as u64;
+                return if unit == TimeUnit::Year {
+                    months / 12
+                } else {
+                    months
+                };
+            }
+            TimeUnit::Week => 7 * 86400,
+            TimeUnit::Day => 86400,
+            TimeUnit::Hour => 3600,
+            TimeUnit::Minute => 60,
+            TimeUnit::Second => 1,
+        };
+        (end.seconds_since_epoch() - start.seconds_since_epoch()) as u64 / seconds
+    }
+
+    /// Returns the age in complete years of a person born at the wall-clock time `birth`,
+    /// as of this UTC date time seen at the `offset`, such as the current time in the time
+    /// zone in which a legal age applies.
+    ///
+    /// The years are complete as counted by [`MockDateTime::complete_periods`], so that a
+    /// person born on February 29th gets one year older on March 1st of common years. The age
+    /// is `0` if the `birth` is later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{GmtOffset, MockDateTime};
+    ///
+    /// let birth: MockDateTime = "2003-05-10T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let now: MockDateTime = "2021-05-09T20:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// // It is already May 10th in Pakistan, but not yet in Peru.
+    /// let pkt: GmtOffset = "+05:00".parse().expect("Failed to parse an offset.");
+    /// let pet: GmtOffset = "-05:00".parse().expect("Failed to parse an offset.");
+    /// assert_eq!(now.age_at(&birth, pkt), 18);
+    /// assert_eq!(now.age_at(&birth, pet), 17);
+    /// ```
+    pub fn age_at(&self, birth: &MockDateTime, offset: GmtOffset) -> u32 {
+        let mut now = self.clone();
+        now.shift_from_utc(offset);
+        if *birth > now {
+            return 0;
+        }
+        u32::try_from(birth.complete_periods(&now, TimeUnit::Year)).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the time from this date time to the `other` one as a fractional number of
+    /// `unit`s, which is negative if the `other` one is earlier, such as `1.5` hours for
+    /// 90 minutes.
+    ///
+    /// Unlike [`MockDateTime::complete_periods`], months and years have the average length
+    /// of the 400-year cycle of the Gregorian calendar, `30.436875` and `365.2425` days.
+    /// Leap seconds are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, TimeUnit};
+    ///
+    /// let start: MockDateTime = "2020-10-14T13:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let end: MockDateTime = "2020-10-14T14:30:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// assert_eq!(start.diff_in(&end, TimeUnit::Hour), 1.5);
+    /// assert_eq!(end.diff_in(&start, TimeUnit::Minute), -90.0);
+    /// ```
+    pub fn diff_in(&self, other: &Self, unit: TimeUnit) -> f64 {
+        // The days of the 400-year Gregorian cycle.
+        const DAYS_PER_CYCLE: f64 = 146_097.0;
+
+        let seconds = match unit {
+            TimeUnit::Year => DAYS_PER_CYCLE / 400.0 * 86400.0,
+            TimeUnit::Month => DAYS_PER_CYCLE / 4800.0 * 86400.0,
+            TimeUnit::Week => 7.0 * 86400.0,
+            TimeUnit::Day => 86400.0,
+            TimeUnit::Hour => 3600.0,
+            TimeUnit::Minute => 60.0,
+            TimeUnit::Second => 1.0,
+        };
+        (other.seconds_since_epoch() - self.seconds_since_epoch()) as f64 / seconds
+    }
+
+    /// Returns the date time in the ISO 8601 extended format, reduced to the given
+    /// `precision`, such as `2020-10` for [`Precision::Month`] or `2020-10-14T13` for
+    /// [`Precision::Hour`].
+    ///
+    /// Years outside of the `0..=9999` range are written in the expanded format, with a
+    /// sign and at least six digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, Precision};
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// assert_eq!(dt.to_iso8601(Precision::Month), "2020-10");
+    /// assert_eq!(dt.to_iso8601(Precision::Minute), "2020-10-14T13:21");
+    /// ```
+    pub fn to_iso8601(&self, precision: Precision) -> String {
+        use std::fmt::Write;
+
+        let mut s = String::with_capacity(21);
+        let result = if (0..=9999).contains(&self.year) {
+            write!(s, "{:04}", self.year)
+        } else {
+            let sign = if self.year < 0 { '-' } else { '+' };
+            write!(s, "{}{:06}", sign, self.year.unsigned_abs())
+        };
+        result.expect("Failed to write to a String.");
+        let fields = [
+            (Precision::Month, '-', u8::from(self.month) + 1),
+            (Precision::Day, '-', u8::from(self.day) + 1),
+            (Precision::Hour, 'T', u8::from(self.hour)),
+            (Precision::Minute, ':', u8::from(self.minute)),
+            (Precision::Second, ':', u8::from(self.second)),
+        ];
+        for (_, separator, value) in fields.iter().filter(|(field, ..)| *field <= precision) {
+            write!(s, "{}{:02}", separator, value).expect("Failed to write to a String.");
+        }
+        s
+    }
+
+    /// Returns the date in the ISO 8601 ordinal format, `YYYY-DDD`, with the one-based day
+    /// of the year, as read by [`MockDateTime::from_ordinal`].
+    ///
+    /// Years outside of the `0..=9999` range are written in the expanded format, with a
+    /// sign and at least six digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-02-01T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.to_ordinal_string(), "2020-032");
+    /// ```
+    pub fn to_ordinal_string(&self) -> String {
+        format!(
+            "{}-{:03}",
+            self.to_iso8601(Precision::Year),
+            self.tm_yday() + 1
+        )
+    }
+
+    /// Returns the date time in the ISO 8601 basic format, `YYYYMMDDThhmmss`, without
+    /// separators, as read by [`MockDateTime::from_iso8601_basic`].
+    ///
+    /// Years outside of the `0..=9999` range are written in the expanded format, with a
+    /// sign and at least six digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:05".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.to_iso8601_basic(), "20201014T132105");
+    /// ```
+    pub fn to_iso8601_basic(&self) -> String {
+        self.to_iso8601(Precision::Second)
+            .chars()
+            .enumerate()
+            .filter(|(idx, c)| *idx == 0 || !matches!(c, '-' | ':'))
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    /// Parses a date time in the ISO 8601 basic format, `YYYYMMDDThhmmss`, as written by
+    /// [`MockDateTime::to_iso8601_basic`].
+    ///
+    /// The year may be signed, with more than four digits in the expanded format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_iso8601_basic("20201014T132105")
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt, "2020-10-14T13:21:05".parse().unwrap());
+    /// ```
+    pub fn from_iso8601_basic(input: &str) -> Result<Self, DateTimeError> {
+        // The fields following the year, `MMDDThhmmss`.
+        const SUFFIX_LEN: usize = 11;
+
+        if !input.is_ascii() || input.len() < SUFFIX_LEN + 4 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let (year, rest) = input.split_at(input.len() - SUFFIX_LEN);
+        let unsigned = year.strip_prefix(|c| c == '-' || c == '+').unwrap_or(year);
+        // Only signed years may have more than four digits.
+        let valid_len = if unsigned.len() < year.len() {
+            unsigned.len() >= 4
+        } else {
+            unsigned.len() == 4
+        };
+        if !valid_len || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        if rest.as_bytes()[4] != b'T' {
+            return Err(DateTimeError::InvalidSeparator {
+                position: year.len() + 4,
+                expected: 'T',
+            });
+        }
+        let field = |range: std::ops::Range<usize>| -> Result<usize, DateTimeError> {
+            let digits = &rest[range];
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            Ok(digits.parse()?)
+        };
+        Self::try_new_iso(
+            year.parse()?,
+            field(0..2)?,
+            field(2..4)?,
+            field(5..7)?,
+            field(7..9)?,
+            field(9..11)?,
+        )
+    }
+
+    /// Splits the `MockDateTime` into its [`Date`] and [`Time`] parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let (date, time) = dt.split();
+    /// assert_eq!(date.year, 2020);
+    /// assert_eq!(u8::from(time.hour), 13);
+    /// assert_eq!(MockDateTime::join(date, time), dt);
+    /// ```
+    pub fn split(&self) -> (Date, Time) {
+        (
+            Date {
+                year: self.year,
+                month: self.month,
+                day: self.day,
+            },
+            Time {
+                hour: self.hour,
+                minute: self.minute,
+                second: self.second,
+            },
+        )
+    }
+
+    /// Creates a new `MockDateTime` from its [`Date`] and [`Time`] parts.
+    pub fn join(date: Date, time: Time) -> Self {
+        Self::new(
+            date.year,
+            date.month,
+            date.day,
+            time.hour,
+            time.minute,
+            time.second,
+        )
+    }
+
+    /// Parses a date time in the [`RFC 2822`] format used by email and HTTP headers,
+    /// such as `Wed, 14 Oct 2020 13:21:00 +0000`.
+    ///
+    /// The weekday is optional, but if present, it must match the date. Since `MockDateTime`
+    /// doesn't store an offset, the result is converted to UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_rfc2822("Wed, 14 Oct 2020 13:21:00 +0200")
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt, "2020-10-14T11:21:00".parse().unwrap());
+    ///
+    /// assert!(MockDateTime::from_rfc2822("Thu, 14 Oct 2020 13:21:00 +0000").is_err());
+    /// ```
+    ///
+    /// [`RFC 2822`]: https://tools.ietf.org/html/rfc2822#section-3.3
+    pub fn from_rfc2822(input: &str) -> Result<Self, DateTimeError> {
+        const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+        const MONTHS: [&str; 12] = [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ];
+
+        fn find_name(names: &[&str], input: &str) -> Result<usize, DateTimeError> {
+            names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(input))
+                .ok_or(DateTimeError::InvalidFormat)
+        }
+
+        fn parse_digits(
+            input: &str,
+            lengths: RangeInclusive<usize>,
+        ) -> Result<usize, DateTimeError> {
+            if !lengths.contains(&input.len()) || !input.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            Ok(input.parse()?)
+        }
+
+        let (weekday, input) = match input.split_once(',') {
+            Some((weekday, input)) => (Some(find_name(&WEEKDAYS, weekday.trim())?), input),
+            None => (None, input),
+        };
+        let tokens: Vec<&str> = input.split_ascii_whitespace().collect();
+        let (day, month, year, time, zone) = match tokens.as_slice() {
+            [day, month, year, time, zone] => (day, month, year, time, zone),
+            _ => return Err(DateTimeError::InvalidFormat),
+        };
+
+        let year = parse_digits(year, 4..=9)? as i32;
+        let month = Month::try_from(find_name(&MONTHS, month)?)?;
+        let day = match parse_digits(day, 1..=2)? {
+            day if day >= 1 && day <= days_in_month(year, month).into() => day - 1,
+            _ => {
+                return Err(DateTimeError::Overflow {
+                    field: "Day",
+                    max: days_in_month(year, month).into(),
+                })
+            }
+        };
+        let mut time = time.split(':');
+        let mut next_time_field = || match time.next() {
+            Some(field) => parse_digits(field, 2..=2).map(Some),
+            None => Ok(None),
+        };
+        let hour = next_time_field()?.ok_or(DateTimeError::InvalidFormat)?;
+        let minute = next_time_field()?.ok_or(DateTimeError::InvalidFormat)?;
+        let second = next_time_field()?.unwrap_or(0);
+        if next_time_field()?.is_some() {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let offset: GmtOffset = match *zone {
+            "UT" | "GMT" => GmtOffset::default(),
+            zone if zone.len() == 5 => zone.parse()?,
+            _ => return Err(DateTimeError::InvalidFormat),
+        };
+
+        let mut result = Self::try_new(year, month.into(), day, hour, minute, second)?;
+        if let Some(weekday) = weekday {
+            let found = WeekDay::new_unchecked(weekday as u8);
+            let expected = result.weekday();
+            if found != expected {
+                return Err(DateTimeError::WeekdayMismatch { expected, found });
+            }
+        }
+
+        result.shift_to_utc(offset);
+        Ok(result)
+    }
+
+    /// Parses a date time in the [`RFC 3339`] format, such as `2020-10-14T13:21:00+02:00`.
+    ///
+    /// The date and the time may also be separated by a space, as permitted by RFC 3339,
+    /// unlike in [`FromStr`], which requires a `T`. The offset, or `Z` for UTC, is required,
+    /// and an input without one is rejected. Since `MockDateTime` stores whole seconds
+    /// and doesn't store an offset, fractional seconds are dropped and the result is
+    /// converted to UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_rfc3339("2020-10-14 13:21:00+02:00")
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt, "2020-10-14T11:21:00".parse().unwrap());
+    ///
+    /// assert!(MockDateTime::from_rfc3339("2020-10-14T13:21:00").is_err());
+    /// ```
+    ///
+    /// [`RFC 3339`]: https://tools.ietf.org/html/rfc3339#section-5.6
+    pub fn from_rfc3339(input: &str) -> Result<Self, DateTimeError> {
+        // The positions of the separators in the `YYYY-MM-DDThh:mm:ss` template.
+        const SEPARATORS: &[(usize, &[u8])] =
+            &[(4, b"-"), (7, b"-"), (10, b"Tt "), (13, b":"), (16, b":")];
+
+        fn parse_digits(input: &[u8], range: Range<usize>) -> Result<usize, DateTimeError> {
+            match input.get(range) {
+                Some(digits) if digits.iter().all(u8::is_ascii_digit) => Ok(digits
+                    .iter()
+                    .fold(0, |value, digit| value * 10 + usize::from(digit - b'0'))),
+                _ => Err(DateTimeError::InvalidFormat),
+            }
+        }
+
+        let bytes = input.as_bytes();
+        for &(position, expected) in SEPARATORS {
+            match bytes.get(position) {
+                Some(found) if expected.contains(found) => {}
+                _ => {
+                    return Err(DateTimeError::InvalidSeparator {
+                        position,
+                        expected: expected[0] as char,
+                    })
+                }
+            }
+        }
+        let mut zone = input.get(19..).ok_or(DateTimeError::InvalidFormat)?;
+        if let Some(fraction) = zone.strip_prefix('.') {
+            let digits = fraction.bytes().take_while(u8::is_ascii_digit).count();
+            if digits == 0 {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            zone = &fraction[digits..];
+        }
+        let offset: GmtOffset = match zone {
+            "Z" | "z" => GmtOffset::default(),
+            zone if zone.len() == 6 && zone.as_bytes()[3] == b':' => zone.parse()?,
+            _ => return Err(DateTimeError::InvalidFormat),
+        };
+
+        let mut result = Self::try_new_iso(
+            parse_digits(bytes, 0..4)? as i32,
+            parse_digits(bytes, 5..7)?,
+            parse_digits(bytes, 8..10)?,
+            parse_digits(bytes, 11..13)?,
+            parse_digits(bytes, 14..16)?,
+            parse_digits(bytes, 17..19)?,
+        )?;
+        result.shift_to_utc(offset);
+        Ok(result)
+    }
+
+    /// Parses a date, optionally followed by a time, whose fields are delimited by any of
+    /// the given `separators`, such as `2020/10/14` or `2020.10.14 13:21:00`.
+    ///
+    /// The fields may have any number of digits, and the date and the time are separated
+    /// by a `T` or a space. The same separator must be used between all of the fields of
+    /// the date, and between all of the fields of the time. A missing time is midnight.
+    ///
+    /// Inputs longer than [`MockDateTime::DEFAULT_MAX_LENIENT_LEN`] bytes are rejected
+    /// without being read, which bounds the work done on untrusted inputs. Use
+    /// [`MockDateTime::parse_lenient_with_max_len`] to choose another maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, Separators};
+    ///
+    /// let separators = Separators::default();
+    /// let dt = MockDateTime::parse_lenient("2020/10/14", &separators)
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt, "2020-10-14T00:00:00".parse().unwrap());
+    ///
+    /// assert!(MockDateTime::parse_lenient("2020/10-14", &separators).is_err());
+    /// ```
+    pub fn parse_lenient(input: &str, separators: &Separators) -> Result<Self, DateTimeError> {
+        Self::parse_lenient_with_max_len(input, separators, Self::DEFAULT_MAX_LENIENT_LEN)
+    }
+
+    /// Like [`MockDateTime::parse_lenient`], but rejects the inputs longer than `max_len`
+    /// bytes instead of [`MockDateTime::DEFAULT_MAX_LENIENT_LEN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, Separators};
+    ///
+    /// let separators = Separators::default();
+    /// assert!(MockDateTime::parse_lenient_with_max_len("2020/10/14", &separators, 10).is_ok());
+    /// assert!(MockDateTime::parse_lenient_with_max_len("2020/10/14", &separators, 9).is_err());
+    /// ```
+    pub fn parse_lenient_with_max_len(
+        input: &str,
+        separators: &Separators,
+        max_len: usize,
+    ) -> Result<Self, DateTimeError> {
+        fn read_digits(input: &str, position: &mut usize) -> Result<usize, DateTimeError> {
+            let digits = input[*position..]
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count();
+            if digits == 0 {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            let value = input[*position..*position + digits].parse()?;
+            *position += digits;
+            Ok(value)
+        }
+
+        fn read_separator(
+            input: &str,
+            position: &mut usize,
+            allowed: &[char],
+        ) -> Result<char, DateTimeError> {
+            match input[*position..].chars().next() {
+                Some(found) if allowed.contains(&found) => {
+                    *position += found.len_utf8();
+                    Ok(found)
+                }
+                _ => Err(DateTimeError::InvalidSeparator {
+                    position: *position,
+                    expected: allowed.first().copied().unwrap_or('-'),
+                }),
+            }
+        }
+
+        // Reads three fields delimited by the same separator.
+        fn read_fields(
+            input: &str,
+            position: &mut usize,
+            allowed: &[char],
+        ) -> Result<[usize; 3], DateTimeError> {
+            let first = read_digits(input, position)?;
+            let separator = read_separator(input, position, allowed)?;
+            let second = read_digits(input, position)?;
+            read_separator(input, position, &[separator])?;
+            let third = read_digits(input, position)?;
+            Ok([first, second, third])
+        }
+
+        if input.len() > max_len {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let mut position = 0;
+        let sign = if input.starts_with('-') {
+            position += 1;
+            -1
+        } else {
+            1
+        };
+        let [year, month, day] = read_fields(input, &mut position, &separators.date)?;
+        let [hour, minute, second] = if position == input.len() {
+            [0, 0, 0]
+        } else {
+            read_separator(input, &mut position, &['T', ' '])?;
+            read_fields(input, &mut position, &separators.time)?
+        };
+        if position != input.len() {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let year = i32::try_from(year).map_err(|_| DateTimeError::Overflow {
+            field: "Year",
+            max: i32::MAX as usize,
+        })?;
+        Self::try_new_iso(sign * year, month, day, hour, minute, second)
+    }
+
+    fn weekday(&self) -> WeekDay {
+        crate::format::get_day_of_week(self.year, self.month, self.day)
+    }
+
+    /// Converts the local time at the given `offset` to UTC, moving to the adjacent day if
+    /// needed. The offset is in whole minutes, so the seconds are unaffected, including a
+    /// leap second.
+    pub(crate) fn shift_to_utc(&mut self, offset: GmtOffset) {
+        self.shift_minutes(-offset.seconds() / 60);
+    }
+
+    /// Converts the UTC time to the local time at the given `offset`, the inverse of
+    /// [`MockDateTime::shift_to_utc`].
+    fn shift_from_utc(&mut self, offset: GmtOffset) {
+        self.shift_minutes(offset.seconds() / 60);
+    }
+
+    // Moves the time by less than a day, moving to the adjacent day if needed.
+    fn shift_minutes(&mut self, delta: i32) {
+        let minutes =
+            i32::from(u8::from(self.hour)) * 60 + i32::from(u8::from(self.minute)) + delta;
+        match minutes.div_euclid(24 * 60) {
+            -1 => self.decrement_day(),
+            1 => self.increment_day(),
+            _ => {}
+        }
+        let minutes = minutes.rem_euclid(24 * 60);
+        self.hour = Hour::new_unchecked((minutes / 60) as u8);
+        self.minute = Minute::new_unchecked((minutes % 60) as u8);
+    }
+
+    fn increment_day(&mut self) {
+        if u8::from(self.day) + 1 < days_in_month(self.year, self.month) {
+            self.day = self.day + 1;
+        } else if u8::from(self.month) < 11 {
+            self.day = Day::new_unchecked(0);
+            self.month = self.month + 1;
+        } else {
+            self.day = Day::new_unchecked(0);
+            self.month = Month::new_unchecked(0);
+            self.year += 1;
+        }
+    }
+
+    fn decrement_day(&mut self) {
+        if u8::from(self.day) > 0 {
+            self.day = self.day - 1;
+        } else {
+            if u8::from(self.month) > 0 {
+                self.month = self.month - 1;
+            } else {
+                self.month = Month::new_unchecked(11);
+                self.year -= 1;
+            }
+            self.day = Day::new_unchecked(days_in_month(self.year, self.month) - 1);
+        }
+    }
+
+    /// Moves the date by `days`, keeping the time of day.
+    fn add_days(&mut self, days: i64) {
+        let (year, month, day) = civil::civil_from_days(self.days_since_epoch() + days);
+        self.year = i32::try_from(year).expect("The year is out of range.");
+        self.month = Month::new_unchecked(month - 1);
+        self.day = Day::new_unchecked(day - 1);
+    }
+
+    /// Returns the number of days since 1970-01-01, ignoring the time of day.
+    fn days_since_epoch(&self) -> i64 {
+        civil::days_from_civil(
+            i64::from(self.year),
+            u8::from(self.month) + 1,
+            u8::from(self.day) + 1,
+        )
+    }
+
+    /// Returns the number of seconds since 1970-01-01T00:00:00, the inverse of
+    /// [`MockDateTime::from_unix_timestamp_f64`].
+    fn seconds_since_epoch(&self) -> i64 {
+        self.days_since_epoch() * 86400 + i64::from(self.seconds_of_day())
+    }
+}
+
+/// The date part of a [`MockDateTime`], returned by [`MockDateTime::split`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Date {
+    pub year: i32,
+    pub month: Month,
+    pub day: Day,
+}
+
+/// The time part of a [`MockDateTime`], returned by [`MockDateTime::split`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Time {
+    pub hour: Hour,
+    pub minute: Minute,
+    pub second: Second,
+}
+
+/// A value returned by [`parse_flexible`], depending on the parts present in its input.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedValue {
+    /// A date without a time, such as `2020-10-14`.
+    Date(Date),
+    /// A time without a date, such as `13:21:00`.
+    Time(Time),
+    /// A date and a time, such as `2020-10-14T13:21:00`.
+    DateTime(MockDateTime),
+}
+
+/// The length of the year of a [`MockDateTime`], returned by [`MockDateTime::year_info`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YearInfo {
+    /// Whether the year is a leap year in the proleptic Gregorian calendar.
+    pub is_leap: bool,
+    /// The number of days in the year, `366` in a leap year and `365` otherwise.
+    pub days: u16,
+}
+
+/// A unit of time, used by [`MockDateTime::complete_periods`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeUnit {
+    Year,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// A hemisphere of the Earth, whose seasons are returned by [`MockDateTime::season`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hemisphere {
+    Northern,
+    Southern,
+}
+
+/// The definition of the seasons used by [`MockDateTime::season`].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum SeasonKind {
+    /// Seasons of three whole months, with the winter of the Northern hemisphere starting
+    /// on December 1st.
+    #[default]
+    Meteorological,
+    /// Seasons starting on fixed dates of the equinoxes and solstices, with the winter of
+    /// the Northern hemisphere starting on December 21st.
+    Astronomical,
+}
+
+/// A season of the year, returned by [`MockDateTime::season`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+/// The most precise field written by [`MockDateTime::to_iso8601`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Precision {
+    Year,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// The separators accepted between the fields by [`MockDateTime::parse_lenient`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Separators {
+    /// The separators between the year, the month and the day, `-`, `/` and `.` by default.
+    pub date: Vec<char>,
+    /// The separators between the hour, the minute and the second, `:` by default.
+    pub time: Vec<char>,
+}
+
+impl Default for Separators {
+    fn default() -> Self {
+        Self {
+            date: vec!['-', '/', '.'],
+            time: vec![':'],
+        }
+    }
+}
+
+impl DateTimeType for MockDateTime {
+    fn year(&self) -> i32 {
+        self.year
+    }
+    fn month(&self) -> Month {
+        self.month
+    }
+    fn day(&self) -> Day {
+        self.day
+    }
+    fn hour(&self) -> Hour {
+        self.hour
+    }
+    fn minute(&self) -> Minute {
+        self.minute
+    }
+    fn second(&self) -> Second {
+        self.second
+    }
+}
+
+impl FromStr for MockDateTime {
+    type Err = DateTimeError;
+
+    /// Parse a `MockDateTime` from a string.
+    ///
+    /// This utility is for easily creating dates, not a complete robust solution. The
+    /// string must take a specific form of the ISO 8601 format: `YYYY-MM-DDThh:mm:ss`,
+    /// optionally preceded by a `-` for the years before the common era.
+    ///
+    /// A signed year may have more than four digits, as in the expanded format of
+    /// ISO 8601 written by [`MockDateTime::to_iso8601`] for the years outside of
+    /// `0..=9999`, such as `+010000-01-01T00:00:00`.
+    ///
+    /// The seconds may be followed by a decimal fraction, such as `.000`, which is ignored.
+    /// Any other trailing input, or input which isn't ASCII, returns
+    /// [`DateTimeError::InvalidFormat`].
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    ///
+    /// let date: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let date: MockDateTime = "-0044-03-15T12:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(date.year, -44);
+    ///
+    /// let date: MockDateTime = "+010000-01-01T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(date.year, 10000);
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // The positions of the separators in the `YYYY-MM-DDThh:mm:ss` template.
+        const SEPARATORS: &[(usize, u8)] =
+            &[(4, b'-'), (7, b'-'), (10, b'T'), (13, b':'), (16, b':')];
+
+        if !input.is_ascii() {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let (sign, unsigned) = match input.as_bytes().first() {
+            Some(b'-') => (-1, &input[1..]),
+            Some(b'+') => (1, &input[1..]),
+            _ => (1, input),
+        };
+        let offset = input.len() - unsigned.len();
+        // Only signed years may be expanded beyond four digits.
+        let year_len = if offset > 0 {
+            unsigned
+                .bytes()
+                .take_while(u8::is_ascii_digit)
+                .count()
+                .max(4)
+        } else {
+            4
+        };
+        let year = &unsigned[..year_len.min(unsigned.len())];
+        let input = &unsigned[year.len()..];
+        for &(position, expected) in SEPARATORS {
+            if input.as_bytes().get(position - 4) != Some(&expected) {
+                return Err(DateTimeError::InvalidSeparator {
+                    position: position - 4 + offset + year.len(),
+                    expected: expected as char,
+                });
+            }
+        }
+        // The `-MM-DDThh:mm:ss` which follows the year, and the optional fraction.
+        let fraction = input.get(15..).ok_or(DateTimeError::InvalidFormat)?;
+        if !fraction.is_empty()
+            && (fraction.len() == 1
+                || !fraction.starts_with('.')
+                || !fraction[1..].bytes().all(|b| b.is_ascii_digit()))
+        {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let year: i32 = sign * year.parse::<i32>()?;
+        let month: Month = input[1..3].parse()?;
+        let day: Day = input[4..6].parse()?;
+        let hour: Hour = input[7..9].parse()?;
+        let minute: Minute = input[10..12].parse()?;
+        let second: Second = input[13..15].parse()?;
+        Ok(Self {
+            year,
+            month: month - 1,
+            day: day - 1,
+            hour,
+            minute,
+            second,
+        })
+    }
+}
+
+/// A date and time in which any of the fields may be absent, such as a date without a time.
+///
+/// Formatting a `PartialDateTime` with [`DateTimeFormat::format_partial_to_string`] renders only
+/// the pattern fields for which a value is present.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::PartialDateTime;
+///
+/// let date: PartialDateTime = "2020-10-14".parse()
+///     .expect("Failed to parse a partial date time.");
+/// assert_eq!(date.year, Some(2020));
+/// assert!(date.hour.is_none());
+///
+/// let time: PartialDateTime = "13:21".parse()
+///     .expect("Failed to parse a partial date time.");
+/// assert!(time.year.is_none());
+/// assert!(time.second.is_none());
+/// ```
+///
+/// [`DateTimeFormat::format_partial_to_string`]: super::DateTimeFormat::format_partial_to_string
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PartialDateTime {
+    pub year: Option<i32>,
+    pub month: Option<Month>,
+    pub day: Option<Day>,
+    pub hour: Option<Hour>,
+    pub minute: Option<Minute>,
+    pub second: Option<Second>,
+}
+
+impl PartialDateTime {
+    /// Returns `true` if the year, month and day are all present.
+    pub fn has_date(&self) -> bool {
+        self.year.is_some() && self.month.is_some() && self.day.is_some()
+    }
+
+    /// Returns `true` if the hour, minute and second are all present.
+    pub fn has_time(&self) -> bool {
+        self.hour.is_some() && self.minute.is_some() && self.second.is_some()
+    }
+
+    /// Returns a `MockDateTime` in which the absent fields are set to their lowest values.
+    pub fn to_date_time(&self) -> MockDateTime {
+        MockDateTime::new(
+            self.year.unwrap_or_default(),
+            self.month.unwrap_or_default(),
+            self.day.unwrap_or_default(),
+            self.hour.unwrap_or_default(),
+            self.minute.unwrap_or_default(),
+            self.second.unwrap_or_default(),
+        )
+    }
+}
+
+impl TryFrom<&MockDateTime> for [u8; 7] {
+    type Error = DateTimeError;
+
+    /// Packs a `MockDateTime` into seven bytes: the year as a big-endian `u16`, followed by
+    /// the zero-based month and day, the hour, the minute and the second.
+    ///
+    /// Years which don't fit in a `u16`, including negative years, are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockDateTime;
+    /// use std::convert::TryFrom;
+    ///
+    /// let dt: MockDateTime = "2020-10-14T13:21:05".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let bytes = <[u8; 7]>::try_from(&dt).expect("Failed to pack a date time.");
+    /// assert_eq!(bytes, [0x07, 0xE4, 9, 13, 13, 21, 5]);
+    /// assert_eq!(MockDateTime::try_from(bytes).unwrap(), dt);
+    /// ```
+    fn try_from(input: &MockDateTime) -> Result<Self, Self::Error> {
+        let year = u16::try_from(input.year).map_err(|_| DateTimeError::Overflow {
+            field: "Year",
+            max: u16::MAX.into(),
+        })?;
+        let [high, low] = year.to_be_bytes();
+        Ok([
+            high,
+            low,
+            input.month.into(),
+            input.day.into(),
+            input.hour.into(),
+            input.minute.into(),
+            input.second.into(),
+        ])
+    }
+}
+
+impl TryFrom<[u8; 7]> for MockDateTime {
+    type Error = DateTimeError;
+
+    /// Unpacks a `MockDateTime` from the seven bytes written by the `TryFrom<&MockDateTime>`
+    /// implementation of `[u8; 7]`, validating each of the fields.
+    fn try_from(input: [u8; 7]) -> Result<Self, Self::Error> {
+        let [high, low, month, day, hour, minute, second] = input;
+        Self::try_new_iso(
+            u16::from_be_bytes([high, low]).into(),
+            usize::from(month) + 1,
+            usize::from(day) + 1,
+            hour.into(),
+            minute.into(),
+            second.into(),
+        )
+    }
+}
+
+impl<T: DateTimeType> From<&T> for PartialDateTime {
+    fn from(input: &T) -> Self {
+        Self {
+            year: Some(input.year()),
+            month: Some(input.month()),
+            day: Some(input.day()),
+            hour: Some(input.hour()),
+            minute: Some(input.minute()),
+            second: Some(input.second()),
+        }
+    }
+}
+
+impl FromStr for PartialDateTime {
+    type Err = DateTimeError;
+
+    /// Parse a `PartialDateTime` from a string.
+    ///
+    /// The string must take one of the reduced forms of the ISO 8601 format: `YYYY[-MM[-DD]]`
+    /// for a date, `hh[:mm[:ss]]` for a time, or a full date and a time separated by `T`.
+    /// The year may be signed, with more than four digits in the expanded format, such as
+    /// `+010000-01-01` or `-000001-12-31`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // Parses an one-indexed unit, such as a month.
+        fn parse_one_indexed<T: FromStr<Err = DateTimeError> + Sub<u8, Output = T>>(
+            input: &str,
+        ) -> Result<T, DateTimeError> {
+            if input.bytes().all(|b| b == b'0') {
+                return Err(DateTimeError::InvalidFormat);
+            }
+            Ok(input.parse::<T>()? - 1)
+        }
+
+        let mut result = Self::default();
+        let (date, time) = match input.find('T') {
+            Some(idx) => (Some(&input[..idx]), Some(&input[idx + 1..])),
+            None if input.contains(':') => (None, Some(input)),
+            None => (Some(input), None),
+        };
+        if let Some(date) = date {
+            let (sign, date) = match date.strip_prefix('-') {
+                Some(date) => (-1, date),
+                None => (1, date.strip_prefix('+').unwrap_or(date)),
+            };
+            let mut parts = date.split('-');
+            result.year = parts
+                .next()
+                .map(str::parse::<i32>)
+                .transpose()?
+                .map(|year| sign * year);
+            result.month = parts.next().map(parse_one_indexed).transpose()?;
+            result.day = parts.next().map(parse_one_indexed).transpose()?;
+            if parts.next().is_some() || (time.is_some() && !result.has_date()) {
+                return Err(DateTimeError::InvalidFormat);
+            }
+        }
+        if let Some(time) = time {
+            let mut parts = time.split(':');
+            result.hour = parts.next().map(str::parse).transpose()?;
+            result.minute = parts.next().map(str::parse).transpose()?;
+            result.second = parts.next().map(str::parse).transpose()?;
+            if parts.next().is_some() {
+                return Err(DateTimeError::InvalidFormat);
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// Parses a date, a time, or both, telling them apart by their separators rather than
+/// filling the absent parts with defaults.
+///
+/// The input takes one of the forms accepted by [`PartialDateTime`], in which every field
+/// of the date or the time is present: `YYYY-MM-DD` for a date, `hh:mm:ss` for a time, or
+/// both separated by `T`. The day is validated against the length of the month.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{parse_flexible, ParsedValue};
+///
+/// for input in &["2020-10-14", "13:21:00", "2020-10-14T13:21:00"] {
+///     match parse_flexible(input).expect("Failed to parse a value.") {
+///         ParsedValue::Date(date) => assert_eq!(date.year, 2020),
+///         ParsedValue::Time(time) => assert_eq!(u8::from(time.hour), 13),
+///         ParsedValue::DateTime(date_time) => assert_eq!(date_time.year, 2020),
+///     }
+/// }
+///
+/// assert!(parse_flexible("2020-10").is_err());
+/// ```
+pub fn parse_flexible(input: &str) -> Result<ParsedValue, DateTimeError> {
+    let partial: PartialDateTime = input.parse()?;
+    if partial.has_date() {
+        let day = partial.day.unwrap_or_default();
+        Day::new_for_month(
+            day.into(),
+            partial.year.unwrap_or_default(),
+            partial.month.unwrap_or_default(),
+        )?;
+    }
+    let (date, time) = partial.to_date_time().split();
+    match (partial.has_date(), partial.has_time()) {
+        (true, true) => Ok(ParsedValue::DateTime(MockDateTime::join(date, time))),
+        (true, false) if partial.hour.is_none() => Ok(ParsedValue::Date(date)),
+        (false, true) if partial.year.is_none() => Ok(ParsedValue::Time(time)),
+        _ => Err(DateTimeError::InvalidFormat),
+    }
+}
+
+/// Returns `true` if the given year is a leap year in the proleptic Gregorian calendar.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::is_leap_year;
+///
+/// assert!(is_leap_year(2000));
+/// assert!(is_leap_year(2020));
+/// assert!(!is_leap_year(1900));
+/// assert!(!is_leap_year(2021));
+/// ```
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// The number of days in each month of a common year, indexed by the zero-based month.
+///
+/// Use [`days_in_month`] to account for the leap day of February.
+pub const MONTH_LENGTHS: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+/// Returns the number of days in the given zero-indexed `month` of the `year`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{days_in_month, Month};
+///
+/// let february = Month::new_unchecked(1);
+/// assert_eq!(days_in_month(2020, february), 29);
+/// assert_eq!(days_in_month(2021, february), 28);
+/// ```
+pub fn days_in_month(year: i32, month: Month) -> u8 {
+    match u8::from(month) {
+        1 if is_leap_year(year) => 29,
+        1 => 28,
+        3 | 5 | 8 | 10 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns an iterator over the leap years in the given range of years.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::leap_years_in;
+///
+/// let years: Vec<i32> = leap_years_in(1996..=2004).collect();
+/// assert_eq!(years, vec![1996, 2000, 2004]);
+/// ```
+pub fn leap_years_in(years: RangeInclusive<i32>) -> impl Iterator<Item = i32> {
+    years.filter(|year| is_leap_year(*year))
+}
+
+/// Returns an iterator over the valid days of the given zero-indexed `month` of the `year`,
+/// taking leap years into account.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{days_in, Month};
+///
+/// assert_eq!(days_in(2020, Month::new_unchecked(1)).count(), 29);
+/// assert_eq!(days_in(2021, Month::new_unchecked(1)).count(), 28);
+/// ```
+pub fn days_in(year: i32, month: Month) -> impl Iterator<Item = Day> {
+    (0..days_in_month(year, month)).map(Day::new_unchecked)
+}
+
+/// Returns the zero-indexed month following the `month` of the `year`, moving to January
+/// of the next year after December.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{next_month, Month};
+///
+/// assert_eq!(next_month(2020, Month::new_unchecked(11)), (2021, Month::new_unchecked(0)));
+/// ```
+pub fn next_month(year: i32, month: Month) -> (i32, Month) {
+    if u8::from(month) < 11 {
+        (year, month + 1)
+    } else {
+        (year + 1, Month::new_unchecked(0))
+    }
+}
+
+/// Returns the zero-indexed month preceding the `month` of the `year`, moving to December
+/// of the previous year before January.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{prev_month, Month};
+///
+/// assert_eq!(prev_month(2021, Month::new_unchecked(0)), (2020, Month::new_unchecked(11)));
+/// ```
+pub fn prev_month(year: i32, month: Month) -> (i32, Month) {
+    if u8::from(month) > 0 {
+        (year, month - 1)
+    } else {
+        (year - 1, Month::new_unchecked(11))
+    }
+}
+
+/// This macro defines a struct for each type of unit to be used in a DateTime. Each
+/// unit is bounded by a range. The traits implemented here will return a Result on
+/// whether or not the unit is in range from the given input.
+///
+/// The `$human_offset` is added to the internal value to display it, `1` for the
+/// zero-based units which are counted from one, such as the month.
+macro_rules! dt_unit {
+    ($name:ident, $value:expr, $human_offset:expr) => {
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(u8);
+
+        impl $name {
+            pub const fn new_unchecked(input: u8) -> Self {
+                Self(input)
+            }
+
+            /// Returns the value as displayed to humans, which is one-based for the
+            /// units counted from one, unlike the zero-based value of `u8::from`.
+            pub const fn as_human(&self) -> u8 {
+                self.0 + $human_offset
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = DateTimeError;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                let val: u8 = input.parse()?;
+                if val > $value {
+                    Err(DateTimeError::Overflow {
+                        field: "$name",
+                        max: $value,
+                    })
+                } else {
+                    Ok(Self(val))
+                }
+            }
+        }
+
+        impl TryFrom<u8> for $name {
+            type Error = DateTimeError;
+
+            fn try_from(input: u8) -> Result<Self, Self::Error> {
+                if input > $value {
+                    Err(DateTimeError::Overflow {
+                        field: "$name",
+                        max: $value,
+                    })
+                } else {
+                    Ok(Self(input))
+                }
+            }
+        }
+
+        impl TryFrom<usize> for $name {
+            type Error = DateTimeError;
+
+            fn try_from(input: usize) -> Result<Self, Self::Error> {
+                if input > $value {
+                    Err(DateTimeError::Overflow {
+                        field: "$name",
+                        max: $value,
+                    })
+                } else {
+                    Ok(Self(input as u8))
+                }
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(input: $name) -> Self {
+                input.0
+            }
+        }
+
+        impl From<$name> for usize {
+            fn from(input: $name) -> Self {
+                input.0 as Self
+            }
+        }
+
+        impl Add<u8> for $name {
+            type Output = Self;
+
+            fn add(self, other: u8) -> Self {
+                Self(self.0 + other)
+            }
+        }
+
+        impl Sub<u8> for $name {
+            type Output = Self;
+
+            fn sub(self, other: u8) -> Self {
+                Self(self.0 - other)
+            }
+        }
+    };
+}
+
+dt_unit!(Month, 12, 1);
+dt_unit!(WeekDay, 7, 0);
+dt_unit!(Day, 32, 1);
+dt_unit!(Hour, 24, 0);
+dt_unit!(Minute, 60, 0);
+dt_unit!(Second, 60, 0);
+
+impl Day {
+    /// Creates a zero-based `Day`, validated against the length of the zero-based `month`
+    /// of the `year`, unlike [`TryFrom`] which accepts any day of the longest month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{Day, Month};
+    ///
+    /// let february = Month::new_unchecked(1);
+    /// assert!(Day::new_for_month(28, 2020, february).is_ok());
+    /// assert!(Day::new_for_month(28, 2021, february).is_err());
+    /// ```
+    pub fn new_for_month(day: u8, year: i32, month: Month) -> Result<Self, DateTimeError> {
+        let days = days_in_month(year, month);
+        if day < days {
+            Ok(Self(day))
+        } else {
+            Err(DateTimeError::Overflow {
+                field: "Day",
+                max: usize::from(days - 1),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_any() {
+        let patterns = &["y-MM-dd", "MM/dd/y"];
+
+        let expected = MockDateTime::try_new(2020, 9, 13, 0, 0, 0).unwrap();
+        assert_eq!(
+            MockDateTime::parse_any("2020-10-14", patterns).unwrap(),
+            expected
+        );
+        assert_eq!(
+            MockDateTime::parse_any("10/14/2020", patterns).unwrap(),
+            expected
+        );
+
+        match MockDateTime::parse_any("14.10.2020", patterns) {
+            Err(DateTimeError::NoMatchingPattern(errors)) => {
+                let patterns: Vec<&str> = errors.iter().map(|(p, _)| p.as_str()).collect();
+                assert_eq!(patterns, vec!["y-MM-dd", "MM/dd/y"]);
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        }
+
+        assert!(matches!(
+            MockDateTime::parse_any("2020-10-14", &["y-MM-dd'"]),
+            Err(DateTimeError::NoMatchingPattern(_))
+        ));
+    }
+
+    #[test]
+    fn test_partial_date_time_from_str() {
+        let date: PartialDateTime = "2020-10-14".parse().unwrap();
+        assert_eq!(
+            date,
+            PartialDateTime {
+                year: Some(2020),
+                month: Some(Month::new_unchecked(9)),
+                day: Some(Day::new_unchecked(13)),
+                ..Default::default()
+            }
+        );
+
+        let month: PartialDateTime = "2020-10".parse().unwrap();
+        assert_eq!(month.month, Some(Month::new_unchecked(9)));
+        assert_eq!(month.day, None);
+
+        let time: PartialDateTime = "13:21:05".parse().unwrap();
+        assert_eq!(
+            time,
+            PartialDateTime {
+                hour: Some(Hour::new_unchecked(13)),
+                minute: Some(Minute::new_unchecked(21)),
+                second: Some(Second::new_unchecked(5)),
+                ..Default::default()
+            }
+        );
+
+        let full: PartialDateTime = "2020-10-14T13:21:00".parse().unwrap();
+        let expected: MockDateTime = "2020-10-14T13:21:00".parse().unwrap();
+        assert_eq!(full, PartialDateTime::from(&expected));
+        assert_eq!(full.to_date_time(), expected);
+
+        // The expanded years of ISO 8601.
+        let samples = &[
+            ("+010000-01-01", 10000, 0, 0),
+            ("-000001-12-31", -1, 11, 30),
+        ];
+        for (input, year, month, day) in samples {
+            let date: PartialDateTime = input.parse().unwrap();
+            assert_eq!(date.year, Some(*year), "{}", input);
+            assert_eq!(date.month, Some(Month::new_unchecked(*month)), "{}", input);
+            assert_eq!(date.day, Some(Day::new_unchecked(*day)), "{}", input);
+            assert_eq!(date.to_date_time().to_iso8601(Precision::Day), *input);
+        }
+
+        assert!("2020-00-14".parse::<PartialDateTime>().is_err());
+        assert!("2020-10T13:21".parse::<PartialDateTime>().is_err());
+        assert!("13:21:00:00".parse::<PartialDateTime>().is_err());
+    }
+
+    #[test]
+    fn test_next_and_previous_weekday() {
+        // The 14th of October 2020 is a Wednesday.
+        let dt: MockDateTime = "2020-10-14T13:21:05".parse().unwrap();
+        let samples: &[(u8, &str, &str)] = &[
+            (3, "2020-10-21T13:21:05", "2020-10-07T13:21:05"),
+            (1, "2020-10-19T13:21:05", "2020-10-12T13:21:05"),
+            (4, "2020-10-15T13:21:05", "2020-10-08T13:21:05"),
+            (0, "2020-10-18T13:21:05", "2020-10-11T13:21:05"),
+        ];
+        for (weekday, next, previous) in samples {
+            let weekday = WeekDay::new_unchecked(*weekday);
+            assert_eq!(dt.next_weekday(weekday), next.parse().unwrap());
+            assert_eq!(dt.previous_weekday(weekday), previous.parse().unwrap());
+        }
+
+        // Crossing month and year boundaries, including a leap day.
+        let dt: MockDateTime = "2020-12-30T00:00:00".parse().unwrap();
+        let friday = WeekDay::new_unchecked(5);
+        assert_eq!(
+            dt.next_weekday(friday),
+            "2021-01-01T00:00:00".parse().unwrap()
+        );
+        let dt: MockDateTime = "2021-01-01T00:00:00".parse().unwrap();
+        assert_eq!(
+            dt.previous_weekday(friday),
+            "2020-12-25T00:00:00".parse().unwrap()
+        );
+        let dt: MockDateTime = "2020-03-02T00:00:00".parse().unwrap();
+        let saturday = WeekDay::new_unchecked(6);
+        assert_eq!(
+            dt.previous_weekday(saturday),
+            "2020-02-29T00:00:00".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_floor_to_weekday() {
+        let sunday = WeekDay::new_unchecked(0);
+        let monday = WeekDay::new_unchecked(1);
+        let samples = &[
+            // Wednesday.
+            (
+                "2020-10-14T13:21:00",
+                "2020-10-12T00:00:00",
+                "2020-10-11T00:00:00",
+            ),
+            // Monday.
+            (
+                "2020-10-12T23:59:59",
+                "2020-10-12T00:00:00",
+                "2020-10-11T00:00:00",
+            ),
+            // Sunday.
+            (
+                "2020-10-11T08:00:00",
+                "2020-10-05T00:00:00",
+                "2020-10-11T00:00:00",
+            ),
+            // Across a month and a year.
+            (
+                "2021-01-01T12:00:00",
+                "2020-12-28T00:00:00",
+                "2020-12-27T00:00:00",
+            ),
+        ];
+        for (input, monday_first, sunday_first) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(
+                dt.floor_to_weekday(monday),
+                monday_first.parse().unwrap(),
+                "{}",
+                input
+            );
+            assert_eq!(
+                dt.floor_to_weekday(sunday),
+                sunday_first.parse().unwrap(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_days_between() {
+        let samples = &[
+            ("2020-10-14T00:00:00", "2020-10-14T23:59:59", 0),
+            ("2020-10-14T13:21:00", "2020-10-15T13:21:00", 1),
+            ("2020-10-14T23:59:59", "2020-10-15T00:00:00", 1),
+            ("2020-10-15T00:00:00", "2020-10-14T23:59:59", -1),
+            // Across the leap day.
+            ("2020-02-28T00:00:00", "2020-03-01T00:00:00", 2),
+            ("2019-02-28T00:00:00", "2019-03-01T00:00:00", 1),
+            ("2020-01-01T00:00:00", "2021-01-01T00:00:00", 366),
+            ("1900-01-01T00:00:00", "2000-01-01T00:00:00", 36524),
+        ];
+        for (start, end, expected) in samples {
+            let start: MockDateTime = start.parse().unwrap();
+            let end: MockDateTime = end.parse().unwrap();
+            assert_eq!(start.days_between(&end), *expected, "{:?}", start);
+        }
+    }
+
+    #[test]
+    fn test_season() {
+        use Season::*;
+        let samples = &[
+            // The date, and its meteorological and astronomical seasons in the North.
+            ("2020-12-10T00:00:00", Winter, Autumn),
+            ("2020-12-21T00:00:00", Winter, Winter),
+            ("2020-01-15T00:00:00", Winter, Winter),
+            ("2020-02-29T00:00:00", Winter, Winter),
+            ("2020-03-01T00:00:00", Spring, Winter),
+            ("2020-03-20T00:00:00", Spring, Spring),
+            ("2020-05-31T00:00:00", Spring, Spring),
+            ("2020-06-01T00:00:00", Summer, Spring),
+            ("2020-06-21T00:00:00", Summer, Summer),
+            ("2020-09-21T00:00:00", Autumn, Summer),
+            ("2020-09-22T00:00:00", Autumn, Autumn),
+            ("2020-11-30T23:59:59", Autumn, Autumn),
+        ];
+        let opposite = |season: Season| match season {
+            Spring => Autumn,
+            Summer => Winter,
+            Autumn => Spring,
+            Winter => Summer,
+        };
+        for (input, meteorological, astronomical) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let kinds = [
+                (SeasonKind::Meteorological, *meteorological),
+                (SeasonKind::Astronomical, *astronomical),
+            ];
+            for (kind, expected) in &kinds {
+                assert_eq!(
+                    dt.season(Hemisphere::Northern, *kind),
+                    *expected,
+                    "{} {:?}",
+                    input,
+                    kind
+                );
+                assert_eq!(
+                    dt.season(Hemisphere::Southern, *kind),
+                    opposite(*expected),
+                    "{} {:?}",
+                    input,
+                    kind
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_default() {
+        assert!(MockDateTime::default().is_default());
+        let year_zero: MockDateTime = "0000-01-01T00:00:00".parse().unwrap();
+        assert!(year_zero.is_default());
+        for input in &[
+            "0000-01-01T00:00:01",
+            "0000-01-02T00:00:00",
+            "0001-01-01T00:00:00",
+            "-0001-01-01T00:00:00",
+        ] {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert!(!dt.is_default(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_add_business_days() {
+        let is_weekend = |day: WeekDay| u8::from(day) == 0 || u8::from(day) == 6;
+        let samples = &[
+            // From Thursday, across the weekend, to Tuesday.
+            ("2020-10-15T13:21:00", 3, "2020-10-20T13:21:00"),
+            ("2020-10-15T13:21:00", 1, "2020-10-16T13:21:00"),
+            ("2020-10-16T13:21:00", 1, "2020-10-19T13:21:00"),
+            // From a weekend day.
+            ("2020-10-17T13:21:00", 1, "2020-10-19T13:21:00"),
+            ("2020-10-15T13:21:00", 0, "2020-10-15T13:21:00"),
+            ("2020-10-15T13:21:00", 10, "2020-10-29T13:21:00"),
+            // Backward, from Tuesday to the previous Thursday.
+            ("2020-10-20T13:21:00", -3, "2020-10-15T13:21:00"),
+            ("2020-10-19T13:21:00", -1, "2020-10-16T13:21:00"),
+            ("2021-01-04T00:00:00", -2, "2020-12-31T00:00:00"),
+        ];
+        for (input, n, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(
+                dt.add_business_days(*n, is_weekend),
+                expected.parse().unwrap(),
+                "{} {}",
+                input,
+                n
+            );
+        }
+
+        let dt: MockDateTime = "2020-10-15T13:21:00".parse().unwrap();
+        assert_eq!(dt.add_business_days(5, |_| true), dt);
+
+        // The whole weeks are skipped without stepping through their days.
+        let samples = &[
+            (1_000_000, "5853-11-10T13:21:00"),
+            (-1_000_000, "-1813-09-20T13:21:00"),
+        ];
+        for (n, expected) in samples {
+            assert_eq!(
+                dt.add_business_days(*n, is_weekend),
+                expected.parse().unwrap(),
+                "{}",
+                n
+            );
+        }
+        // Friday is the only business day.
+        let only_friday = |day: WeekDay| u8::from(day) != 5;
+        assert_eq!(
+            dt.add_business_days(2, only_friday),
+            "2020-10-23T13:21:00".parse().unwrap()
+        );
+        assert_eq!(
+            dt.add_business_days(-1, only_friday),
+            "2020-10-09T13:21:00".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_tm_wday_and_yday() {
+        // The `tm_wday` and `tm_yday` of `gmtime` for the same instants.
+        let samples = &[
+            ("1970-01-01T00:00:00", 4, 0),
+            ("2000-02-29T12:00:00", 2, 59),
+            ("2000-12-31T23:59:59", 0, 365),
+            ("2001-12-31T00:00:00", 1, 364),
+            ("2020-10-14T13:21:00", 3, 287),
+            ("2021-01-02T00:00:00", 6, 1),
+            ("1969-12-31T23:59:59", 3, 364),
+        ];
+        for (input, wday, yday) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.tm_wday(), *wday, "{}", input);
+            assert_eq!(dt.tm_yday(), *yday, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_year_info() {
+        let samples = &[
+            ("2020-10-14T13:21:00", true, 366),
+            ("2000-01-01T00:00:00", true, 366),
+            ("2021-10-14T13:21:00", false, 365),
+            ("1900-01-01T00:00:00", false, 365),
+        ];
+        for (input, is_leap, days) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let info = dt.year_info();
+            assert_eq!(info.is_leap, *is_leap, "{}", input);
+            assert_eq!(info.days, *days, "{}", input);
+            // December 31st is the last of the `days` days of the year.
+            let last: MockDateTime = format!("{}-12-31T00:00:00", &input[..4]).parse().unwrap();
+            assert_eq!(last.tm_yday() + 1, info.days, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_seconds_of_day() {
+        let samples = &[
+            ("2020-10-14T00:00:00", 0),
+            ("2020-10-14T00:00:01", 1),
+            ("2020-10-14T12:00:00", 43200),
+            ("2020-10-14T23:59:59", 86399),
+            ("2016-12-31T23:59:60", 86400),
+        ];
+        for (input, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.seconds_of_day(), *expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_julian_date() {
+        let samples = &[
+            ("2000-01-01T12:00:00", 2_451_545.0),
+            ("2000-01-01T00:00:00", 2_451_544.5),
+            ("2000-01-01T18:00:00", 2_451_545.25),
+            ("1970-01-01T00:00:00", 2_440_587.5),
+            ("1858-11-17T00:00:00", 2_400_000.5),
+            ("-4713-11-24T12:00:00", 0.0),
+        ];
+        for (input, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.julian_date(), *expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_seconds_until_time() {
+        let samples = &[
+            // Later today.
+            ("2020-10-14T13:21:00", (14, 0, 0), 2340),
+            ("2020-10-14T00:00:00", (23, 59, 59), 86399),
+            // Earlier than now, or now, wraps to tomorrow.
+            ("2020-10-14T13:21:00", (9, 30, 0), 72540),
+            ("2020-10-14T13:21:00", (13, 21, 0), 86400),
+            ("2020-10-14T23:59:59", (0, 0, 0), 1),
+            ("2016-12-31T23:59:60", (0, 0, 0), 1),
+        ];
+        for (input, (hour, minute, second), expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let seconds = dt.seconds_until_time(
+                Hour::new_unchecked(*hour),
+                Minute::new_unchecked(*minute),
+                Second::new_unchecked(*second),
+            );
+            assert_eq!(seconds, *expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_shard_hash() {
+        // The hashes are fixed, so that the shards don't change between runs or versions.
+        let samples = &[
+            ("2020-10-14T13:21:05", 0xfe37_b461_3ff1_dfd1, 1, 0),
+            ("1970-01-01T00:00:00", 0x5b14_70ed_396a_6bcc, 12, 0),
+            ("-0044-03-15T12:00:00", 0xb792_1a0a_5edc_bbfc, 12, 5),
+        ];
+        for (input, hash, shard_16, shard_7) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.stable_hash(), *hash, "{}", input);
+            assert_eq!(dt.shard_hash(16), *shard_16, "{}", input);
+            assert_eq!(dt.shard_hash(7), *shard_7, "{}", input);
+            assert_eq!(dt.clone().shard_hash(7), dt.shard_hash(7));
+            assert_eq!(dt.shard_hash(1), 0);
+        }
+
+        // Every field affects the hash.
+        let dt: MockDateTime = "2020-10-14T13:21:05".parse().unwrap();
+        let others = &[
+            "2021-10-14T13:21:05",
+            "2020-11-14T13:21:05",
+            "2020-10-15T13:21:05",
+            "2020-10-14T14:21:05",
+            "2020-10-14T13:22:05",
+            "2020-10-14T13:21:06",
+        ];
+        for other in others {
+            let other: MockDateTime = other.parse().unwrap();
+            assert_ne!(dt.stable_hash(), other.stable_hash());
+        }
+    }
+
+    #[test]
+    fn test_parse_flexible() {
+        let date_time: MockDateTime = "2020-10-14T13:21:05".parse().unwrap();
+        let (date, time) = date_time.split();
+        assert_eq!(
+            parse_flexible("2020-10-14").unwrap(),
+            ParsedValue::Date(date)
+        );
+        assert_eq!(parse_flexible("13:21:05").unwrap(), ParsedValue::Time(time));
+        assert_eq!(
+            parse_flexible("2020-10-14T13:21:05").unwrap(),
+            ParsedValue::DateTime(date_time)
+        );
+        assert_eq!(
+            parse_flexible("-0044-03-15").unwrap(),
+            ParsedValue::Date(Date {
+                year: -44,
+                month: Month::new_unchecked(2),
+                day: Day::new_unchecked(14),
+            })
+        );
+        assert_eq!(
+            parse_flexible("00:00:00").unwrap(),
+            ParsedValue::Time(Time::default())
+        );
+
+        // The absent fields aren't filled with defaults.
+        for input in &[
+            "2020",
+            "2020-10",
+            "13",
+            "13:21",
+            "2020-10-14T13:21",
+            "2020-10T13:21:05",
+            "2021-02-29",
+            "2020-10-14 13:21:05",
+            "",
+        ] {
+            assert!(parse_flexible(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_age_at() {
+        let birth: MockDateTime = "2003-05-10T00:00:00".parse().unwrap();
+        let samples = &[
+            // The birthday starts at 19:00 UTC at +05:00.
+            ("2021-05-09T18:59:59", "+05:00", 17),
+            ("2021-05-09T19:00:00", "+05:00", 18),
+            ("2021-05-09T19:00:00", "Z", 17),
+            ("2021-05-10T00:00:00", "Z", 18),
+            // The birthday starts at 05:00 UTC at -05:00.
+            ("2021-05-10T04:59:59", "-05:00", 17),
+            ("2021-05-10T05:00:00", "-05:00", 18),
+            // Across the end of the year.
+            ("2020-12-31T23:30:00", "+01:00", 17),
+            ("2003-05-09T23:59:59", "Z", 0),
+            ("2003-05-10T00:00:00", "Z", 0),
+            ("2000-01-01T00:00:00", "Z", 0),
+        ];
+        for (now, offset, expected) in samples {
+            let now: MockDateTime = now.parse().unwrap();
+            let offset: GmtOffset = offset.parse().unwrap();
+            assert_eq!(
+                now.age_at(&birth, offset),
+                *expected,
+                "{:?} {}",
+                now,
+                offset
+            );
+        }
+
+        // A person born on February 29th gets older on March 1st of common years.
+        let birth: MockDateTime = "2004-02-29T00:00:00".parse().unwrap();
+        let utc = GmtOffset::default();
+        let now: MockDateTime = "2022-02-28T12:00:00".parse().unwrap();
+        assert_eq!(now.age_at(&birth, utc), 17);
+        let now: MockDateTime = "2022-03-01T00:00:00".parse().unwrap();
+        assert_eq!(now.age_at(&birth, utc), 18);
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        let us: LanguageIdentifier = "en-US".parse().unwrap();
+        let eg: LanguageIdentifier = "ar-EG".parse().unwrap();
+        // From Thursday, October 15th, to Monday, October 19th, 2020.
+        let samples = &[
+            ("2020-10-15T12:00:00", false, false),
+            ("2020-10-16T12:00:00", false, true),
+            ("2020-10-17T12:00:00", true, true),
+            ("2020-10-18T12:00:00", true, false),
+            ("2020-10-19T12:00:00", false, false),
+        ];
+        for (input, us_weekend, eg_weekend) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.is_weekend(&us), *us_weekend, "{}", input);
+            assert_eq!(dt.is_weekend(&eg), *eg_weekend, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_from_str_separators() {
+        let samples = &[
+            ("2020/10-14T13:21:05", 4, '-'),
+            ("2020-10/14T13:21:05", 7, '-'),
+            ("2020-10-14 13:21:05", 10, 'T'),
+            ("2020-10-14T13.21:05", 13, ':'),
+            ("2020-10-14T13:21.05", 16, ':'),
+            ("-0044-03/15T12:00:00", 8, '-'),
+            // The first mismatch is reported.
+            ("2020/10/14 13.21.05", 4, '-'),
+            // A truncated input is missing a separator.
+            ("2020-10-14", 10, 'T'),
+        ];
+        for (input, position, expected) in samples {
+            match input.parse::<MockDateTime>() {
+                Err(DateTimeError::InvalidSeparator {
+                    position: p,
+                    expected: e,
+                }) => assert_eq!((p, e), (*position, *expected), "{}", input),
+                result => panic!("{}: unexpected result {:?}", input, result),
+            }
+        }
+
+        for input in &[
+            "2020-10-14T13:21:0",
+            "2020-10-14T13:21:0é",
+            "2020-10-14T13:21:00garbage",
+            "2020-10-14T13:21:00Z",
+            "2020-10-14T13:21:00.",
+            "2020-10-14T13:21:00.5Z",
+            "-0044-03-15T12:00:00 ",
+        ] {
+            assert!(
+                matches!(
+                    input.parse::<MockDateTime>(),
+                    Err(DateTimeError::InvalidFormat)
+                ),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_fraction() {
+        let dt: MockDateTime = "2020-10-14T13:21:05.123".parse().unwrap();
+        assert_eq!(dt, "2020-10-14T13:21:05".parse().unwrap());
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        let samples = &[
+            "2020-10-14T13:21:05",
+            "2021-01-01T00:00:00",
+            "1999-12-31T23:59:59",
+            "-0044-03-15T12:00:00",
+        ];
+        for sample in samples {
+            let dt: MockDateTime = sample.parse().unwrap();
+            let (date, time) = dt.split();
+            assert_eq!(MockDateTime::join(date, time), dt, "{}", sample);
+        }
+
+        let (date, time) = MockDateTime::try_new(2020, 9, 13, 13, 21, 5)
+            .unwrap()
+            .split();
+        assert_eq!(
+            date,
+            Date {
+                year: 2020,
+                month: Month::new_unchecked(9),
+                day: Day::new_unchecked(13),
+            }
+        );
+        assert_eq!(
+            time,
+            Time {
+                hour: Hour::new_unchecked(13),
+                minute: Minute::new_unchecked(21),
+                second: Second::new_unchecked(5),
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_new_iso() {
+        let dt = MockDateTime::try_new_iso(2020, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(u8::from(dt.month), 0);
+        assert_eq!(u8::from(dt.day), 0);
+        assert_eq!(dt, "2020-01-01T00:00:00".parse().unwrap());
+
+        let samples = &[
+            ((2020, 2, 29), "2020-02-29T13:21:05"),
+            ((2020, 12, 31), "2020-12-31T13:21:05"),
+        ];
+        for ((year, month, day), expected) in samples {
+            assert_eq!(
+                MockDateTime::try_new_iso(*year, *month, *day, 13, 21, 5).unwrap(),
+                expected.parse().unwrap()
+            );
+        }
+
+        let invalid = &[
+            (2020, 0, 1),
+            (2020, 13, 1),
+            (2020, 1, 0),
+            (2021, 2, 29),
+            (2020, 4, 31),
+        ];
+        for (year, month, day) in invalid {
+            assert!(
+                MockDateTime::try_new_iso(*year, *month, *day, 0, 0, 0).is_err(),
+                "{}-{}-{}",
+                year,
+                month,
+                day
+            );
+        }
+        assert!(MockDateTime::try_new_iso(2020, 1, 1, 25, 0, 0).is_err());
+    }
+
+    #[test]
+    fn test_from_ordinal() {
+        let samples = &[
+            (2020, 1, "2020-01-01T00:00:00"),
+            (2020, 31, "2020-01-31T00:00:00"),
+            (2020, 32, "2020-02-01T00:00:00"),
+            (2020, 60, "2020-02-29T00:00:00"),
+            (2021, 60, "2021-03-01T00:00:00"),
+            (2020, 366, "2020-12-31T00:00:00"),
+            (2021, 365, "2021-12-31T00:00:00"),
+            (-44, 75, "-0044-03-15T00:00:00"),
+        ];
+        for (year, day_of_year, expected) in samples {
+            let expected: MockDateTime = expected.parse().unwrap();
+            assert_eq!(
+                MockDateTime::from_ordinal(*year, *day_of_year).unwrap(),
+                expected,
+                "{} {}",
+                year,
+                day_of_year
+            );
+        }
+
+        for (year, day_of_year, max) in &[(2021, 366, 365), (2020, 367, 366), (2020, 0, 366)] {
+            let err = MockDateTime::from_ordinal(*year, *day_of_year).unwrap_err();
+            assert!(
+                matches!(err, DateTimeError::Overflow { field: "DayOfYear", max: m } if m == *max),
+                "{:?}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_ordinal_string() {
+        let samples = &[
+            ("2020-01-01T00:00:00", "2020-001", 1),
+            ("2020-02-01T13:21:00", "2020-032", 32),
+            ("2020-12-31T23:59:59", "2020-366", 366),
+            ("2021-12-31T00:00:00", "2021-365", 365),
+            ("-0044-03-15T00:00:00", "-000044-075", 75),
+        ];
+        for (input, expected, day_of_year) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.to_ordinal_string(), *expected);
+            // The ordinal date round-trips, at midnight.
+            let date = MockDateTime::from_ordinal(dt.year, *day_of_year).unwrap();
+            assert_eq!(date.split().0, dt.split().0, "{}", input);
+            assert_eq!(date.to_ordinal_string(), *expected);
+        }
+    }
+
+    #[test]
+    fn test_from_unix_timestamp_f64() {
+        let samples = &[
+            (0.0, "1970-01-01T00:00:00"),
+            (1602681660.25, "2020-10-14T13:21:00"),
+            (1602681660.999, "2020-10-14T13:21:00"),
+            (951782400.0, "2000-02-29T00:00:00"),
+            (-0.5, "1969-12-31T23:59:59"),
+            (-86400.0, "1969-12-31T00:00:00"),
+            (-1234567890.75, "1930-11-18T00:28:29"),
+            (-62135596800.0, "0001-01-01T00:00:00"),
+            (-62167219200.0, "0000-01-01T00:00:00"),
+            (-62198755200.0, "-0001-01-01T00:00:00"),
+            (253402300799.0, "9999-12-31T23:59:59"),
+        ];
+        for (timestamp, expected) in samples {
+            let expected: MockDateTime = expected.parse().unwrap();
+            assert_eq!(
+                MockDateTime::from_unix_timestamp_f64(*timestamp).unwrap(),
+                expected,
+                "{}",
+                timestamp
+            );
+        }
+
+        for timestamp in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, -1e17] {
+            assert!(
+                matches!(
+                    MockDateTime::from_unix_timestamp_f64(*timestamp),
+                    Err(DateTimeError::InvalidTimestamp(_))
+                ),
+                "{}",
+                timestamp
+            );
+        }
+    }
+
+    #[test]
+    fn test_seconds_since_epoch() {
+        for timestamp in &[0.0, 1602681660.0, -1.0, 951782400.0, -62135596800.0] {
+            let dt = MockDateTime::from_unix_timestamp_f64(*timestamp).unwrap();
+            assert_eq!(dt.seconds_since_epoch(), *timestamp as i64, "{}", timestamp);
+        }
+    }
+
+    #[test]
+    fn test_complete_periods() {
+        let samples = &[
+            // The final month is partial.
+            (
+                "2020-01-15T00:00:00",
+                "2020-04-14T23:59:59",
+                TimeUnit::Month,
+                2,
+            ),
+            (
+                "2020-01-15T00:00:00",
+                "2020-04-15T00:00:00",
+                TimeUnit::Month,
+                3,
+            ),
+            (
+                "2020-01-31T00:00:00",
+                "2020-02-29T00:00:00",
+                TimeUnit::Month,
+                0,
+            ),
+            (
+                "2020-01-31T00:00:00",
+                "2020-03-01T00:00:00",
+                TimeUnit::Month,
+                1,
+            ),
+            (
+                "2019-11-20T12:00:00",
+                "2021-02-20T11:00:00",
+                TimeUnit::Month,
+                14,
+            ),
+            (
+                "2019-11-20T12:00:00",
+                "2021-02-20T11:00:00",
+                TimeUnit::Year,
+                1,
+            ),
+            (
+                "2020-10-14T13:21:00",
+                "2020-10-14T13:21:00",
+                TimeUnit::Month,
+                0,
+            ),
+            (
+                "2020-02-01T00:00:00",
+                "2020-03-01T00:00:00",
+                TimeUnit::Day,
+                29,
+            ),
+            (
+                "2020-02-01T00:00:00",
+                "2020-03-01T00:00:00",
+                TimeUnit::Week,
+                4,
+            ),
+            (
+                "2020-12-31T23:00:00",
+                "2021-01-01T01:30:00",
+                TimeUnit::Hour,
+                2,
+            ),
+            (
+                "2020-12-31T23:00:00",
+                "2021-01-01T01:30:00",
+                TimeUnit::Minute,
+                150,
+            ),
+            (
+                "-0001-12-31T00:00:00",
+                "0001-01-01T00:00:00",
+                TimeUnit::Day,
+                367,
+            ),
+        ];
+        for (start, end, unit, expected) in samples {
+            let start: MockDateTime = start.parse().unwrap();
+            let end: MockDateTime = end.parse().unwrap();
+            assert_eq!(
+                start.complete_periods(&end, *unit),
+                *expected,
+                "{:?} {:?}",
+                start,
+                unit
+            );
+            assert_eq!(end.complete_periods(&start, *unit), *expected);
+        }
+    }
+
+    #[test]
+    fn test_diff_in() {
+        let samples = &[
+            (
+                "2020-10-14T13:00:00",
+                "2020-10-14T14:30:00",
+                TimeUnit::Hour,
+                1.5,
+            ),
+            (
+                "2020-10-14T00:00:00",
+                "2020-10-15T12:00:00",
+                TimeUnit::Day,
+                1.5,
+            ),
+            (
+                "2020-10-15T12:00:00",
+                "2020-10-14T00:00:00",
+                TimeUnit::Day,
+                -1.5,
+            ),
+            (
+                "2020-10-14T00:00:00",
+                "2020-10-24T12:00:00",
+                TimeUnit::Week,
+                1.5,
+            ),
+            (
+                "2020-10-14T13:21:00",
+                "2020-10-14T13:21:45",
+                TimeUnit::Minute,
+                0.75,
+            ),
+            (
+                "2020-10-14T13:21:00",
+                "2020-10-14T13:21:00",
+                TimeUnit::Second,
+                0.0,
+            ),
+            (
+                "2000-01-01T00:00:00",
+                "2400-01-01T00:00:00",
+                TimeUnit::Year,
+                400.0,
+            ),
+            (
+                "2000-01-01T00:00:00",
+                "2400-01-01T00:00:00",
+                TimeUnit::Month,
+                4800.0,
+            ),
+        ];
+        for (start, end, unit, expected) in samples {
+            let start: MockDateTime = start.parse().unwrap();
+            let end: MockDateTime = end.parse().unwrap();
+            assert_eq!(
+                start.diff_in(&end, *unit),
+                *expected,
+                "{:?} {:?}",
+                start,
+                unit
+            );
+        }
+
+        let start: MockDateTime = "2020-01-01T00:00:00".parse().unwrap();
+        let end: MockDateTime = "2021-01-01T00:00:00".parse().unwrap();
+        let years = start.diff_in(&end, TimeUnit::Year);
+        assert!((years - 366.0 / 365.2425).abs() < 1e-12, "{}", years);
+    }
+
+    #[test]
+    fn test_to_iso8601() {
+        let dt: MockDateTime = "2020-10-14T13:21:05".parse().unwrap();
+        let samples = &[
+            (Precision::Year, "2020"),
+            (Precision::Month, "2020-10"),
+            (Precision::Day, "2020-10-14"),
+            (Precision::Hour, "2020-10-14T13"),
+            (Precision::Minute, "2020-10-14T13:21"),
+            (Precision::Second, "2020-10-14T13:21:05"),
+        ];
+        for (precision, expected) in samples {
+            assert_eq!(dt.to_iso8601(*precision), *expected);
+        }
+
+        let dt: MockDateTime = "-0044-03-15T12:00:00".parse().unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Day), "-000044-03-15");
+        let dt = MockDateTime::from_ordinal(12020, 1).unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Year), "+012020");
+
+        // The years beyond six digits are written in full.
+        let dt = MockDateTime::try_new(12_000_000, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Second), "+12000000-01-01T00:00:00");
+        assert_eq!(
+            dt.to_iso8601(Precision::Second)
+                .parse::<MockDateTime>()
+                .unwrap(),
+            dt
+        );
+        assert_eq!(dt.to_iso8601_basic(), "+120000000101T000000");
+        assert_eq!(dt.to_ordinal_string(), "+12000000-001");
+        let dt = MockDateTime::try_new(i32::MIN, 0, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Year), "-2147483648");
+    }
+
+    #[test]
+    fn test_packed_bytes() {
+        let samples = &[
+            ("2020-10-14T13:21:05", [0x07, 0xE4, 9, 13, 13, 21, 5]),
+            ("0000-01-01T00:00:00", [0, 0, 0, 0, 0, 0, 0]),
+            ("2020-02-29T23:59:59", [0x07, 0xE4, 1, 28, 23, 59, 59]),
+        ];
+        for (input, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let bytes = <[u8; 7]>::try_from(&dt).unwrap();
+            assert_eq!(bytes, *expected, "{}", input);
+            assert_eq!(MockDateTime::try_from(bytes).unwrap(), dt, "{}", input);
+        }
+        let dt = MockDateTime::try_new(65535, 11, 30, 0, 0, 0).unwrap();
+        let bytes = <[u8; 7]>::try_from(&dt).unwrap();
+        assert_eq!(MockDateTime::try_from(bytes).unwrap(), dt);
+
+        for year in &[65536, -1] {
+            let dt = MockDateTime::try_new(*year, 0, 0, 0, 0, 0).unwrap();
+            assert!(matches!(
+                <[u8; 7]>::try_from(&dt),
+                Err(DateTimeError::Overflow {
+                    field: "Year",
+                    max: 65535,
+                })
+            ));
+        }
+
+        // The fields are validated when unpacking.
+        for bytes in &[
+            [0x07, 0xE4, 12, 0, 0, 0, 0],
+            [0x07, 0xE5, 1, 28, 0, 0, 0],
+            [0x07, 0xE4, 0, 0, 25, 0, 0],
+            [0x07, 0xE4, 0, 0, 0, 0, 61],
+        ] {
+            assert!(MockDateTime::try_from(*bytes).is_err(), "{:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_iso8601_basic() {
+        let samples = &[
+            ("2020-10-14T13:21:05", "20201014T132105"),
+            ("0005-01-01T00:00:00", "00050101T000000"),
+            ("-0044-03-15T12:00:00", "-0000440315T120000"),
+            ("+010000-12-31T23:59:59", "+0100001231T235959"),
+        ];
+        for (input, expected) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            assert_eq!(dt.to_iso8601_basic(), *expected);
+            assert_eq!(MockDateTime::from_iso8601_basic(expected).unwrap(), dt);
+        }
+
+        assert!(matches!(
+            MockDateTime::from_iso8601_basic("20201014 132105"),
+            Err(DateTimeError::InvalidSeparator {
+                position: 8,
+                expected: 'T',
+            })
+        ));
+        for input in &[
+            "2020-10-14T13:21:05",
+            "202001014T132105",
+            "20201014T13210",
+            "20201314T132105",
+            "20201014T1321+5",
+            "+2020101é4T132105",
+            "",
+        ] {
+            assert!(
+                MockDateTime::from_iso8601_basic(input).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_expanded_year() {
+        let samples = &[
+            ("+010000-01-01T00:00:00", 10000, 0, 0),
+            ("-000001-12-31T00:00:00", -1, 11, 30),
+            ("+2020-10-14T13:21:00", 2020, 9, 13),
+            ("-0044-03-15T00:00:00", -44, 2, 14),
+        ];
+        for (input, year, month, day) in samples {
+            let dt: MockDateTime = input.parse().unwrap();
+            let expected = MockDateTime::try_new(*year, *month, *day, 0, 0, 0).unwrap();
+            assert_eq!(dt.split().0, expected.split().0, "{}", input);
+        }
+
+        let dt: MockDateTime = "+010000-01-01T00:00:00".parse().unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Second), "+010000-01-01T00:00:00");
+        let dt: MockDateTime = "-000001-12-31T23:59:59".parse().unwrap();
+        assert_eq!(dt.to_iso8601(Precision::Day), "-000001-12-31");
+        assert_eq!(
+            dt.to_iso8601(Precision::Second)
+                .parse::<MockDateTime>()
+                .unwrap(),
+            dt
+        );
+
+        // Only signed years may have more than four digits.
+        assert!(matches!(
+            "010000-01-01T00:00:00".parse::<MockDateTime>(),
+            Err(DateTimeError::InvalidSeparator { position: 4, .. })
+        ));
+        assert!(matches!(
+            "+010000/01-01T00:00:00".parse::<MockDateTime>(),
+            Err(DateTimeError::InvalidSeparator { position: 7, .. })
+        ));
+        assert!("+9999999999-01-01T00:00:00"
+            .parse::<MockDateTime>()
+            .is_err());
+
+        for input in &[
+            "--0044-03-15T00:00:00",
+            "+-0044-03-15T00:00:00",
+            "-044-03-15T00:00:00",
+            "-",
+            "+",
+            "+010000-01-01T00:00:00Z",
+            "-000001-12-31T23:59:59+01:00",
+            "+010000-01-01T00:00:00-",
+        ] {
+            assert!(input.parse::<MockDateTime>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_days_in() {
+        let february = Month::new_unchecked(1);
+        let days: Vec<u8> = days_in(2020, february).map(u8::from).collect();
+        assert_eq!(days, (0..29).collect::<Vec<_>>());
+        assert_eq!(days_in(2021, february).count(), 28);
+        assert_eq!(days_in(1900, february).count(), 28);
+        assert_eq!(days_in(2000, february).count(), 29);
+        assert_eq!(days_in(2021, Month::new_unchecked(3)).count(), 30);
+        assert_eq!(days_in(2021, Month::new_unchecked(11)).count(), 31);
+    }
+
+    #[test]
+    fn test_next_and_prev_month() {
+        let january = Month::new_unchecked(0);
+        let june = Month::new_unchecked(5);
+        let july = Month::new_unchecked(6);
+        let december = Month::new_unchecked(11);
+        assert_eq!(next_month(2020, june), (2020, july));
+        assert_eq!(next_month(2020, december), (2021, january));
+        assert_eq!(next_month(-1, december), (0, january));
+        assert_eq!(prev_month(2020, july), (2020, june));
+        assert_eq!(prev_month(2021, january), (2020, december));
+        assert_eq!(prev_month(0, january), (-1, december));
+
+        let (year, month) = next_month(2020, december);
+        let dt = MockDateTime::new(
+            year,
+            month,
+            Day::new_unchecked(0),
+            Hour::new_unchecked(0),
+            Minute::new_unchecked(0),
+            Second::new_unchecked(0),
+        );
+        assert_eq!(dt, "2021-01-01T00:00:00".parse().unwrap());
+    }
+
+    #[test]
+    fn test_as_human() {
+        assert_eq!(Month::new_unchecked(9).as_human(), 10);
+        assert_eq!(u8::from(Month::new_unchecked(9)), 9);
+        assert_eq!(Day::new_unchecked(0).as_human(), 1);
+        assert_eq!(Day::new_unchecked(30).as_human(), 31);
+        assert_eq!(Hour::new_unchecked(0).as_human(), 0);
+        assert_eq!(Minute::new_unchecked(59).as_human(), 59);
+        assert_eq!(WeekDay::new_unchecked(3).as_human(), 3);
+    }
+
+    #[test]
+    fn test_month_lengths() {
+        assert_eq!(
+            MONTH_LENGTHS
+                .iter()
+                .map(|days| u16::from(*days))
+                .sum::<u16>(),
+            365
+        );
+        let february = Month::new_unchecked(1);
+        assert_eq!(days_in_month(2020, february), 29);
+        assert_eq!(days_in_month(2019, february), 28);
+        assert_eq!(days_in_month(1900, february), 28);
+        assert_eq!(days_in_month(2000, february), 29);
+        for year in &[2019, 2020] {
+            let days: u16 = (0..12)
+                .map(|month| u16::from(days_in_month(*year, Month::new_unchecked(month))))
+                .sum();
+            assert_eq!(days, if is_leap_year(*year) { 366 } else { 365 });
+        }
+    }
+
+    #[test]
+    fn test_day_new_for_month() {
+        let april = Month::new_unchecked(3);
+        let february = Month::new_unchecked(1);
+        assert_eq!(u8::from(Day::new_for_month(29, 2021, april).unwrap()), 29);
+        // April 31st.
+        assert!(matches!(
+            Day::new_for_month(30, 2021, april),
+            Err(DateTimeError::Overflow {
+                field: "Day",
+                max: 29
+            })
+        ));
+        // February 29th.
+        assert!(Day::new_for_month(28, 2020, february).is_ok());
+        assert!(Day::new_for_month(28, 2021, february).is_err());
+        assert!(Day::new_for_month(28, 1900, february).is_err());
+    }
+
+    #[test]
+    fn test_with_fields() {
+        let dt: MockDateTime = "2020-10-14T13:21:05".parse().unwrap();
+
+        let updated = dt
+            .clone()
+            .with_fields(None, Some(1), Some(28), None, None, None)
+            .unwrap();
+        assert_eq!(updated, "2020-02-29T13:21:05".parse().unwrap());
+
+        let updated = dt
+            .clone()
+            .with_fields(Some(1999), None, None, Some(0), Some(0), Some(0))
+            .unwrap();
+        assert_eq!(updated, "1999-10-14T00:00:00".parse().unwrap());
+
+        assert_eq!(
+            dt.clone()
+                .with_fields(None, None, None, None, None, None)
+                .unwrap(),
+            dt
+        );
+
+        let err = dt
+            .with_fields(None, Some(1), Some(28), Some(25), None, None)
+            .unwrap_err();
+        assert!(
+            matches!(err, DateTimeError::Overflow { max: 24, .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_from_rfc2822() {
+        let samples = &[
+            ("Wed, 14 Oct 2020 13:21:00 +0000", "2020-10-14T13:21:00"),
+            ("14 Oct 2020 13:21:00 GMT", "2020-10-14T13:21:00"),
+            ("wed, 14 oct 2020 13:21 UT", "2020-10-14T13:21:00"),
+            ("Wed,  14 Oct 2020  13:21:05 +0530", "2020-10-14T07:51:05"),
+            ("Thu, 31 Dec 2020 22:00:00 -0300", "2021-01-01T01:00:00"),
+            ("Fri, 1 Jan 2021 01:00:00 +0200", "2020-12-31T23:00:00"),
+            ("Sat, 29 Feb 2020 12:00:00 +0000", "2020-02-29T12:00:00"),
+        ];
+        for (input, expected) in samples {
+            let expected: MockDateTime = expected.parse().unwrap();
+            assert_eq!(
+                MockDateTime::from_rfc2822(input).unwrap(),
+                expected,
+                "{}",
+                input
+            );
+        }
+
+        let err = MockDateTime::from_rfc2822("Thu, 14 Oct 2020 13:21:00 +0000").unwrap_err();
+        assert!(
+            matches!(
+                err,
+                DateTimeError::WeekdayMismatch { expected, found }
+                    if expected == WeekDay::new_unchecked(3) && found == WeekDay::new_unchecked(4)
+            ),
+            "{:?}",
+            err
+        );
+
+        let invalid = &[
+            "Wed 14 Oct 2020 13:21:00 +0000",
+            "Wed, 14 Okt 2020 13:21:00 +0000",
+            "Wed, 14 Oct 20 13:21:00 +0000",
+            "Wed, 14 Oct 2020 13:21:00",
+            "Wed, 14 Oct 2020 13:21:00 +00",
+            "Wed, 14 Oct 2020 13:21:00:00 +0000",
+            "Sun, 30 Feb 2020 13:21:00 +0000",
+        ];
+        for input in invalid {
+            assert!(MockDateTime::from_rfc2822(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        let separators = Separators::default();
+        let samples = &[
+            ("2020-10-14", "2020-10-14T00:00:00"),
+            ("2020/10/14", "2020-10-14T00:00:00"),
+            ("2020.10.14", "2020-10-14T00:00:00"),
+            ("2020/1/5", "2020-01-05T00:00:00"),
+            ("2020/10/14 13:21:00", "2020-10-14T13:21:00"),
+            ("2020.10.14T13:21:05", "2020-10-14T13:21:05"),
+            ("-44.3.15", "-0044-03-15T00:00:00"),
+        ];
+        for (input, expected) in samples {
+            assert_eq!(
+                MockDateTime::parse_lenient(input, &separators).unwrap(),
+                expected.parse().unwrap(),
+                "{}",
+                input
+            );
+        }
+
+        assert!(matches!(
+            MockDateTime::parse_lenient("2020/10-14", &separators),
+            Err(DateTimeError::InvalidSeparator {
+                position: 7,
+                expected: '/',
+            })
+        ));
+        for input in &[
+            "2020/10/14 13.21.00",
+            "2020/10/14 13:21",
+            "2020/10/14 ",
+            "2020/10/",
+            "2020/13/14",
+            "2020 10 14",
+        ] {
+            assert!(
+                MockDateTime::parse_lenient(input, &separators).is_err(),
+                "{}",
+                input
+            );
+        }
+
+        let separators = Separators {
+            date: vec![' '],
+            time: vec!['.'],
+        };
+        assert_eq!(
+            MockDateTime::parse_lenient("2020 10 14T13.21.00", &separators).unwrap(),
+            "2020-10-14T13:21:00".parse().unwrap()
+        );
+        assert!(MockDateTime::parse_lenient("2020/10/14", &separators).is_err());
+    }
+
+    #[test]
+    fn test_parse_lenient_max_len() {
+        let separators = Separators::default();
+        let input = format!("{}2020/10/14", "0".repeat(10 * 1024));
+        assert!(matches!(
+            MockDateTime::parse_lenient(&input, &separators),
+            Err(DateTimeError::InvalidFormat)
+        ));
+
+        // The leading zeros are accepted within the maximum length.
+        let input = format!("{}2020/10/14", "0".repeat(54));
+        assert_eq!(input.len(), MockDateTime::DEFAULT_MAX_LENIENT_LEN);
+        assert_eq!(
+            MockDateTime::parse_lenient(&input, &separators).unwrap(),
+            "2020-10-14T00:00:00".parse().unwrap()
+        );
+
+        let input = format!("{}2020/10/14", "0".repeat(10 * 1024));
+        assert!(MockDateTime::parse_lenient_with_max_len(&input, &separators, usize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_from_rfc3339() {
+        let samples = &[
+            ("2020-10-14T13:21:00Z", "2020-10-14T13:21:00"),
+            ("2020-10-14 13:21:00Z", "2020-10-14T13:21:00"),
+            ("2020-10-14t13:21:00z", "2020-10-14T13:21:00"),
+            ("2020-10-14T13:21:05.123+05:30", "2020-10-14T07:51:05"),
+            ("2020-10-14 13:21:05.123+05:30", "2020-10-14T07:51:05"),
+            ("2020-12-31T22:00:00-03:00", "2021-01-01T01:00:00"),
+            ("2016-12-31 23:59:60Z", "2016-12-31T23:59:60"),
+        ];
+        for (input, expected) in samples {
+            let expected: MockDateTime = expected.parse().unwrap();
+            assert_eq!(
+                MockDateTime::from_rfc3339(input).unwrap(),
+                expected,
+                "{}",
+                input
+            );
+        }
+
+        let invalid = &[
+            "2020-10-14T13:21:00",
+            "2020-10-14  13:21:00Z",
+            "2020-10-14_13:21:00Z",
+            "2020-10-14T13:21Z",
+            "2020-10-14T13:21:00.Z",
+            "2020-10-14T13:21:00+0530",
+            "2020-02-30T13:21:00Z",
+            "2020-10-14",
+        ];
+        for input in invalid {
+            assert!(MockDateTime::from_rfc3339(input).is_err(), "{}", input);
+        }
+        // Unlike the ISO 8601 parser, an offset is required.
+        assert!("2020-10-14T13:21:00".parse::<MockDateTime>().is_ok());
+        assert!(matches!(
+            MockDateTime::from_rfc3339("2020-10-14T13:21:00"),
+            Err(DateTimeError::InvalidFormat)
+        ));
+        // The strict ISO 8601 parser still requires a `T`.
+        assert!("2020-10-14 13:21:00".parse::<MockDateTime>().is_err());
+    }
+
+    #[test]
+    fn test_leap_years_in() {
+        let years: Vec<i32> = leap_years_in(1996..=2004).collect();
+        assert_eq!(years, vec![1996, 2000, 2004]);
+
+        let years: Vec<i32> = leap_years_in(1897..=1903).collect();
+        assert_eq!(years, Vec::<i32>::new());
+
+        assert_eq!(leap_years_in(2001..=2003).count(), 0);
+    }
+}
@@ -0,0 +1,213 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use super::DateTimeError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The offset of a local time from GMT (UTC), stored in seconds.
+///
+/// Offsets are bounded to the range of `-18:00` to `+18:00`.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::GmtOffset;
+///
+/// let offset: GmtOffset = "+05:30".parse()
+///     .expect("Failed to parse a GMT offset.");
+/// assert_eq!(offset.seconds(), 19800);
+/// assert_eq!(offset.to_string(), "+05:30");
+///
+/// assert_eq!(GmtOffset::default().to_string(), "Z");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GmtOffset(i32);
+
+impl GmtOffset {
+    /// The largest magnitude of an offset, in seconds.
+    pub const MAX_SECONDS: i32 = 18 * 60 * 60;
+
+    /// Creates a new `GmtOffset` from a number of seconds east of GMT.
+    pub fn try_new(seconds: i32) -> Result<Self, DateTimeError> {
+        if seconds.abs() > Self::MAX_SECONDS {
+            Err(DateTimeError::InvalidOffset(seconds))
+        } else {
+            Ok(Self(seconds))
+        }
+    }
+
+    /// Returns the number of seconds east of GMT.
+    pub fn seconds(self) -> i32 {
+        self.0
+    }
+
+    /// Returns `true` if the offset is zero.
+    pub fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl fmt::Display for GmtOffset {
+    /// Formats the offset in the ISO 8601 extended format, `±hh:mm`, using `Z` for
+    /// a zero offset. Seconds are included only if they are non-zero.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.write_str("Z");
+        }
+        let sign = if self.0 < 0 { '-' } else { '+' };
+        let seconds = self.0.abs();
+        write!(f, "{}{:02}:{:02}", sign, seconds / 3600, seconds / 60 % 60)?;
+        if seconds % 60 != 0 {
+            write!(f, ":{:02}", seconds % 60)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GmtOffset {
+    type Err = DateTimeError;
+
+    /// Parse a `GmtOffset` from a string.
+    ///
+    /// The string must be either `Z`, or a sign followed by hours and optional minutes
+    /// and seconds, in either the basic (`+0530`) or extended (`+05:30`) ISO 8601 format.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let sign = match input.as_bytes().first() {
+            Some(b'Z') if input.len() == 1 => return Ok(Self(0)),
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return Err(DateTimeError::InvalidFormat),
+        };
+        let parts = &input[1..];
+        let digits: String = if parts.contains(':') {
+            let mut groups = parts.split(':');
+            let mut digits = String::with_capacity(6);
+            for group in &mut groups {
+                if group.len() != 2 {
+                    return Err(DateTimeError::InvalidFormat);
+                }
+                digits.push_str(group);
+            }
+            digits
+        } else {
+            parts.to_string()
+        };
+        if !digits.bytes().all(|b| b.is_ascii_digit()) || ![2, 4, 6].contains(&digits.len()) {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        let hours: i32 = digits[0..2].parse()?;
+        let minutes: i32 = digits.get(2..4).map_or(Ok(0), str::parse)?;
+        let seconds: i32 = digits.get(4..6).map_or(Ok(0), str::parse)?;
+        if minutes > 59 || seconds > 59 {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        Self::try_new(sign * (hours * 3600 + minutes * 60 + seconds))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for GmtOffset {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GmtOffset {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct GmtOffsetVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GmtOffsetVisitor {
+            type Value = GmtOffset;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "a valid ISO 8601 GMT offset")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                s.parse::<GmtOffset>().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_string(GmtOffsetVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let samples = &[
+            ("Z", 0),
+            ("+00:00", 0),
+            ("-00:00", 0),
+            ("+05", 18000),
+            ("+0530", 19800),
+            ("+05:30", 19800),
+            ("-08:00", -28800),
+            ("+00:19:32", 1172),
+            ("-001932", -1172),
+            ("+18:00", 64800),
+        ];
+        for (input, seconds) in samples {
+            let offset: GmtOffset = input.parse().unwrap();
+            assert_eq!(offset.seconds(), *seconds, "{}", input);
+        }
+
+        let broken = &[
+            "", "z", "05:30", "+5:30", "+05:3", "+05:60", "+0530:00", "+05:30:", "+18:01", "+1a",
+        ];
+        for input in broken {
+            assert!(input.parse::<GmtOffset>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_to_string() {
+        let samples = &[
+            (0, "Z"),
+            (19800, "+05:30"),
+            (-28800, "-08:00"),
+            (-1800, "-00:30"),
+            (1172, "+00:19:32"),
+        ];
+        for (seconds, expected) in samples {
+            assert_eq!(GmtOffset::try_new(*seconds).unwrap().to_string(), *expected);
+        }
+    }
+
+    fn quarter_hour_offsets() -> impl Iterator<Item = GmtOffset> {
+        (-14 * 4..=14 * 4).map(|quarters| GmtOffset::try_new(quarters * 15 * 60).unwrap())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for offset in quarter_hour_offsets() {
+            assert_eq!(offset.to_string().parse::<GmtOffset>().unwrap(), offset);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        for offset in quarter_hour_offsets() {
+            let json = serde_json::to_string(&offset)?;
+            assert_eq!(json, format!("\"{}\"", offset));
+            assert_eq!(serde_json::from_str::<GmtOffset>(&json)?, offset);
+        }
+        assert!(serde_json::from_str::<GmtOffset>(r#""+25:00""#).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,456 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use super::{days_in_month, Day, GmtOffset, Hour, MockDateTime, Month, WeekDay};
+use icu_locid::subtags::Region;
+
+/// A change of the offset of a time zone, such as the start of daylight saving time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Transition {
+    /// The wall-clock time at which the transition happens, in the offset before it.
+    pub local_time: MockDateTime,
+    pub offset_before: GmtOffset,
+    pub offset_after: GmtOffset,
+}
+
+/// Rules describing the offset transitions of a time zone.
+///
+/// *Notice:* This is a placeholder until ICU4X supports time zones, which allows validating
+/// wall-clock times with [`is_local_time_representable`], and finding the intervals which
+/// span a change of offset with [`contains_transition`].
+///
+/// The transitions recur every year, as those of daylight saving time do, so that
+/// [`contains_transition`] only checks one of the years entirely within an interval.
+pub trait TransitionRules {
+    /// Returns the transitions happening during the `year`, in chronological order.
+    fn transitions_in_year(&self, year: i32) -> Vec<Transition>;
+}
+
+/// A transition recurring every year on a given occurrence of a weekday in a month,
+/// such as the second Sunday of March at 02:00.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecurringTransition {
+    pub month: Month,
+    /// The occurrence of the `weekday` in the month, from `1` to `5`, where `5` stands
+    /// for the last one.
+    pub week: u8,
+    pub weekday: WeekDay,
+    /// The wall-clock hour at which the transition happens, in the offset before it.
+    pub hour: Hour,
+}
+
+impl RecurringTransition {
+    /// Returns the wall-clock time at which the transition happens during the `year`.
+    pub fn local_time(&self, year: i32) -> MockDateTime {
+        let first_day = MockDateTime::new(
+            year,
+            self.month,
+            Day::new_unchecked(0),
+            self.hour,
+            Default::default(),
+            Default::default(),
+        );
+        let first = if first_day.weekday() == self.weekday {
+            first_day
+        } else {
+            first_day.next_weekday(self.weekday)
+        };
+        let mut day = u8::from(first.day) + 7 * (self.week.max(1) - 1);
+        while day >= days_in_month(year, self.month) {
+            day -= 7;
+        }
+        MockDateTime {
+            day: Day::new_unchecked(day),
+            ..first
+        }
+    }
+}
+
+/// Transition rules with a fixed standard and daylight saving offset, which switch
+/// between them on the same days every year.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{
+///     is_local_time_representable, FixedTransitionRules, Hour, MockDateTime, Month,
+///     RecurringTransition, WeekDay,
+/// };
+///
+/// // The United States Eastern Time.
+/// let rules = FixedTransitionRules {
+///     standard_offset: "-05:00".parse().unwrap(),
+///     daylight_offset: "-04:00".parse().unwrap(),
+///     // The second Sunday of March, at 02:00.
+///     start: RecurringTransition {
+///         month: Month::new_unchecked(2),
+///         week: 2,
+///         weekday: WeekDay::new_unchecked(0),
+///         hour: Hour::new_unchecked(2),
+///     },
+///     // The first Sunday of November, at 02:00.
+///     end: RecurringTransition {
+///         month: Month::new_unchecked(10),
+///         week: 1,
+///         weekday: WeekDay::new_unchecked(0),
+///         hour: Hour::new_unchecked(2),
+///     },
+/// };
+///
+/// let dt: MockDateTime = "2021-03-14T02:30:00".parse()
+///     .expect("Failed to parse a date time.");
+/// assert!(!is_local_time_representable(&dt, &rules));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedTransitionRules {
+    pub standard_offset: GmtOffset,
+    pub daylight_offset: GmtOffset,
+    /// The transition from the standard to the daylight saving offset.
+    pub start: RecurringTransition,
+    /// The transition from the daylight saving to the standard offset.
+    pub end: RecurringTransition,
+}
+
+impl TransitionRules for FixedTransitionRules {
+    fn transitions_in_year(&self, year: i32) -> Vec<Transition> {
+        let start = Transition {
+            local_time: self.start.local_time(year),
+            offset_before: self.standard_offset,
+            offset_after: self.daylight_offset,
+        };
+        let end = Transition {
+            local_time: self.end.local_time(year),
+            offset_before: self.daylight_offset,
+            offset_after: self.standard_offset,
+        };
+        // In the southern hemisphere, daylight saving time ends before it starts.
+        if u8::from(self.start.month) <= u8::from(self.end.month) {
+            vec![start, end]
+        } else {
+            vec![end, start]
+        }
+    }
+}
+
+/// Returns `false` if the wall-clock `date_time` is skipped by one of the transitions of
+/// the `rules`, such as 02:30 on the day daylight saving time starts at 02:00.
+///
+/// Wall-clock times which occur twice, when the offset is moved backward, are representable.
+pub fn is_local_time_representable(date_time: &MockDateTime, rules: &impl TransitionRules) -> bool {
+    fn is_same_date(a: &MockDateTime, b: &MockDateTime) -> bool {
+        a.year == b.year && a.month == b.month && a.day == b.day
+    }
+
+    // A transition late in the previous year may skip the first hours of this one.
+    let transitions = rules
+        .transitions_in_year(date_time.year - 1)
+        .into_iter()
+        .chain(rules.transitions_in_year(date_time.year));
+    for transition in transitions {
+        let gap = transition.offset_after.seconds() - transition.offset_before.seconds();
+        if gap <= 0 {
+            continue;
+        }
+        let start = &transition.local_time;
+        let days = if is_same_date(date_time, start) {
+            0
+        } else {
+            let mut next_day = start.clone();
+            next_day.increment_day();
+            if !is_same_date(date_time, &next_day) {
+                continue;
+            }
+            1
+        };
+        let elapsed =
+            days * 24 * 3600 + date_time.seconds_of_day() as i32 - start.seconds_of_day() as i32;
+        if (0..gap).contains(&elapsed) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Returns `true` if one of the transitions of the `rules` changing the offset happens
+/// between the wall-clock times `start` and `end`, including `start` and excluding `end`,
+/// in either order.
+///
+/// Such an interval is shorter or longer than its wall-clock times suggest, such as the
+/// night from `2021-03-13T22:00:00` to `2021-03-14T06:00:00` in the United States, which
+/// lasts seven hours.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{
+///     contains_transition, FixedTransitionRules, Hour, MockDateTime, Month,
+///     RecurringTransition, WeekDay,
+/// };
+///
+/// // The United States Eastern Time.
+/// let rules = FixedTransitionRules {
+///     standard_offset: "-05:00".parse().unwrap(),
+///     daylight_offset: "-04:00".parse().unwrap(),
+///     start: RecurringTransition {
+///         month: Month::new_unchecked(2),
+///         week: 2,
+///         weekday: WeekDay::new_unchecked(0),
+///         hour: Hour::new_unchecked(2),
+///     },
+///     end: RecurringTransition {
+///         month: Month::new_unchecked(10),
+///         week: 1,
+///         weekday: WeekDay::new_unchecked(0),
+///         hour: Hour::new_unchecked(2),
+///     },
+/// };
+///
+/// let start: MockDateTime = "2021-03-13T22:00:00".parse()
+///     .expect("Failed to parse a date time.");
+/// let end: MockDateTime = "2021-03-14T06:00:00".parse()
+///     .expect("Failed to parse a date time.");
+/// assert!(contains_transition(&start, &end, &rules));
+/// ```
+pub fn contains_transition(
+    start: &MockDateTime,
+    end: &MockDateTime,
+    rules: &impl TransitionRules,
+) -> bool {
+    let (start, end) = if start <= end {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    let contains = |year: i32| {
+        rules
+            .transitions_in_year(year)
+            .into_iter()
+            .filter(|transition| transition.offset_before != transition.offset_after)
+            .any(|transition| (start..end).contains(&&transition.local_time))
+    };
+    // The years between the first and the last one are entirely within the interval, and
+    // have the same transitions, so one of them is enough.
+    contains(start.year)
+        || (end.year != start.year && contains(end.year))
+        || (i64::from(end.year) - i64::from(start.year) > 1 && contains(start.year + 1))
+}
+
+/// Provides the offset from GMT in effect in a region, such as `JP`, at a given instant.
+///
+/// *Notice:* This is a placeholder until ICU4X supports time zones, which allows creating
+/// the [`MockZonedDateTime`](super::MockZonedDateTime) whose offset fields are formatted,
+/// with [`MockZonedDateTime::from_utc_in_region`](super::MockZonedDateTime::from_utc_in_region).
+pub trait OffsetProvider {
+    /// Returns the offset in effect in the `region` at the UTC `instant`, or `None` if there
+    /// is no data for the region.
+    fn offset_for(&self, region: Region, instant: &MockDateTime) -> Option<GmtOffset>;
+}
+
+/// An [`OffsetProvider`] for regions without daylight saving time, whose offset is the same
+/// at every instant.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::{FixedOffsetProvider, MockDateTime, OffsetProvider};
+///
+/// let provider = FixedOffsetProvider {
+///     offsets: vec![("JP".parse().unwrap(), "+09:00".parse().unwrap())],
+/// };
+///
+/// let instant: MockDateTime = "2020-10-14T13:21:00".parse()
+///     .expect("Failed to parse a date time.");
+/// let offset = provider.offset_for("JP".parse().unwrap(), &instant);
+/// assert_eq!(offset, Some("+09:00".parse().unwrap()));
+/// assert_eq!(provider.offset_for("FR".parse().unwrap(), &instant), None);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FixedOffsetProvider {
+    /// The offset of each region.
+    pub offsets: Vec<(Region, GmtOffset)>,
+}
+
+impl OffsetProvider for FixedOffsetProvider {
+    fn offset_for(&self, region: Region, _instant: &MockDateTime) -> Option<GmtOffset> {
+        self.offsets
+            .iter()
+            .find(|(id, _)| *id == region)
+            .map(|(_, offset)| *offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us_eastern() -> FixedTransitionRules {
+        FixedTransitionRules {
+            standard_offset: "-05:00".parse().unwrap(),
+            daylight_offset: "-04:00".parse().unwrap(),
+            start: RecurringTransition {
+                month: Month::new_unchecked(2),
+                week: 2,
+                weekday: WeekDay::new_unchecked(0),
+                hour: Hour::new_unchecked(2),
+            },
+            end: RecurringTransition {
+                month: Month::new_unchecked(10),
+                week: 1,
+                weekday: WeekDay::new_unchecked(0),
+                hour: Hour::new_unchecked(2),
+            },
+        }
+    }
+
+    #[test]
+    fn test_recurring_transition_local_time() {
+        let rules = us_eastern();
+        let samples = &[
+            (2020, "2020-03-08T02:00:00", "2020-11-01T02:00:00"),
+            (2021, "2021-03-14T02:00:00", "2021-11-07T02:00:00"),
+        ];
+        for (year, start, end) in samples {
+            assert_eq!(rules.start.local_time(*year), start.parse().unwrap());
+            assert_eq!(rules.end.local_time(*year), end.parse().unwrap());
+        }
+
+        // The last Sunday of October.
+        let last_sunday = RecurringTransition {
+            month: Month::new_unchecked(9),
+            week: 5,
+            weekday: WeekDay::new_unchecked(0),
+            hour: Hour::new_unchecked(3),
+        };
+        assert_eq!(
+            last_sunday.local_time(2021),
+            "2021-10-31T03:00:00".parse().unwrap()
+        );
+        assert_eq!(
+            last_sunday.local_time(2022),
+            "2022-10-30T03:00:00".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_local_time_representable() {
+        let rules = us_eastern();
+        let samples = &[
+            ("2021-03-14T01:59:59", true),
+            ("2021-03-14T02:00:00", false),
+            ("2021-03-14T02:30:00", false),
+            ("2021-03-14T02:59:59", false),
+            ("2021-03-14T03:00:00", true),
+            ("2021-03-15T02:30:00", true),
+            ("2021-11-07T01:30:00", true),
+            ("2021-11-07T02:30:00", true),
+        ];
+        for (input, expected) in samples {
+            let date_time: MockDateTime = input.parse().unwrap();
+            assert_eq!(
+                is_local_time_representable(&date_time, &rules),
+                *expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_local_time_representable_across_midnight() {
+        let rules = FixedTransitionRules {
+            start: RecurringTransition {
+                hour: Hour::new_unchecked(23),
+                ..us_eastern().start
+            },
+            daylight_offset: "-03:00".parse().unwrap(),
+            ..us_eastern()
+        };
+        let samples = &[
+            ("2021-03-14T22:59:59", true),
+            ("2021-03-14T23:30:00", false),
+            ("2021-03-15T00:59:59", false),
+            ("2021-03-15T01:00:00", true),
+        ];
+        for (input, expected) in samples {
+            let date_time: MockDateTime = input.parse().unwrap();
+            assert_eq!(
+                is_local_time_representable(&date_time, &rules),
+                *expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_contains_transition() {
+        let rules = us_eastern();
+        let samples = &[
+            // Across the spring transition, at 2021-03-14T02:00:00.
+            ("2021-03-13T22:00:00", "2021-03-14T06:00:00", true),
+            ("2021-03-14T02:00:00", "2021-03-14T03:00:00", true),
+            ("2021-03-14T01:00:00", "2021-03-14T02:00:00", false),
+            ("2021-03-14T03:00:00", "2021-03-15T03:00:00", false),
+            // Across the fall transition, at 2021-11-07T02:00:00.
+            ("2021-11-06T12:00:00", "2021-11-07T12:00:00", true),
+            ("2021-11-07T02:00:00", "2021-11-07T02:00:01", true),
+            ("2021-11-07T02:00:01", "2021-11-08T00:00:00", false),
+            // Within the daylight saving time, and across both transitions.
+            ("2021-04-01T00:00:00", "2021-10-01T00:00:00", false),
+            ("2021-01-01T00:00:00", "2021-12-31T00:00:00", true),
+            // Across the end of the year.
+            ("2020-12-01T00:00:00", "2021-03-20T00:00:00", true),
+            ("2020-12-01T00:00:00", "2021-02-01T00:00:00", false),
+            // Across several years.
+            ("2019-11-10T00:00:00", "2021-02-01T00:00:00", true),
+            ("1900-06-01T00:00:00", "2100-06-01T00:00:00", true),
+        ];
+        for (start, end, expected) in samples {
+            let start: MockDateTime = start.parse().unwrap();
+            let end: MockDateTime = end.parse().unwrap();
+            assert_eq!(
+                contains_transition(&start, &end, &rules),
+                *expected,
+                "{:?} {:?}",
+                start,
+                end
+            );
+            assert_eq!(contains_transition(&end, &start, &rules), *expected);
+        }
+        assert!(!contains_transition(
+            &rules.start.local_time(2021),
+            &rules.start.local_time(2021),
+            &rules
+        ));
+
+        // Transitions which don't change the offset are ignored, even over millions of years.
+        let rules = FixedTransitionRules {
+            daylight_offset: rules.standard_offset,
+            ..rules
+        };
+        let start = MockDateTime::try_new(-5_000_000, 0, 0, 0, 0, 0).unwrap();
+        let end = MockDateTime::try_new(5_000_000, 0, 0, 0, 0, 0).unwrap();
+        assert!(!contains_transition(&start, &end, &rules));
+    }
+
+    #[test]
+    fn test_fixed_offset_provider() {
+        let japan: Region = "JP".parse().unwrap();
+        let provider = FixedOffsetProvider {
+            offsets: vec![(japan, "+09:00".parse().unwrap())],
+        };
+        // The offset is the same at every instant.
+        for instant in &[
+            "2020-01-01T00:00:00",
+            "2020-07-01T12:00:00",
+            "1970-01-01T00:00:00",
+        ] {
+            let instant: MockDateTime = instant.parse().unwrap();
+            assert_eq!(
+                provider.offset_for(japan, &instant),
+                Some(GmtOffset::try_new(9 * 3600).unwrap())
+            );
+            assert_eq!(provider.offset_for("KR".parse().unwrap(), &instant), None);
+        }
+    }
+}
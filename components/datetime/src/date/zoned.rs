@@ -0,0 +1,245 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use super::{
+    DateTimeError, DateTimeType, Day, GmtOffset, Hour, Minute, MockDateTime, Month, OffsetProvider,
+    Second,
+};
+use icu_locid::subtags::Region;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Temporary implementation of [`DateTimeType`] for a [`MockDateTime`] associated with
+/// a [`GmtOffset`], which is needed to format the time zone fields.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::date::MockZonedDateTime;
+///
+/// let dt: MockZonedDateTime = "2020-10-14T13:21:00+05:30".parse()
+///     .expect("Failed to parse a zoned date time.");
+/// assert_eq!(dt.offset.seconds(), 19800);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MockZonedDateTime {
+    /// The local date and time.
+    pub date_time: MockDateTime,
+    pub offset: GmtOffset,
+}
+
+impl MockZonedDateTime {
+    /// Creates a new `MockZonedDateTime` from a local date time and its offset from GMT.
+    pub const fn new(date_time: MockDateTime, offset: GmtOffset) -> Self {
+        Self { date_time, offset }
+    }
+
+    /// Creates a new `MockZonedDateTime` at the UTC `instant` in the `region`, with the
+    /// offset given by the `provider`, or `None` if it has no data for the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{FixedOffsetProvider, MockDateTime, MockZonedDateTime};
+    ///
+    /// let provider = FixedOffsetProvider {
+    ///     offsets: vec![("JP".parse().unwrap(), "+09:00".parse().unwrap())],
+    /// };
+    /// let instant: MockDateTime = "2020-10-14T20:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// let dt = MockZonedDateTime::from_utc_in_region(&instant, "JP".parse().unwrap(), &provider)
+    ///     .expect("Failed to find the offset of the region.");
+    /// assert_eq!(dt, "2020-10-15T05:00:00+09:00".parse().unwrap());
+    /// ```
+    pub fn from_utc_in_region(
+        instant: &MockDateTime,
+        region: Region,
+        provider: &impl OffsetProvider,
+    ) -> Option<Self> {
+        let offset = provider.offset_for(region, instant)?;
+        let mut date_time = instant.clone();
+        date_time.shift_from_utc(offset);
+        Some(Self { date_time, offset })
+    }
+
+    /// Compares the instants represented by both date times, normalizing them to UTC, so
+    /// that `13:00+02:00` is equal to `11:00Z`.
+    ///
+    /// Unlike `PartialEq`, which compares the fields, this ignores how the instant is
+    /// represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::MockZonedDateTime;
+    /// use std::cmp::Ordering;
+    ///
+    /// let paris: MockZonedDateTime = "2020-10-14T13:00:00+02:00".parse()
+    ///     .expect("Failed to parse a zoned date time.");
+    /// let london: MockZonedDateTime = "2020-10-14T11:00:00Z".parse()
+    ///     .expect("Failed to parse a zoned date time.");
+    /// assert_eq!(paris.cmp_instant(&london), Ordering::Equal);
+    /// assert_ne!(paris, london);
+    /// ```
+    pub fn cmp_instant(&self, other: &Self) -> Ordering {
+        self.utc_seconds().cmp(&other.utc_seconds())
+    }
+
+    /// Returns the UTC wall-clock time of this date time, removing its offset and moving
+    /// to the adjacent day if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::date::{MockDateTime, MockZonedDateTime};
+    ///
+    /// let dt: MockZonedDateTime = "2020-10-14T01:00:00+02:00".parse()
+    ///     .expect("Failed to parse a zoned date time.");
+    /// let utc: MockDateTime = "2020-10-13T23:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dt.to_parts_utc(), utc);
+    /// ```
+    pub fn to_parts_utc(&self) -> MockDateTime {
+        let mut date_time = self.date_time.clone();
+        date_time.shift_to_utc(self.offset);
+        date_time
+    }
+
+    // Returns the number of seconds since 1970-01-01T00:00:00Z.
+    fn utc_seconds(&self) -> i64 {
+        self.date_time.seconds_since_epoch() - i64::from(self.offset.seconds())
+    }
+}
+
+impl DateTimeType for MockZonedDateTime {
+    fn year(&self) -> i32 {
+        self.date_time.year()
+    }
+    fn month(&self) -> Month {
+        self.date_time.month()
+    }
+    fn day(&self) -> Day {
+        self.date_time.day()
+    }
+    fn hour(&self) -> Hour {
+        self.date_time.hour()
+    }
+    fn minute(&self) -> Minute {
+        self.date_time.minute()
+    }
+    fn second(&self) -> Second {
+        self.date_time.second()
+    }
+    fn gmt_offset(&self) -> Option<GmtOffset> {
+        Some(self.offset)
+    }
+}
+
+impl FromStr for MockZonedDateTime {
+    type Err = DateTimeError;
+
+    /// Parse a `MockZonedDateTime` from a string.
+    ///
+    /// The string must be a [`MockDateTime`] immediately followed by a [`GmtOffset`],
+    /// such as `2020-10-14T13:21:00Z` or `2020-10-14T13:21:00-08:00`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        // The offset follows the `Thh:mm:ss` time.
+        let split = input.find('T').ok_or(DateTimeError::InvalidFormat)? + 9;
+        if !input.is_char_boundary(split) || split >= input.len() {
+            return Err(DateTimeError::InvalidFormat);
+        }
+        Ok(Self {
+            date_time: input[..split].parse()?,
+            offset: input[split..].parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let samples = &[
+            ("2020-10-14T13:21:00Z", "2020-10-14T13:21:00", 0),
+            ("2020-10-14T13:21:00+05:30", "2020-10-14T13:21:00", 19800),
+            ("-0044-03-15T12:00:00-0800", "-0044-03-15T12:00:00", -28800),
+        ];
+        for (input, date_time, offset) in samples {
+            let dt: MockZonedDateTime = input.parse().unwrap();
+            assert_eq!(dt.date_time, date_time.parse().unwrap(), "{}", input);
+            assert_eq!(dt.offset.seconds(), *offset, "{}", input);
+        }
+
+        for input in &[
+            "2020-10-14T13:21:00",
+            "2020-10-14T13:21+05:30",
+            "2020-10-14T13:21:00+25:00",
+            "2020-10-14",
+        ] {
+            assert!(input.parse::<MockZonedDateTime>().is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_to_parts_utc() {
+        let samples = &[
+            ("2020-10-14T23:30:00+02:00", "2020-10-14T21:30:00"),
+            ("2020-10-14T01:00:00+02:00", "2020-10-13T23:00:00"),
+            ("2020-03-01T01:00:00+02:00", "2020-02-29T23:00:00"),
+            ("2019-03-01T01:00:00+02:00", "2019-02-28T23:00:00"),
+            ("2020-12-31T20:00:00-05:00", "2021-01-01T01:00:00"),
+            ("2020-10-14T13:21:00Z", "2020-10-14T13:21:00"),
+        ];
+        for (input, expected) in samples {
+            let dt: MockZonedDateTime = input.parse().unwrap();
+            assert_eq!(dt.to_parts_utc(), expected.parse().unwrap(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_cmp_instant() {
+        let samples = &[
+            (
+                "2020-10-14T13:00:00+02:00",
+                "2020-10-14T11:00:00Z",
+                Ordering::Equal,
+            ),
+            (
+                "2020-10-14T13:00:00+02:00",
+                "2020-10-14T12:00:00Z",
+                Ordering::Less,
+            ),
+            (
+                "2020-10-14T13:00:00-02:00",
+                "2020-10-14T14:00:00Z",
+                Ordering::Greater,
+            ),
+            // Across midnight and the end of the year.
+            (
+                "2021-01-01T01:00:00+05:30",
+                "2020-12-31T19:30:00Z",
+                Ordering::Equal,
+            ),
+            (
+                "2020-12-31T23:00:00-08:00",
+                "2021-01-01T06:59:59Z",
+                Ordering::Greater,
+            ),
+        ];
+        for (a, b, expected) in samples {
+            let a: MockZonedDateTime = a.parse().unwrap();
+            let b: MockZonedDateTime = b.parse().unwrap();
+            assert_eq!(a.cmp_instant(&b), *expected, "{:?} {:?}", a, b);
+            assert_eq!(b.cmp_instant(&a), expected.reverse());
+        }
+
+        // The wall-clock times are ordered the other way around.
+        let a: MockZonedDateTime = "2020-10-14T13:00:00+05:00".parse().unwrap();
+        let b: MockZonedDateTime = "2020-10-14T12:00:00+00:00".parse().unwrap();
+        assert_eq!(a.cmp_instant(&b), Ordering::Less);
+        assert!(a.date_time > b.date_time);
+    }
+}
@@ -0,0 +1,117 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use icu_locid::LanguageIdentifier;
+
+/// The hours to which a flexible day period applies.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum DayPeriodRule {
+    /// The day period applies exactly at the top of the given hour, e.g. noon.
+    At(u8),
+    /// The day period applies from the first hour, inclusive, until the second hour,
+    /// exclusive, wrapping around midnight if the second hour is the lower one.
+    From(u8, u8),
+}
+
+impl DayPeriodRule {
+    /// Returns `true` if the rule applies to the `hour`, in the `0..24` range.
+    pub fn contains(self, hour: u8) -> bool {
+        match self {
+            Self::At(at) => hour == at,
+            Self::From(start, end) if start < end => start <= hour && hour < end,
+            Self::From(start, end) => start <= hour || hour < end,
+        }
+    }
+}
+
+/// A flexible day period, such as "in the afternoon", used by the `B` field.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FlexibleDayPeriod {
+    pub name: &'static str,
+    pub rule: DayPeriodRule,
+}
+
+// The English names of the CLDR `dayPeriods` data, with the hours of the matching rules of
+// the `dayPeriodRuleSet` supplemental data.
+//
+// The rules which apply at a specific time are listed first, so that they take precedence
+// over the ranges containing them.
+const EN: &[FlexibleDayPeriod] = &[
+    FlexibleDayPeriod {
+        name: "midnight",
+        rule: DayPeriodRule::At(0),
+    },
+    FlexibleDayPeriod {
+        name: "noon",
+        rule: DayPeriodRule::At(12),
+    },
+    FlexibleDayPeriod {
+        name: "in the morning",
+        rule: DayPeriodRule::From(6, 12),
+    },
+    FlexibleDayPeriod {
+        name: "in the afternoon",
+        rule: DayPeriodRule::From(12, 18),
+    },
+    FlexibleDayPeriod {
+        name: "in the evening",
+        rule: DayPeriodRule::From(18, 21),
+    },
+    FlexibleDayPeriod {
+        name: "at night",
+        rule: DayPeriodRule::From(21, 6),
+    },
+];
+
+/// Returns the flexible day periods for the language of the given `LanguageIdentifier`.
+///
+/// If the language has no flexible day periods, the result is empty, and the `B` field
+/// falls back to the AM/PM markers.
+pub fn get_flexible_day_periods(langid: &LanguageIdentifier) -> &'static [FlexibleDayPeriod] {
+    match langid.language.as_str() {
+        "en" => EN,
+        _ => &[],
+    }
+}
+
+/// Returns the flexible day period which applies to the `hour`.
+///
+/// The rules applying at a specific time are only considered if `is_top_of_hour` is `true`.
+pub fn get_day_period_for_hour(
+    day_periods: &[FlexibleDayPeriod],
+    hour: u8,
+    is_top_of_hour: bool,
+) -> Option<&FlexibleDayPeriod> {
+    day_periods.iter().find(|day_period| match day_period.rule {
+        DayPeriodRule::At(_) if !is_top_of_hour => false,
+        rule => rule.contains(hour),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_day_period_for_hour() {
+        let langid: LanguageIdentifier = "en-US".parse().unwrap();
+        let day_periods = get_flexible_day_periods(&langid);
+        let samples = &[
+            (0, true, "midnight"),
+            (0, false, "at night"),
+            (5, true, "at night"),
+            (6, true, "in the morning"),
+            (12, true, "noon"),
+            (12, false, "in the afternoon"),
+            (18, false, "in the evening"),
+            (23, false, "at night"),
+        ];
+        for (hour, is_top_of_hour, expected) in samples {
+            let day_period = get_day_period_for_hour(day_periods, *hour, *is_top_of_hour);
+            assert_eq!(day_period.map(|p| p.name), Some(*expected), "{}", hour);
+        }
+
+        let langid: LanguageIdentifier = "fr".parse().unwrap();
+        assert!(get_flexible_day_periods(&langid).is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::fields::FieldLength;
+use crate::options::preferences::Calendar;
+use crate::provider::gregory::DateSymbolsV1;
+
+/// The eras of the supported calendars.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Era {
+    /// Before Christ, for the years before the common era.
+    BeforeChrist,
+    /// Anno Domini, for the years of the common era.
+    AnnoDomini,
+    /// The only era of the Buddhist calendar.
+    Buddhist,
+}
+
+// The CLDR `root` name of the Buddhist era, for every width, since the `gregory@1` data
+// only has the names of the Gregorian eras.
+const BUDDHIST: &str = "BE";
+
+/// The Gregorian year preceding the year `1` of the Buddhist era.
+const BUDDHIST_ERA_OFFSET: i32 = -543;
+
+/// Splits a signed Gregorian year into its era and the year within that era, in
+/// the given `calendar`.
+///
+/// The signed years are astronomical, as in ISO 8601, so the year `0` is the year `1` of
+/// the `BeforeChrist` era and the year `-43` is its year `44`. The Buddhist
+/// calendar has a single era, so the year `2020` is the year `2563` of the
+/// `Buddhist` era, and the years before it are counted astronomically as well, so the
+/// year `-543` is its year `0` and the year `-600` its year `-57`.
+pub fn era_year(year: i32, calendar: Calendar) -> (Era, i64) {
+    let year = i64::from(year);
+    match calendar {
+        Calendar::Gregorian if year <= 0 => (Era::BeforeChrist, 1 - year),
+        Calendar::Gregorian => (Era::AnnoDomini, year),
+        Calendar::Buddhist => (Era::Buddhist, year - i64::from(BUDDHIST_ERA_OFFSET)),
+    }
+}
+
+/// Returns the signed Gregorian year of the year within the only era of the `calendar`,
+/// the inverse of [`era_year`] for the common era.
+pub fn gregorian_year(year: i32, calendar: Calendar) -> i32 {
+    match calendar {
+        Calendar::Gregorian => year,
+        Calendar::Buddhist => year + BUDDHIST_ERA_OFFSET,
+    }
+}
+
+/// Returns the name of the `era` for the `length` of the field, or `None` for the
+/// Gregorian eras if the `symbols` have no era names.
+pub fn get_symbol_for_era(symbols: &DateSymbolsV1, era: Era, length: FieldLength) -> Option<&str> {
+    let names = match (era, &symbols.eras) {
+        (Era::Buddhist, _) => return Some(BUDDHIST),
+        (_, None) => return None,
+        (_, Some(eras)) => match length {
+            FieldLength::Wide => &eras.wide,
+            FieldLength::Narrow => &eras.narrow,
+            _ => &eras.abbreviated,
+        },
+    };
+    match era {
+        Era::BeforeChrist => Some(&names.bc),
+        _ => Some(&names.ad),
+    }
+}
+
+/// Returns the length in bytes of the longest era name for the `length` of the field.
+pub fn max_symbol_len(symbols: &DateSymbolsV1, length: FieldLength) -> usize {
+    [Era::BeforeChrist, Era::AnnoDomini, Era::Buddhist]
+        .iter()
+        .filter_map(|era| get_symbol_for_era(symbols, *era, length))
+        .map(str::len)
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_era_year() {
+        assert_eq!(era_year(2020, Calendar::Gregorian), (Era::AnnoDomini, 2020));
+        assert_eq!(era_year(1, Calendar::Gregorian), (Era::AnnoDomini, 1));
+        assert_eq!(era_year(0, Calendar::Gregorian), (Era::BeforeChrist, 1));
+        assert_eq!(era_year(-43, Calendar::Gregorian), (Era::BeforeChrist, 44));
+        assert_eq!(
+            era_year(i32::MIN, Calendar::Gregorian),
+            (Era::BeforeChrist, 2_147_483_649)
+        );
+    }
+
+    #[test]
+    fn test_buddhist_era_year() {
+        assert_eq!(era_year(2020, Calendar::Buddhist), (Era::Buddhist, 2563));
+        assert_eq!(era_year(-543, Calendar::Buddhist), (Era::Buddhist, 0));
+        assert_eq!(era_year(-600, Calendar::Buddhist), (Era::Buddhist, -57));
+        assert_eq!(
+            era_year(i32::MIN, Calendar::Buddhist),
+            (Era::Buddhist, -2_147_483_105)
+        );
+        assert_eq!(gregorian_year(2563, Calendar::Buddhist), 2020);
+        assert_eq!(gregorian_year(2020, Calendar::Gregorian), 2020);
+    }
+}
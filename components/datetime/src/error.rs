@@ -1,8 +1,10 @@
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::options::preferences::Calendar;
 use crate::pattern;
 use icu_provider::prelude::DataError;
+use std::fmt;
 
 /// A list of possible error outcomes for the [`DateTimeFormat`](crate::DateTimeFormat) struct.
 ///
@@ -14,6 +16,29 @@ pub enum DateTimeFormatError {
     Format(std::fmt::Error),
     /// An error originating inside of the DataProvider
     DataProvider(DataError),
+    /// The input lacks the value of a field of the pattern, such as the GMT offset
+    MissingInputField(&'static str),
+    /// The calendar requested by the `ca` keyword of the locale is not supported
+    UnsupportedCalendar(String),
+}
+
+impl fmt::Display for DateTimeFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pattern(err) => write!(f, "invalid pattern: {}", err),
+            Self::Format(err) => write!(f, "{}", err),
+            Self::DataProvider(err) => write!(f, "{}", err),
+            Self::MissingInputField(field) => write!(f, "missing input field: {}", field),
+            Self::UnsupportedCalendar(calendar) => {
+                write!(f, "unsupported calendar `{}`, expected one of", calendar)?;
+                for (i, (keyword, _)) in Calendar::KEYWORDS.iter().enumerate() {
+                    let separator = if i == 0 { " " } else { ", " };
+                    write!(f, "{}`{}`", separator, keyword)?;
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
 impl From<DataError> for DateTimeFormatError {
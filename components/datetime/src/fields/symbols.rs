@@ -6,11 +6,14 @@ use std::convert::TryFrom;
 #[derive(Debug)]
 pub enum SymbolError {
     Unknown(u8),
+    Invalid(char),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum FieldSymbol {
+    Era,
     Year(Year),
+    Quarter(Quarter),
     Month(Month),
     Day(Day),
     Weekday(Weekday),
@@ -18,21 +21,36 @@ pub enum FieldSymbol {
     Hour(Hour),
     Minute,
     Second(Second),
+    TimeZone(TimeZone),
 }
 
 impl TryFrom<u8> for FieldSymbol {
     type Error = SymbolError;
     fn try_from(b: u8) -> Result<Self, Self::Error> {
         match b {
+            b'G' => Ok(Self::Era),
             b'm' => Ok(Self::Minute),
             _ => Year::try_from(b)
                 .map(Self::Year)
+                .or_else(|_| Quarter::try_from(b).map(Self::Quarter))
                 .or_else(|_| Month::try_from(b).map(Self::Month))
                 .or_else(|_| Day::try_from(b).map(Self::Day))
                 .or_else(|_| Weekday::try_from(b).map(Self::Weekday))
                 .or_else(|_| DayPeriod::try_from(b).map(Self::DayPeriod))
                 .or_else(|_| Hour::try_from(b).map(Self::Hour))
-                .or_else(|_| Second::try_from(b).map(Self::Second)),
+                .or_else(|_| Second::try_from(b).map(Self::Second))
+                .or_else(|_| TimeZone::try_from(b).map(Self::TimeZone)),
+        }
+    }
+}
+
+impl TryFrom<char> for FieldSymbol {
+    type Error = SymbolError;
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        if ch.is_ascii() {
+            Self::try_from(ch as u8)
+        } else {
+            Err(SymbolError::Invalid(ch))
         }
     }
 }
@@ -60,6 +78,29 @@ impl From<Year> for FieldSymbol {
     }
 }
 
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Quarter {
+    Format,
+    StandAlone,
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = SymbolError;
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'Q' => Ok(Self::Format),
+            b'q' => Ok(Self::StandAlone),
+            b => Err(SymbolError::Unknown(b)),
+        }
+    }
+}
+
+impl From<Quarter> for FieldSymbol {
+    fn from(input: Quarter) -> Self {
+        Self::Quarter(input)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Month {
     Format,
@@ -189,8 +230,14 @@ impl From<Weekday> for FieldSymbol {
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum DayPeriod {
+    /// The `a` field, such as `AM` or `PM`.
     AmPm,
+    /// The `b` field, which is `noon` or `midnight` at 12:00 and 00:00, to the precision
+    /// displayed by the pattern, and otherwise falls back to `AM` or `PM`, as it does in
+    /// the languages without these terms.
     NoonMidnight,
+    /// The `B` field, such as `in the morning`.
+    Flexible,
 }
 
 impl TryFrom<u8> for DayPeriod {
@@ -199,6 +246,7 @@ impl TryFrom<u8> for DayPeriod {
         match b {
             b'a' => Ok(Self::AmPm),
             b'b' => Ok(Self::NoonMidnight),
+            b'B' => Ok(Self::Flexible),
             b => Err(SymbolError::Unknown(b)),
         }
     }
@@ -209,3 +257,31 @@ impl From<DayPeriod> for FieldSymbol {
         Self::DayPeriod(input)
     }
 }
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TimeZone {
+    LocalizedGmt,
+    /// The ISO 8601 offset, such as `+05:30`, written as `Z` when the offset is zero.
+    IsoWithZ,
+    /// The ISO 8601 offset, such as `+05:30`, also written with digits when the offset
+    /// is zero.
+    Iso,
+}
+
+impl TryFrom<u8> for TimeZone {
+    type Error = SymbolError;
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'O' => Ok(Self::LocalizedGmt),
+            b'X' => Ok(Self::IsoWithZ),
+            b'x' => Ok(Self::Iso),
+            b => Err(SymbolError::Unknown(b)),
+        }
+    }
+}
+
+impl From<TimeZone> for FieldSymbol {
+    fn from(input: TimeZone) -> Self {
+        Self::TimeZone(input)
+    }
+}
@@ -3,11 +3,17 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 
 use crate::date::{self, DateTimeType};
+use crate::day_periods;
+use crate::eras;
 use crate::fields::{self, FieldLength, FieldSymbol};
+use crate::locale_data::LocaleData;
+use crate::numbering_systems::NumberingSystem;
 use crate::pattern::{Pattern, PatternItem};
 use crate::provider;
 use crate::provider::helpers::DateTimeDates;
+use crate::quarters;
 use crate::{error::DateTimeFormatError, pattern::TimeGranularity};
+use std::borrow::Cow;
 use std::fmt;
 use writeable::Writeable;
 
@@ -18,6 +24,9 @@ use writeable::Writeable;
 /// and it will also contain additional methods allowing the user to introspect
 /// and even manipulate the formatted data.
 ///
+/// The time zone fields of the pattern are left out for the values without a GMT offset,
+/// such as a `MockDateTime`. Use [`DateTimeFormat::try_format_to_string`] to reject them.
+///
 /// # Examples
 ///
 /// ```
@@ -38,21 +47,69 @@ use writeable::Writeable;
 ///
 /// let _ = format!("Date: {}", formatted_date);
 /// ```
+///
+/// [`DateTimeFormat::try_format_to_string`]: crate::DateTimeFormat::try_format_to_string
 pub struct FormattedDateTime<'l, T>
 where
     T: DateTimeType,
 {
     pub(crate) pattern: &'l Pattern,
     pub(crate) data: &'l provider::gregory::DatesV1,
+    pub(crate) locale_data: &'l LocaleData,
     pub(crate) date_time: &'l T,
 }
 
+impl<'l, T> FormattedDateTime<'l, T>
+where
+    T: DateTimeType,
+{
+    /// Returns the number of extended grapheme clusters in the formatted value, which is
+    /// the number of user-perceived characters.
+    ///
+    /// Unlike the byte length or the number of `char`s, this doesn't count combining marks
+    /// separately, and can be used to align the formatted value in a column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dtf.format(&date_time).grapheme_len(), "Sep 12, 2020".len());
+    /// # } // feature = "provider_serde"
+    /// ```
+    #[cfg(feature = "graphemes")]
+    pub fn grapheme_len(&self) -> usize {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        self.to_string().graphemes(true).count()
+    }
+}
+
 impl<'l, T> Writeable for FormattedDateTime<'l, T>
 where
     T: DateTimeType,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        write_pattern(self.pattern, self.data, self.date_time, sink).map_err(|_| std::fmt::Error)
+        write_pattern_lenient(
+            self.pattern,
+            self.data,
+            self.locale_data,
+            self.date_time,
+            sink,
+        )
     }
 
     // TODO: Implement write_len
@@ -63,38 +120,115 @@ where
     T: DateTimeType,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.pattern, self.data, self.date_time, f).map_err(|_| std::fmt::Error)
+        write_pattern_lenient(self.pattern, self.data, self.locale_data, self.date_time, f)
     }
 }
 
-// Temporary formatting number with length.
-fn format_number<W>(result: &mut W, num: usize, length: FieldLength) -> Result<(), std::fmt::Error>
+/// Writes the `value` with the digits of the `numbering_system`, padded with zeros to
+/// `min_digits`, and truncated to its last `max_digits`.
+pub(crate) fn write_number<W>(
+    value: usize,
+    min_digits: usize,
+    max_digits: usize,
+    numbering_system: NumberingSystem,
+    out: &mut W,
+) -> fmt::Result
 where
     W: fmt::Write + ?Sized,
 {
-    match length {
-        FieldLength::One => write!(result, "{}", num),
-        FieldLength::TwoDigit => {
-            if num < 100 {
-                write!(result, "{:0>width$}", num, width = 2)
-            } else {
-                let buffer = num.to_string();
-                let len = buffer.len();
-                result.write_str(&buffer[len - 2..])
-            }
+    // The digits of the value, from the least significant one.
+    let mut digits = [0u8; 20];
+    let mut len = 0;
+    let mut remainder = value;
+    loop {
+        digits[len] = (remainder % 10) as u8;
+        len += 1;
+        remainder /= 10;
+        if remainder == 0 {
+            break;
         }
-        length => write!(result, "{:0>width$}", num, width = length as usize),
     }
+    let len = len.min(max_digits);
+    for _ in len..min_digits {
+        out.write_char(numbering_system.zero)?;
+    }
+    for digit in digits[..len].iter().rev() {
+        out.write_char(numbering_system.digit(*digit))?;
+    }
+    Ok(())
+}
+
+// Temporary formatting number with length, where only two-digit fields are truncated,
+// such as `yy` for the last two digits of the year.
+fn format_number<W>(
+    result: &mut W,
+    num: usize,
+    length: FieldLength,
+    numbering_system: NumberingSystem,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    let (min_digits, max_digits) = match length {
+        FieldLength::One => (1, usize::MAX),
+        FieldLength::TwoDigit => (2, 2),
+        length => (length as usize, usize::MAX),
+    };
+    write_number(num, min_digits, max_digits, numbering_system, result)
+}
+
+/// Writes the `offset` in the ISO 8601 format selected by the `length` of an `x` or `X`
+/// field, following UTS 35:
+///
+/// - `x`: `+hh` with optional minutes, such as `+05` or `+0530`
+/// - `xx`: `+hhmm`
+/// - `xxx`: `+hh:mm`
+/// - `xxxx`: `+hhmm` with optional seconds, such as `+053015`
+/// - `xxxxx`: `+hh:mm` with optional seconds, such as `+05:30:15`
+///
+/// If `with_z` is `true`, as for the `X` fields, a zero offset is written as `Z`.
+fn write_iso8601_offset<W>(
+    w: &mut W,
+    offset: date::GmtOffset,
+    length: FieldLength,
+    with_z: bool,
+) -> fmt::Result
+where
+    W: fmt::Write + ?Sized,
+{
+    if with_z && offset.is_zero() {
+        return w.write_char('Z');
+    }
+    let sign = if offset.seconds() < 0 { '-' } else { '+' };
+    let seconds = offset.seconds().abs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    let separator = match length {
+        FieldLength::Abbreviated | FieldLength::Narrow => ":",
+        _ => "",
+    };
+    write!(w, "{}{:02}", sign, hours)?;
+    if length != FieldLength::One || minutes != 0 {
+        write!(w, "{}{:02}", separator, minutes)?;
+    }
+    if matches!(length, FieldLength::Wide | FieldLength::Narrow) && seconds != 0 {
+        write!(w, "{}{:02}", separator, seconds)?;
+    }
+    Ok(())
 }
 
 // Temporary simplified function to get the day of the week
-fn get_day_of_week(year: usize, month: date::Month, day: date::Day) -> date::WeekDay {
-    let month: usize = month.into();
-    let day: usize = day.into();
-    let t = &[0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-    let year = if month < 2 { year - 1 } else { year };
-    let result = (year + year / 4 - year / 100 + year / 400 + t[month] + day + 1) % 7;
-    date::WeekDay::new_unchecked(result as u8)
+pub(crate) fn get_day_of_week(year: i32, month: date::Month, day: date::Day) -> date::WeekDay {
+    let days =
+        date::civil::days_from_civil(i64::from(year), u8::from(month) + 1, u8::from(day) + 1);
+    date::WeekDay::new_unchecked(date::civil::weekday_from_days(days))
+}
+
+// Returns the day of the week numbered relative to the first day of the week,
+// where the first day is `1`.
+fn get_local_day_of_week(day_of_week: date::WeekDay, first_weekday: date::WeekDay) -> usize {
+    let day_of_week: usize = day_of_week.into();
+    let first_weekday: usize = first_weekday.into();
+    (day_of_week + 7 - first_weekday) % 7 + 1
 }
 
 /// Returns `true` if the most granular time being displayed will align with
@@ -111,9 +245,109 @@ fn is_top_of_hour<T: DateTimeType>(pattern: &Pattern, date_time: &T) -> bool {
     }
 }
 
+/// Returns `true` if the value of the `field` is present in the `date_time`.
+fn is_field_present(field: &fields::Field, date_time: &date::PartialDateTime) -> bool {
+    match field.symbol {
+        FieldSymbol::Era | FieldSymbol::Year(..) => date_time.year.is_some(),
+        FieldSymbol::Quarter(..) | FieldSymbol::Month(..) => date_time.month.is_some(),
+        FieldSymbol::Day(..) => date_time.day.is_some(),
+        FieldSymbol::Weekday(..) => date_time.has_date(),
+        FieldSymbol::Hour(..) | FieldSymbol::DayPeriod(..) => date_time.hour.is_some(),
+        FieldSymbol::Minute => date_time.minute.is_some(),
+        FieldSymbol::Second(..) => date_time.second.is_some(),
+        FieldSymbol::TimeZone(..) => false,
+    }
+}
+
+/// Removes the fields of the `pattern` which are absent in the `date_time`, along with the
+/// literals which separate them from the remaining fields.
+pub fn filter_pattern(pattern: &Pattern, date_time: &date::PartialDateTime) -> Pattern {
+    retain_fields(pattern, |field| is_field_present(field, date_time))
+}
+
+/// Removes the years and the eras of the `pattern`, along with the literals which separate
+/// them from the remaining fields.
+pub fn remove_year(pattern: &Pattern) -> Pattern {
+    retain_fields(pattern, |field| {
+        !matches!(field.symbol, FieldSymbol::Era | FieldSymbol::Year(..))
+    })
+}
+
+/// Removes the eras of the `pattern`, along with the literals which separate them from the
+/// remaining fields.
+pub fn remove_era(pattern: &Pattern) -> Pattern {
+    retain_fields(pattern, |field| field.symbol != FieldSymbol::Era)
+}
+
+/// Removes the time zone fields of the `pattern`, along with the literals which separate
+/// them from the remaining fields.
+pub fn remove_time_zone(pattern: &Pattern) -> Pattern {
+    retain_fields(pattern, |field| {
+        !matches!(field.symbol, FieldSymbol::TimeZone(..))
+    })
+}
+
+/// Replaces the date fields of the `pattern`, along with the literals between them, with the
+/// `name` of the day, such as "yesterday", keeping the time fields and the literal joining
+/// them to the date. A `pattern` without date fields is returned unchanged.
+pub fn replace_date(pattern: &Pattern, name: &str) -> Pattern {
+    let is_date = |item: &PatternItem| match item {
+        PatternItem::Field(field) => matches!(
+            field.symbol,
+            FieldSymbol::Era
+                | FieldSymbol::Year(..)
+                | FieldSymbol::Quarter(..)
+                | FieldSymbol::Month(..)
+                | FieldSymbol::Weekday(..)
+                | FieldSymbol::Day(..)
+        ),
+        PatternItem::Literal(_) => false,
+    };
+    let items = pattern.items();
+    match (
+        items.iter().position(is_date),
+        items.iter().rposition(is_date),
+    ) {
+        (Some(first), Some(last)) => {
+            let mut result = items[..first].to_vec();
+            result.push(PatternItem::Literal(name.to_string()));
+            result.extend_from_slice(&items[last + 1..]);
+            result.into()
+        }
+        _ => pattern.clone(),
+    }
+}
+
+/// Removes the fields of the `pattern` for which `keep` returns `false`.
+///
+/// For each removed field, the literal which joins it to the preceding kept field is removed.
+/// If there is no such literal, the literal following the removed field is removed instead.
+fn retain_fields(pattern: &Pattern, keep: impl Fn(&fields::Field) -> bool) -> Pattern {
+    let mut result: Vec<PatternItem> = Vec::with_capacity(pattern.items().len());
+    let mut skip_literal = false;
+    for item in pattern.items() {
+        match item {
+            PatternItem::Field(field) if keep(field) => {
+                result.push(item.clone());
+                skip_literal = false;
+            }
+            PatternItem::Field(_) => match result.as_slice() {
+                [.., PatternItem::Field(_), PatternItem::Literal(_)] => {
+                    result.pop();
+                }
+                _ => skip_literal = true,
+            },
+            PatternItem::Literal(_) if skip_literal => skip_literal = false,
+            PatternItem::Literal(_) => result.push(item.clone()),
+        }
+    }
+    result.into()
+}
+
 pub fn write_pattern<T, W>(
     pattern: &crate::pattern::Pattern,
     data: &provider::gregory::DatesV1,
+    locale_data: &LocaleData,
     date_time: &T,
     w: &mut W,
 ) -> Result<(), DateTimeFormatError>
@@ -123,74 +357,481 @@ where
 {
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => match field.symbol {
-                FieldSymbol::Year(..) => format_number(w, date_time.year(), field.length)?,
-                FieldSymbol::Month(month) => match field.length {
-                    FieldLength::One | FieldLength::TwoDigit => {
-                        format_number(w, usize::from(date_time.month()) + 1, field.length)?
-                    }
-                    length => {
-                        let symbol = data.get_symbol_for_month(month, length, date_time.month());
-                        w.write_str(symbol)?
+            PatternItem::Field(field) => {
+                write_field(pattern, field, data, locale_data, date_time, w)?
+            }
+            PatternItem::Literal(l) => w.write_str(&l)?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes the `date_time` with the `pattern` like [`write_pattern`], except that the time
+/// zone fields are left out, along with their literals, if the `date_time` has no GMT
+/// offset. Only the errors of `w` are returned.
+pub fn write_pattern_lenient<T, W>(
+    pattern: &crate::pattern::Pattern,
+    data: &provider::gregory::DatesV1,
+    locale_data: &LocaleData,
+    date_time: &T,
+    w: &mut W,
+) -> fmt::Result
+where
+    T: DateTimeType,
+    W: fmt::Write + ?Sized,
+{
+    let lacks_offset = date_time.gmt_offset().is_none()
+        && pattern.items().iter().any(|item| {
+            matches!(item, PatternItem::Field(field) if matches!(field.symbol, FieldSymbol::TimeZone(..)))
+        });
+    if lacks_offset {
+        write_pattern(&remove_time_zone(pattern), data, locale_data, date_time, w)
+    } else {
+        write_pattern(pattern, data, locale_data, date_time, w)
+    }
+    .map_err(|_| fmt::Error)
+}
+
+/// Formats the `date_time` with the `pattern`, borrowing the literal of the patterns which
+/// have no fields instead of allocating a `String`.
+pub fn format_pattern_to_cow<'p, T>(
+    pattern: &'p Pattern,
+    data: &provider::gregory::DatesV1,
+    locale_data: &LocaleData,
+    date_time: &T,
+) -> Result<Cow<'p, str>, DateTimeFormatError>
+where
+    T: DateTimeType,
+{
+    Ok(match pattern.items() {
+        [] => Cow::Borrowed(""),
+        [PatternItem::Literal(literal)] => Cow::Borrowed(literal),
+        _ => {
+            let mut s = String::new();
+            write_pattern(pattern, data, locale_data, date_time, &mut s)?;
+            Cow::Owned(s)
+        }
+    })
+}
+
+/// Writes a single `field` of the `pattern`, which is needed by the fields depending on
+/// the other ones, such as the day periods.
+pub fn write_field<T, W>(
+    pattern: &crate::pattern::Pattern,
+    field: &fields::Field,
+    data: &provider::gregory::DatesV1,
+    locale_data: &LocaleData,
+    date_time: &T,
+    w: &mut W,
+) -> Result<(), DateTimeFormatError>
+where
+    T: DateTimeType,
+    W: fmt::Write + ?Sized,
+{
+    let numbering_system = locale_data.numbering_system;
+    match field.symbol {
+        FieldSymbol::Era => {
+            let (era, _) = eras::era_year(date_time.year(), locale_data.calendar);
+            if let Some(symbol) = eras::get_symbol_for_era(&data.symbols, era, field.length) {
+                w.write_str(symbol)?
+            }
+        }
+        FieldSymbol::Year(..) => {
+            let (_, year) = eras::era_year(date_time.year(), locale_data.calendar);
+            let digits = year.unsigned_abs() as usize;
+            match field.length {
+                // Like the century, the sign of the years before the Buddhist era is
+                // dropped by the two-digit years.
+                FieldLength::TwoDigit => format_number(w, digits, field.length, numbering_system)?,
+                length => {
+                    if year < 0 {
+                        w.write_char('-')?;
                     }
-                },
-                FieldSymbol::Weekday(weekday) => {
-                    let dow = get_day_of_week(date_time.year(), date_time.month(), date_time.day());
-                    let symbol = data.get_symbol_for_weekday(weekday, field.length, dow);
+                    write_number(
+                        digits,
+                        (length as usize).max(locale_data.min_year_digits),
+                        usize::MAX,
+                        numbering_system,
+                        w,
+                    )?
+                }
+            }
+        }
+        FieldSymbol::Quarter(quarter) => match (field.length, locale_data.quarters) {
+            (FieldLength::One, _) | (FieldLength::TwoDigit, _) => format_number(
+                w,
+                quarters::quarter_of(date_time.month()) + 1,
+                locale_data.numeric_length(field.length),
+                numbering_system,
+            )?,
+            (length, Some(names)) => {
+                w.write_str(names.get_symbol(quarter, length, date_time.month()))?
+            }
+            (_, None) => format_number(
+                w,
+                quarters::quarter_of(date_time.month()) + 1,
+                FieldLength::One,
+                numbering_system,
+            )?,
+        },
+        FieldSymbol::Month(month) => match field.length {
+            FieldLength::One | FieldLength::TwoDigit => format_number(
+                w,
+                usize::from(date_time.month()) + 1,
+                locale_data.numeric_length(field.length),
+                numbering_system,
+            )?,
+            length => {
+                let length = locale_data.month_width.unwrap_or(length);
+                let symbol = data.get_symbol_for_month(month, length, date_time.month());
+                w.write_str(symbol)?
+            }
+        },
+        FieldSymbol::Weekday(weekday) => {
+            let dow = get_day_of_week(date_time.year(), date_time.month(), date_time.day());
+            match (weekday, field.length) {
+                (fields::Weekday::StandAlone, FieldLength::One)
+                | (fields::Weekday::StandAlone, FieldLength::TwoDigit)
+                | (fields::Weekday::Local, FieldLength::One)
+                | (fields::Weekday::Local, FieldLength::TwoDigit) => format_number(
+                    w,
+                    get_local_day_of_week(dow, locale_data.first_weekday),
+                    locale_data.numeric_length(field.length),
+                    numbering_system,
+                )?,
+                (weekday, length) => {
+                    let symbol = data.get_symbol_for_weekday(weekday, length, dow);
                     w.write_str(symbol)?
                 }
-                FieldSymbol::Day(..) => {
-                    format_number(w, usize::from(date_time.day()) + 1, field.length)?
+            }
+        }
+        FieldSymbol::Day(day) => {
+            let value = usize::from(date_time.day()) + 1;
+            format_number(
+                w,
+                value,
+                locale_data.numeric_length(field.length),
+                numbering_system,
+            )?;
+            if let (fields::Day::DayOfMonth, Some(suffixes)) = (day, &locale_data.day_ordinals) {
+                w.write_str(suffixes.get(value))?
+            }
+        }
+        FieldSymbol::Hour(hour) => {
+            let h = date_time.hour().into();
+            let value = match hour {
+                fields::Hour::H11 => h % 12,
+                fields::Hour::H12 => {
+                    let v = h % 12;
+                    if v == 0 {
+                        12
+                    } else {
+                        v
+                    }
+                }
+                fields::Hour::H23 => h,
+                fields::Hour::H24 => {
+                    if h == 0 {
+                        24
+                    } else {
+                        h
+                    }
+                }
+            };
+            format_number(
+                w,
+                value,
+                locale_data.numeric_length(field.length),
+                numbering_system,
+            )?
+        }
+        FieldSymbol::Minute => format_number(
+            w,
+            date_time.minute().into(),
+            locale_data.numeric_length(field.length),
+            numbering_system,
+        )?,
+        FieldSymbol::Second(..) => format_number(
+            w,
+            date_time.second().into(),
+            locale_data.numeric_length(field.length),
+            numbering_system,
+        )?,
+        FieldSymbol::TimeZone(fields::TimeZone::LocalizedGmt) => {
+            let offset = date_time
+                .gmt_offset()
+                .ok_or(DateTimeFormatError::MissingInputField("GmtOffset"))?;
+            locale_data
+                .gmt_format
+                .write_offset(w, offset, field.length == FieldLength::Wide)?
+        }
+        FieldSymbol::TimeZone(zone) => {
+            let offset = date_time
+                .gmt_offset()
+                .ok_or(DateTimeFormatError::MissingInputField("GmtOffset"))?;
+            write_iso8601_offset(w, offset, field.length, zone == fields::TimeZone::IsoWithZ)?
+        }
+        FieldSymbol::DayPeriod(period) => {
+            let is_top_of_hour = is_top_of_hour(&pattern, date_time);
+            let flexible = match period {
+                fields::DayPeriod::Flexible => day_periods::get_day_period_for_hour(
+                    locale_data.day_periods,
+                    date_time.hour().into(),
+                    is_top_of_hour,
+                ),
+                _ => None,
+            };
+            match flexible {
+                Some(day_period) => w.write_str(day_period.name)?,
+                None => w.write_str(data.get_symbol_for_day_period(
+                    period,
+                    field.length,
+                    date_time.hour(),
+                    is_top_of_hour,
+                ))?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns an upper bound of the length in bytes of any value formatted with the `pattern`,
+/// in which each digit takes the length of the longest digit of the numbering system.
+pub fn max_width(
+    pattern: &Pattern,
+    data: &provider::gregory::DatesV1,
+    locale_data: &LocaleData,
+) -> usize {
+    // The number of digits of a numeric field whose values have up to `max_digits`.
+    fn digits(length: FieldLength, max_digits: usize) -> usize {
+        match length {
+            FieldLength::One => max_digits,
+            FieldLength::TwoDigit => 2,
+            length => (length as usize).max(max_digits),
+        }
+    }
+
+    let digit_len = locale_data.numbering_system.max_digit_len();
+    pattern
+        .items()
+        .iter()
+        .map(|item| match item {
+            PatternItem::Field(field) => match field.symbol {
+                FieldSymbol::Era => eras::max_symbol_len(&data.symbols, field.length),
+                FieldSymbol::Year(..) => {
+                    // Era years have up to 10 digits, and the years before the Buddhist
+                    // era a sign, except in two-digit years.
+                    let max_digits = locale_data.min_year_digits.max(10);
+                    let sign = match field.length {
+                        FieldLength::TwoDigit => 0,
+                        _ => 1,
+                    };
+                    sign + digits(field.length, max_digits) * digit_len
                 }
-                FieldSymbol::Hour(hour) => {
-                    let h = date_time.hour().into();
-                    let value = match hour {
-                        fields::Hour::H11 => h % 12,
-                        fields::Hour::H12 => {
-                            let v = h % 12;
-                            if v == 0 {
-                                12
-                            } else {
-                                v
-                            }
-                        }
-                        fields::Hour::H23 => h,
-                        fields::Hour::H24 => {
-                            if h == 0 {
-                                24
-                            } else {
-                                h
-                            }
-                        }
+                FieldSymbol::Quarter(quarter) => match (field.length, locale_data.quarters) {
+                    (FieldLength::One, _) | (FieldLength::TwoDigit, _) => {
+                        digits(field.length, 1) * digit_len
+                    }
+                    (length, Some(names)) => names
+                        .get_symbols(quarter, length)
+                        .iter()
+                        .map(|symbol| symbol.len())
+                        .max()
+                        .unwrap_or(0),
+                    (_, None) => digit_len,
+                },
+                FieldSymbol::Month(month) => match field.length {
+                    FieldLength::One | FieldLength::TwoDigit => 2 * digit_len,
+                    length => (0..12)
+                        .map(|idx| {
+                            data.get_symbol_for_month(
+                                month,
+                                locale_data.month_width.unwrap_or(length),
+                                date::Month::new_unchecked(idx),
+                            )
+                            .len()
+                        })
+                        .max()
+                        .unwrap_or(0),
+                },
+                FieldSymbol::Weekday(weekday) => match (weekday, field.length) {
+                    (fields::Weekday::StandAlone, FieldLength::One)
+                    | (fields::Weekday::StandAlone, FieldLength::TwoDigit)
+                    | (fields::Weekday::Local, FieldLength::One)
+                    | (fields::Weekday::Local, FieldLength::TwoDigit) => {
+                        digits(field.length, 1) * digit_len
+                    }
+                    (weekday, length) => (0..7)
+                        .map(|idx| {
+                            data.get_symbol_for_weekday(
+                                weekday,
+                                length,
+                                date::WeekDay::new_unchecked(idx),
+                            )
+                            .len()
+                        })
+                        .max()
+                        .unwrap_or(0),
+                },
+                FieldSymbol::Day(day) => {
+                    let suffix = match (day, &locale_data.day_ordinals) {
+                        (fields::Day::DayOfMonth, Some(suffixes)) => suffixes.max_len(),
+                        _ => 0,
                     };
-                    format_number(w, value, field.length)?
+                    digits(field.length, 2) * digit_len + suffix
+                }
+                FieldSymbol::Hour(..) | FieldSymbol::Minute | FieldSymbol::Second(..) => {
+                    digits(field.length, 2) * digit_len
                 }
-                FieldSymbol::Minute => format_number(w, date_time.minute().into(), field.length)?,
-                FieldSymbol::Second(..) => {
-                    format_number(w, date_time.second().into(), field.length)?
+                FieldSymbol::TimeZone(fields::TimeZone::LocalizedGmt) => {
+                    locale_data.gmt_format.max_len()
                 }
+                // The sign followed by `hh:mm:ss`.
+                FieldSymbol::TimeZone(..) => 9,
                 FieldSymbol::DayPeriod(period) => {
-                    let symbol = data.get_symbol_for_day_period(
-                        period,
-                        field.length,
-                        date_time.hour(),
-                        is_top_of_hour(&pattern, date_time),
-                    );
-                    w.write_str(symbol)?
+                    let flexible = match period {
+                        fields::DayPeriod::Flexible => locale_data
+                            .day_periods
+                            .iter()
+                            .map(|day_period| day_period.name.len())
+                            .max()
+                            .unwrap_or(0),
+                        _ => 0,
+                    };
+                    (0..24)
+                        .flat_map(|hour| {
+                            [false, true].iter().map(move |is_top_of_hour| {
+                                data.get_symbol_for_day_period(
+                                    period,
+                                    field.length,
+                                    date::Hour::new_unchecked(hour),
+                                    *is_top_of_hour,
+                                )
+                                .len()
+                            })
+                        })
+                        .max()
+                        .unwrap_or(0)
+                        .max(flexible)
                 }
             },
-            PatternItem::Literal(l) => w.write_str(&l)?,
+            PatternItem::Literal(l) => l.len(),
+        })
+        .sum()
+}
+
+/// Writes the `date_time` as an RFC 3339 timestamp in UTC, `YYYY-MM-DDTHH:MM:SSZ`,
+/// without going through the pattern or the locale data.
+///
+/// A value with a GMT offset is shifted to UTC first, moving to the adjacent day if needed.
+///
+/// Years outside of the `0..=9999` range can't be represented in RFC 3339, so they are
+/// written in the ISO 8601 expanded format instead, with a sign and at least six digits.
+pub fn write_rfc3339<T, W>(date_time: &T, w: &mut W) -> fmt::Result
+where
+    T: DateTimeType,
+    W: fmt::Write + ?Sized,
+{
+    fn push_digits(buffer: &mut [u8], len: &mut usize, mut value: u32, width: usize) {
+        for idx in (*len..*len + width).rev() {
+            buffer[idx] = b'0' + (value % 10) as u8;
+            value /= 10;
         }
+        *len += width;
     }
-    Ok(())
+
+    let mut utc = date::MockDateTime {
+        year: date_time.year(),
+        month: date_time.month(),
+        day: date_time.day(),
+        hour: date_time.hour(),
+        minute: date_time.minute(),
+        second: date_time.second(),
+    };
+    if let Some(offset) = date_time.gmt_offset() {
+        utc.shift_to_utc(offset);
+    }
+
+    // The sign, the ten digits of the largest years, and the 16 bytes of `-MM-DDThh:mm:ssZ`.
+    let mut buffer = [0u8; 27];
+    let mut len = 0;
+    let year = utc.year;
+    if (0..=9999).contains(&year) {
+        push_digits(&mut buffer, &mut len, year as u32, 4);
+    } else {
+        buffer[0] = if year < 0 { b'-' } else { b'+' };
+        len += 1;
+        let year = year.unsigned_abs();
+        let digits = (6..10).find(|&d| year < 10u32.pow(d)).unwrap_or(10);
+        push_digits(&mut buffer, &mut len, year, digits as usize);
+    }
+    let fields = [
+        (b'-', usize::from(utc.month) as u32 + 1),
+        (b'-', usize::from(utc.day) as u32 + 1),
+        (b'T', u32::from(u8::from(utc.hour))),
+        (b':', u32::from(u8::from(utc.minute))),
+        (b':', u32::from(u8::from(utc.second))),
+    ];
+    for (separator, value) in &fields {
+        buffer[len] = *separator;
+        len += 1;
+        push_digits(&mut buffer, &mut len, *value, 2);
+    }
+    buffer[len] = b'Z';
+    len += 1;
+    // The buffer only ever contains ASCII.
+    w.write_str(std::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_local_day_of_week() {
+        let sunday = date::WeekDay::new_unchecked(0);
+        let monday = date::WeekDay::new_unchecked(1);
+        let saturday = date::WeekDay::new_unchecked(6);
+
+        assert_eq!(get_local_day_of_week(sunday, sunday), 1);
+        assert_eq!(get_local_day_of_week(monday, sunday), 2);
+        assert_eq!(get_local_day_of_week(sunday, monday), 7);
+        assert_eq!(get_local_day_of_week(monday, monday), 1);
+        assert_eq!(get_local_day_of_week(saturday, monday), 6);
+        assert_eq!(get_local_day_of_week(monday, saturday), 3);
+    }
+
+    #[test]
+    fn test_filter_pattern() {
+        let pattern = Pattern::from_bytes("MMM d, y, h:mm a").unwrap();
+        let samples = &[
+            ("2020-09-12", "MMM d, y"),
+            ("2020-09", "MMM, y"),
+            ("12:35", "h:mm a"),
+            ("2020-09-12T12:35:00", "MMM d, y, h:mm a"),
+        ];
+        for (input, expected) in samples {
+            let date_time: date::PartialDateTime = input.parse().unwrap();
+            assert_eq!(
+                filter_pattern(&pattern, &date_time),
+                Pattern::from_bytes(expected).unwrap()
+            );
+        }
+
+        let hour = date::PartialDateTime {
+            hour: Some(date::Hour::new_unchecked(12)),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter_pattern(&pattern, &hour),
+            Pattern::from_bytes("h a").unwrap()
+        );
+    }
+
     #[test]
     fn test_format_number() {
+        use crate::numbering_systems::LATN;
+
         let values = &[2, 20, 201, 2017, 20173];
         let samples = &[
             (FieldLength::One, ["2", "20", "201", "2017", "20173"]),
@@ -204,9 +845,168 @@ mod tests {
         for (length, expected) in samples {
             for (value, expected) in values.iter().zip(expected) {
                 let mut s = String::new();
-                format_number(&mut s, *value, *length).unwrap();
+                format_number(&mut s, *value, *length, LATN).unwrap();
                 assert_eq!(s, *expected);
             }
         }
     }
+
+    #[test]
+    fn test_write_rfc3339() {
+        let samples = &[
+            ("2020-10-14T13:21:05", "2020-10-14T13:21:05Z"),
+            ("0001-01-01T00:00:00", "0001-01-01T00:00:00Z"),
+            ("9999-12-31T23:59:59", "9999-12-31T23:59:59Z"),
+        ];
+        for (input, expected) in samples {
+            let date_time: date::MockDateTime = input.parse().unwrap();
+            let mut s = String::new();
+            write_rfc3339(&date_time, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+
+        let samples = &[
+            (-44, "-000044-03-15T00:00:00Z"),
+            (10000, "+010000-03-15T00:00:00Z"),
+            (12_000_000, "+12000000-03-15T00:00:00Z"),
+            (i32::MIN, "-2147483648-03-15T00:00:00Z"),
+        ];
+        for (year, expected) in samples {
+            let date_time = date::MockDateTime::try_new(*year, 2, 14, 0, 0, 0).unwrap();
+            let mut s = String::new();
+            write_rfc3339(&date_time, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+
+        // The values with a GMT offset are shifted to UTC, across midnight if needed.
+        let samples = &[
+            ("2020-10-14T13:21:00+02:00", "2020-10-14T11:21:00Z"),
+            ("2020-10-14T01:30:00+05:30", "2020-10-13T20:00:00Z"),
+            ("2020-12-31T20:00:00-08:00", "2021-01-01T04:00:00Z"),
+            ("2020-10-14T13:21:00Z", "2020-10-14T13:21:00Z"),
+        ];
+        for (input, expected) in samples {
+            let date_time: date::MockZonedDateTime = input.parse().unwrap();
+            let mut s = String::new();
+            write_rfc3339(&date_time, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+    }
+
+    #[test]
+    fn test_write_number() {
+        use crate::numbering_systems::{ARAB, LATN};
+
+        let samples = &[
+            (0, 1, usize::MAX, "0"),
+            (7, 2, usize::MAX, "07"),
+            (2020, 1, usize::MAX, "2020"),
+            (2020, 6, usize::MAX, "002020"),
+            (2020, 2, 2, "20"),
+            (5, 2, 2, "05"),
+            (100, 2, 2, "00"),
+        ];
+        for (value, min_digits, max_digits, expected) in samples {
+            let mut s = String::new();
+            write_number(*value, *min_digits, *max_digits, LATN, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+
+        let mut s = String::new();
+        write_number(2020, 1, usize::MAX, ARAB, &mut s).unwrap();
+        assert_eq!(s, "\u{662}\u{660}\u{662}\u{660}");
+    }
+
+    #[test]
+    fn test_write_pattern_two_digit_year() {
+        let langid = "en".parse().unwrap();
+        let locale_data = LocaleData::new(&langid, None, &Default::default());
+        let data = provider::gregory::DatesV1::default();
+        let pattern = Pattern::from_bytes("yy").unwrap();
+        for (year, expected) in &[(2020, "20"), (5, "05"), (2000, "00")] {
+            let date_time = date::MockDateTime::try_new(*year, 0, 0, 0, 0, 0).unwrap();
+            let mut s = String::new();
+            write_pattern(&pattern, &data, &locale_data, &date_time, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+
+        // The minimum year digits don't apply to two-digit years.
+        let locale_data = LocaleData {
+            min_year_digits: 4,
+            ..locale_data
+        };
+        let date_time = date::MockDateTime::try_new(5, 0, 0, 0, 0, 0).unwrap();
+        let mut s = String::new();
+        write_pattern(&pattern, &data, &locale_data, &date_time, &mut s).unwrap();
+        assert_eq!(s, "05");
+    }
+
+    #[test]
+    fn test_format_pattern_to_cow() {
+        let langid = "en".parse().unwrap();
+        let locale_data = LocaleData::new(&langid, None, &Default::default());
+        let data = provider::gregory::DatesV1::default();
+        let date_time = date::MockDateTime::try_new(2020, 9, 13, 12, 35, 0).unwrap();
+
+        let pattern = Pattern::from_bytes("'Today'").unwrap();
+        let formatted = format_pattern_to_cow(&pattern, &data, &locale_data, &date_time);
+        assert!(matches!(formatted, Ok(Cow::Borrowed("Today"))));
+
+        let pattern = Pattern::from_bytes("HH:mm").unwrap();
+        let formatted = format_pattern_to_cow(&pattern, &data, &locale_data, &date_time);
+        assert!(matches!(formatted, Ok(Cow::Owned(ref s)) if s == "12:35"));
+
+        let pattern = Pattern::from_bytes("HH:mm O").unwrap();
+        let formatted = format_pattern_to_cow(&pattern, &data, &locale_data, &date_time);
+        assert!(matches!(
+            formatted,
+            Err(DateTimeFormatError::MissingInputField("GmtOffset"))
+        ));
+    }
+
+    #[test]
+    fn test_write_pattern_suppress_leading_zeros() {
+        let langid = "en".parse().unwrap();
+        let locale_data = LocaleData {
+            suppress_leading_zeros: true,
+            ..LocaleData::new(&langid, None, &Default::default())
+        };
+        let data = provider::gregory::DatesV1::default();
+        let date_time = date::MockDateTime::try_new(5, 6, 3, 9, 5, 7).unwrap();
+        let samples = &[
+            ("dd-MM", "4-7"),
+            ("HH:mm:ss", "9:5:7"),
+            // The years keep the width of the pattern.
+            ("yyyy", "0005"),
+            ("yy", "05"),
+        ];
+        for (pattern, expected) in samples {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            let mut s = String::new();
+            write_pattern(&pattern, &data, &locale_data, &date_time, &mut s).unwrap();
+            assert_eq!(s, *expected);
+        }
+    }
+
+    #[test]
+    fn test_max_width() {
+        let langid = "en".parse().unwrap();
+        let latn = LocaleData::new(&langid, None, &Default::default());
+        // The Arabic-Indic digits take two bytes each.
+        let arab = LocaleData {
+            numbering_system: crate::numbering_systems::ARAB,
+            ..latn.clone()
+        };
+        let data = provider::gregory::DatesV1::default();
+        let pattern = Pattern::from_bytes("dd/MM/yy HH:mm").unwrap();
+        assert_eq!(max_width(&pattern, &data, &latn), 14);
+        assert_eq!(max_width(&pattern, &data, &arab), 24);
+
+        let date_time = date::MockDateTime::try_new(2020, 9, 13, 23, 59, 0).unwrap();
+        for locale_data in &[latn, arab] {
+            let mut s = String::new();
+            write_pattern(&pattern, &data, locale_data, &date_time, &mut s).unwrap();
+            assert_eq!(s.len(), max_width(&pattern, &data, locale_data));
+        }
+    }
 }
@@ -0,0 +1,115 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::date::GmtOffset;
+use icu_locid::LanguageIdentifier;
+use std::fmt;
+
+/// The localized GMT format of a language, used by the `O` field.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct GmtFormat {
+    /// The text preceding the offset, from the `{0}` pattern of the CLDR `gmtFormat`.
+    pub prefix: &'static str,
+    /// The text following the offset, from the `{0}` pattern of the CLDR `gmtFormat`.
+    pub suffix: &'static str,
+    /// The text used for a zero offset, the CLDR `gmtZeroFormat`.
+    pub zero: &'static str,
+}
+
+// The GMT formats of the CLDR `timeZoneNames` data, for `root` and the languages which
+// replace its `GMT` prefix.
+//
+// All of the listed languages use the `+HH:mm;-HH:mm` hour format.
+const ROOT: GmtFormat = GmtFormat {
+    prefix: "GMT",
+    suffix: "",
+    zero: "GMT",
+};
+
+const FR: GmtFormat = GmtFormat {
+    prefix: "UTC",
+    suffix: "",
+    zero: "UTC",
+};
+
+/// Returns the localized GMT format for the language of the given `LanguageIdentifier`.
+pub fn get_gmt_format(langid: &LanguageIdentifier) -> GmtFormat {
+    match langid.language.as_str() {
+        "fr" => FR,
+        _ => ROOT,
+    }
+}
+
+impl GmtFormat {
+    /// Writes the `offset` in the short localized GMT format, such as `GMT-8` or `GMT+5:30`,
+    /// or in the long one, such as `GMT-08:00`, if `long` is `true`.
+    ///
+    /// The seconds of the offset are only written if they are non-zero.
+    pub fn write_offset<W>(&self, w: &mut W, offset: GmtOffset, long: bool) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        if offset.is_zero() {
+            return w.write_str(self.zero);
+        }
+        let sign = if offset.seconds() < 0 { '-' } else { '+' };
+        let seconds = offset.seconds().abs();
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        w.write_str(self.prefix)?;
+        if long {
+            write!(w, "{}{:02}:{:02}", sign, hours, minutes)?;
+        } else {
+            write!(w, "{}{}", sign, hours)?;
+            if minutes != 0 || seconds != 0 {
+                write!(w, ":{:02}", minutes)?;
+            }
+        }
+        if seconds != 0 {
+            write!(w, ":{:02}", seconds)?;
+        }
+        w.write_str(self.suffix)
+    }
+
+    /// Returns the length in bytes of the longest offset written by `write_offset`.
+    pub fn max_len(&self) -> usize {
+        // The sign followed by `HH:mm:ss`.
+        self.zero
+            .len()
+            .max(self.prefix.len() + 9 + self.suffix.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_offset() {
+        let langid: LanguageIdentifier = "en".parse().unwrap();
+        let format = get_gmt_format(&langid);
+        let samples = &[
+            ("+05:30", "GMT+5:30", "GMT+05:30"),
+            ("-08:00", "GMT-8", "GMT-08:00"),
+            ("+14:00", "GMT+14", "GMT+14:00"),
+            ("-00:30", "GMT-0:30", "GMT-00:30"),
+            ("+01:00:30", "GMT+1:00:30", "GMT+01:00:30"),
+            ("Z", "GMT", "GMT"),
+        ];
+        for (offset, short, long) in samples {
+            let offset: GmtOffset = offset.parse().unwrap();
+            let mut s = String::new();
+            format.write_offset(&mut s, offset, false).unwrap();
+            assert_eq!(s, *short);
+            let mut s = String::new();
+            format.write_offset(&mut s, offset, true).unwrap();
+            assert_eq!(s, *long);
+        }
+
+        let langid: LanguageIdentifier = "fr".parse().unwrap();
+        let mut s = String::new();
+        get_gmt_format(&langid)
+            .write_offset(&mut s, "+05:30".parse().unwrap(), false)
+            .unwrap();
+        assert_eq!(s, "UTC+5:30");
+    }
+}
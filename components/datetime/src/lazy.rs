@@ -0,0 +1,93 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::options::DateTimeFormatOptions;
+use crate::provider;
+use crate::{DateTimeFormat, DateTimeFormatError};
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+
+/// A [`DateTimeFormat`] which doesn't load anything from the `DataProvider` until it's first
+/// used, so that a formatter which is never used costs nothing but its construction.
+///
+/// The trade-off is that the `DataProvider` must outlive the `LazyDateTimeFormat`, and that
+/// the errors of the construction, including an unsupported calendar, are only returned by
+/// [`LazyDateTimeFormat::get`]. Use [`DateTimeFormat::try_new_with_locale`] to load the data
+/// eagerly instead.
+///
+/// # Examples
+///
+/// ```
+/// use icu_locid_macros::langid;
+/// use icu_datetime::{DateTimeFormatOptions, LazyDateTimeFormat};
+/// use icu_datetime::date::MockDateTime;
+/// use icu_provider::inv::InvariantDataProvider;
+///
+/// let provider = InvariantDataProvider;
+/// let mut lazy = LazyDateTimeFormat::new(langid!("en"), &provider, &DateTimeFormatOptions::default());
+///
+/// // The data is loaded here.
+/// let dtf = lazy.get().expect("Failed to create DateTimeFormat instance.");
+///
+/// let date_time = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+///     .expect("Failed to construct DateTime.");
+/// let _ = dtf.format_to_string(&date_time);
+/// ```
+pub struct LazyDateTimeFormat<'d, D: ?Sized> {
+    locale: Locale,
+    data_provider: &'d D,
+    options: DateTimeFormatOptions,
+    /// The overrides of [`LazyDateTimeFormat::with_literal_overrides`], in the order in which
+    /// they are applied once the data is loaded.
+    literal_overrides: Vec<HashMap<String, String>>,
+    format: Option<DateTimeFormat<'d>>,
+}
+
+impl<'d, D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized> LazyDateTimeFormat<'d, D> {
+    /// Keeps the `locale`, the `DataProvider` and the options to construct a
+    /// [`DateTimeFormat`] with on first use.
+    pub fn new<T: Into<Locale>>(
+        locale: T,
+        data_provider: &'d D,
+        options: &DateTimeFormatOptions,
+    ) -> Self {
+        Self {
+            locale: locale.into(),
+            data_provider,
+            options: options.clone(),
+            literal_overrides: vec![],
+            format: None,
+        }
+    }
+
+    /// Replaces the literals of the pattern once the data is loaded, as
+    /// [`DateTimeFormat::with_literal_overrides`] does.
+    pub fn with_literal_overrides(mut self, overrides: HashMap<String, String>) -> Self {
+        match self.format {
+            Some(format) => self.format = Some(format.with_literal_overrides(overrides)),
+            None => self.literal_overrides.push(overrides),
+        }
+        self
+    }
+
+    /// Returns the [`DateTimeFormat`], constructing it on the first call, which returns the
+    /// error of the construction if it fails. The following calls try again.
+    pub fn get(&mut self) -> Result<&DateTimeFormat<'d>, DateTimeFormatError> {
+        if self.format.is_none() {
+            let mut format = DateTimeFormat::try_new_with_locale(
+                self.locale.clone(),
+                self.data_provider,
+                &self.options,
+            )?;
+            for overrides in self.literal_overrides.drain(..) {
+                format = format.with_literal_overrides(overrides);
+            }
+            self.format = Some(format);
+        }
+        Ok(self
+            .format
+            .as_ref()
+            .expect("The DateTimeFormat was constructed above."))
+    }
+}
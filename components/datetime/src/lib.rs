@@ -71,26 +71,46 @@
 //! [`ICU4X`]: ../icu/index.html
 //! [`Style`]: options::style
 //! [`MockDateTime`]: date::MockDateTime
+mod calendars;
 pub mod date;
+mod day_periods;
+mod eras;
 mod error;
-mod fields;
+#[doc(hidden)]
+pub mod fields;
 mod format;
+mod gmt_format;
+mod lazy;
+mod locale_data;
+mod numbering_systems;
 pub mod options;
+mod ordinals;
+mod parse;
 #[doc(hidden)]
 pub mod pattern;
 pub mod provider;
+mod quarters;
+mod week_data;
 
 use crate::provider::helpers::DateTimeDates;
-use date::DateTimeType;
+use date::{DateTimeError, DateTimeType, MockDateTime, MockZonedDateTime, PartialDateTime};
 pub use error::DateTimeFormatError;
-use format::write_pattern;
 pub use format::FormattedDateTime;
-use icu_locid::LanguageIdentifier;
+use format::{
+    filter_pattern, format_pattern_to_cow, max_width, remove_era, remove_year, replace_date,
+    write_pattern, write_pattern_lenient, write_rfc3339,
+};
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::prelude::*;
+pub use lazy::LazyDateTimeFormat;
+use locale_data::LocaleData;
+pub use numbering_systems::{default_numbering_system, NumberingSystem};
+use options::preferences;
 #[doc(inline)]
 pub use options::DateTimeFormatOptions;
 use pattern::Pattern;
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 /// `DateTimeFormat` is the main structure of the `icu_datetime` component.
 /// When constructed, it uses data from the `DataProvider`, selected `LanguageIdentifier` and provided options to
@@ -132,12 +152,21 @@ pub struct DateTimeFormat<'d> {
     _langid: LanguageIdentifier,
     pattern: Pattern,
     data: Cow<'d, provider::gregory::DatesV1>,
+    locale_data: LocaleData,
+    /// The locale of the data returned by the `DataProvider`, which may be a fallback of
+    /// the requested one.
+    #[cfg(feature = "debug_provenance")]
+    data_langid: Option<LanguageIdentifier>,
 }
 
 impl<'d> DateTimeFormat<'d> {
     /// `DateTimeFormat` constructor which takes a selected `LanguageIdentifier`, reference to a `DataProvider` and
     /// a list of options and collects all data necessary to format date and time values into the given locale.
     ///
+    /// The default calendar of the region, or of the likely region of the language, is used, such as the
+    /// Buddhist one for `th-TH` and `th`, unless it is set in the [`preferences`](options::preferences). Use
+    /// [`DateTimeFormat::try_new_with_locale`] to select it with a Unicode extension keyword instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -161,27 +190,196 @@ impl<'d> DateTimeFormat<'d> {
         data_provider: &D,
         options: &DateTimeFormatOptions,
     ) -> Result<Self, DateTimeFormatError> {
-        let data = data_provider
-            .load_payload(&DataRequest {
-                resource_path: ResourcePath {
-                    key: provider::key::GREGORY_V1,
-                    options: ResourceOptions {
-                        variant: None,
-                        langid: Some(langid.clone()),
-                    },
-                },
-            })?
-            .take_payload()?;
+        Self::try_new_with_locale(langid.into(), data_provider, options)
+    }
 
-        let pattern = data.get_pattern_for_options(options)?.unwrap_or_default();
+    /// Like [`DateTimeFormat::try_new`], but takes a `Locale`, whose `ca` Unicode extension
+    /// keyword may select the calendar, such as `en-u-ca-buddhist`, unless it is set in the
+    /// [`preferences`](options::preferences). A calendar which isn't supported returns
+    /// [`DateTimeFormatError::UnsupportedCalendar`]. Likewise, its `nu` keyword may select the
+    /// digits, such as the Latin ones for `ar-u-nu-latn`, as [`default_numbering_system`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::Locale;
+    /// use icu_datetime::{DateTimeFormat, DateTimeFormatError, DateTimeFormatOptions};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let options = DateTimeFormatOptions::default();
+    ///
+    /// let locale: Locale = "en-u-ca-buddhist".parse().expect("Failed to parse a locale.");
+    /// assert!(DateTimeFormat::try_new_with_locale(locale, &provider, &options).is_ok());
+    ///
+    /// let locale: Locale = "en-u-ca-persian".parse().expect("Failed to parse a locale.");
+    /// assert!(matches!(
+    ///     DateTimeFormat::try_new_with_locale(locale, &provider, &options),
+    ///     Err(DateTimeFormatError::UnsupportedCalendar(_))
+    /// ));
+    /// ```
+    pub fn try_new_with_locale<D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized>(
+        locale: Locale,
+        data_provider: &D,
+        options: &DateTimeFormatOptions,
+    ) -> Result<Self, DateTimeFormatError> {
+        let calendar = preferences::Calendar::from_keywords(&locale.extensions.unicode.keywords)?;
+        let numbering_system = default_numbering_system(&locale);
+        let langid: LanguageIdentifier = locale.into();
+        let locale_data = LocaleData {
+            numbering_system,
+            ..LocaleData::new(&langid, calendar, options)
+        };
+        Self::try_new_with_data(langid, data_provider, locale_data, |data| {
+            Ok(data.get_pattern_for_options(options)?.unwrap_or_default())
+        })
+    }
+
+    /// `DateTimeFormat` constructor which formats with the given CLDR `pattern`, such as
+    /// `"MMM d, y 'at' h:mm a"`, instead of the one which [`DateTimeFormat::try_new`]
+    /// selects with the options. The data of the `LanguageIdentifier` still provides the
+    /// names, such as the ones of the months.
+    ///
+    /// The ASCII letters which aren't field symbols, such as the `T` of RFC 3339, are
+    /// formatted as literals even if they aren't quoted. Use
+    /// [`DateTimeFormat::try_new_with_pattern_strict`] to reject them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::DateTimeFormat;
+    /// use icu_datetime::date::MockDateTime;
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let dtf = DateTimeFormat::try_new_with_pattern(langid!("en"), &provider, "y-MM-ddTHH:mm")
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time = MockDateTime::try_new(2020, 8, 11, 12, 35, 0)
+    ///     .expect("Failed to construct DateTime.");
+    /// assert_eq!(dtf.format_to_string(&date_time), "2020-09-12T12:35");
+    /// ```
+    pub fn try_new_with_pattern<D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+        pattern: &str,
+    ) -> Result<Self, DateTimeFormatError> {
+        let pattern = Pattern::from_bytes(pattern)?;
+        Self::try_new_from_pattern(langid, data_provider, pattern)
+    }
+
+    /// Like [`DateTimeFormat::try_new_with_pattern`], but returns
+    /// [`DateTimeFormatError::Pattern`] for the ASCII letters of the `pattern` which are
+    /// neither field symbols nor quoted, such as the `T` of `"y-MM-ddTHH:mm"`, which must
+    /// be written as `'T'`.
+    ///
+    /// This catches the typos in patterns supplied by users, which would otherwise be
+    /// formatted as literals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, DateTimeFormatError};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let result = DateTimeFormat::try_new_with_pattern_strict(langid!("en"), &provider, "y-MM-ddTHH:mm");
+    /// assert!(matches!(result, Err(DateTimeFormatError::Pattern(_))));
+    ///
+    /// let result = DateTimeFormat::try_new_with_pattern_strict(langid!("en"), &provider, "y-MM-dd'T'HH:mm");
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn try_new_with_pattern_strict<D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+        pattern: &str,
+    ) -> Result<Self, DateTimeFormatError> {
+        let pattern = Pattern::from_bytes_strict(pattern)?;
+        Self::try_new_from_pattern(langid, data_provider, pattern)
+    }
+
+    fn try_new_from_pattern<D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+        pattern: Pattern,
+    ) -> Result<Self, DateTimeFormatError> {
+        let locale_data = LocaleData::new(&langid, None, &DateTimeFormatOptions::default());
+        Self::try_new_with_data(langid, data_provider, locale_data, |_| Ok(pattern))
+    }
+
+    /// Loads the data of the `langid`, and formats with the pattern which `get_pattern`
+    /// selects from it.
+    fn try_new_with_data<D: DataProvider<'d, provider::gregory::DatesV1> + ?Sized>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+        locale_data: LocaleData,
+        get_pattern: impl FnOnce(&provider::gregory::DatesV1) -> Result<Pattern, DateTimeFormatError>,
+    ) -> Result<Self, DateTimeFormatError> {
+        let mut response = data_provider.load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: provider::key::GREGORY_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid.clone()),
+                },
+            },
+        })?;
+        let data = response.take_payload()?;
+        let mut pattern = get_pattern(&data)?;
+        // The languages without era names in the data are formatted without the Gregorian
+        // eras, rather than with the names of another language.
+        if data.symbols.eras.is_none() && locale_data.calendar == preferences::Calendar::Gregorian {
+            pattern = remove_era(&pattern);
+        }
 
         Ok(Self {
             _langid: langid,
             pattern,
             data,
+            locale_data,
+            #[cfg(feature = "debug_provenance")]
+            data_langid: response.metadata.data_langid,
         })
     }
 
+    /// Replaces the literals of the pattern, such as the separators between the fields,
+    /// which are keys of `overrides` by their values. The literals are matched as a whole,
+    /// so `", "` doesn't match `","`, and the fields are never replaced.
+    ///
+    /// Subsequent calls apply to the pattern as replaced by the previous ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    /// use std::collections::HashMap;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Short),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let mut overrides = HashMap::new();
+    /// overrides.insert("/".to_string(), ".".to_string());
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.")
+    ///     .with_literal_overrides(overrides);
+    ///
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dtf.format_to_string(&date_time), "9.12.20");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn with_literal_overrides(mut self, overrides: HashMap<String, String>) -> Self {
+        self.pattern.replace_literals(&overrides);
+        self
+    }
+
     /// `format` takes a `DateTime` value and returns an instance of a `FormattedDateTime` object
     /// which contains all information necessary to display a formatted date and operate on it.
     ///
@@ -216,6 +414,7 @@ impl<'d> DateTimeFormat<'d> {
         FormattedDateTime {
             pattern: &self.pattern,
             data: &self.data,
+            locale_data: &self.locale_data,
             date_time: value,
         }
     }
@@ -223,6 +422,10 @@ impl<'d> DateTimeFormat<'d> {
     /// `format_to_write` takes a mutable reference to anything that implements `Write` trait
     /// and a `DateTime` value and populates the buffer with a formatted value.
     ///
+    /// The time zone fields of the pattern are left out, along with their literals, for the
+    /// values without a GMT offset, such as a `MockDateTime`. Use
+    /// [`try_format_to_string`](Self::try_format_to_string) to reject them instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -249,7 +452,490 @@ impl<'d> DateTimeFormat<'d> {
     where
         T: DateTimeType,
     {
-        write_pattern(&self.pattern, &self.data, value, w).map_err(|_| std::fmt::Error)
+        write_pattern_lenient(&self.pattern, &self.data, &self.locale_data, value, w)
+    }
+
+    // Writes the `value` formatted with the pattern to `w`.
+    fn write_to<T, W>(&self, w: &mut W, value: &T) -> Result<(), DateTimeFormatError>
+    where
+        T: DateTimeType,
+        W: std::fmt::Write + ?Sized,
+    {
+        write_pattern(&self.pattern, &self.data, &self.locale_data, value, w)
+    }
+
+    /// `write_fmt` takes a `Formatter` and a `DateTime` value and writes the formatted value
+    /// to it, so that it can be used in `Display` implementations without an intermediate
+    /// `String`.
+    ///
+    /// The width, fill and alignment flags of the `Formatter` are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use icu_locid_macros::langid;
+    /// # use icu_datetime::DateTimeFormat;
+    /// # use icu_datetime::date::MockDateTime;
+    /// # use icu_provider::inv::InvariantDataProvider;
+    /// use std::fmt;
+    ///
+    /// struct Event<'a> {
+    ///     dtf: &'a DateTimeFormat<'a>,
+    ///     start: MockDateTime,
+    /// }
+    ///
+    /// impl fmt::Display for Event<'_> {
+    ///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    ///         f.write_str("Starts on ")?;
+    ///         self.dtf.write_fmt(f, &self.start)
+    ///     }
+    /// }
+    ///
+    /// # let lid = langid!("en");
+    /// # let provider = InvariantDataProvider;
+    /// let dtf = DateTimeFormat::try_new_with_pattern(lid, &provider, "y-MM-dd HH:mm")
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let event = Event {
+    ///     dtf: &dtf,
+    ///     start: MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///         .expect("Failed to construct DateTime."),
+    /// };
+    /// assert_eq!(event.to_string(), "Starts on 2020-10-02 12:34");
+    /// ```
+    pub fn write_fmt<T>(&self, f: &mut std::fmt::Formatter, value: &T) -> std::fmt::Result
+    where
+        T: DateTimeType,
+    {
+        self.format_to_write(f, value)
+    }
+
+    /// `format_all_into` takes a slice of `DateTime` values and writes each of them, formatted,
+    /// to the corresponding `String` of `out`.
+    ///
+    /// The `String`s already in `out` are reused, so formatting batches of similar values
+    /// repeatedly into the same `Vec` doesn't allocate. `out` is resized to the length of
+    /// `values`.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if a value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`, leaving the `String`s of the
+    /// values which follow it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let values: Vec<MockDateTime> = vec![
+    ///     "2020-09-12T12:35:00".parse().unwrap(),
+    ///     "2020-10-14T13:21:00".parse().unwrap(),
+    /// ];
+    /// let mut out = Vec::new();
+    /// dtf.format_all_into(&values, &mut out)
+    ///     .expect("Failed to format the values.");
+    /// assert_eq!(out, vec!["Sep 12, 2020", "Oct 14, 2020"]);
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn format_all_into<T>(
+        &self,
+        values: &[T],
+        out: &mut Vec<String>,
+    ) -> Result<(), DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        out.truncate(values.len());
+        for (idx, value) in values.iter().enumerate() {
+            if let Some(s) = out.get_mut(idx) {
+                s.clear();
+                self.write_to(s, value)?;
+            } else {
+                out.push(self.try_format_to_string(value)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// `format_all_joined_into` takes a slice of `DateTime` values and appends them, formatted
+    /// and separated by `separator`, to `out`.
+    ///
+    /// Writing all of the values into a single `String` avoids allocating for each of them.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if a value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`, in which case `out` may end with
+    /// a partially formatted value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let values: Vec<MockDateTime> = vec![
+    ///     "2020-09-12T12:35:00".parse().unwrap(),
+    ///     "2020-10-14T13:21:00".parse().unwrap(),
+    /// ];
+    /// let mut out = String::new();
+    /// dtf.format_all_joined_into(&values, "\n", &mut out)
+    ///     .expect("Failed to format the values.");
+    /// assert_eq!(out, "Sep 12, 2020\nOct 14, 2020");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn format_all_joined_into<T>(
+        &self,
+        values: &[T],
+        separator: &str,
+        out: &mut String,
+    ) -> Result<(), DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        for (idx, value) in values.iter().enumerate() {
+            if idx > 0 {
+                out.push_str(separator);
+            }
+            self.write_to(out, value)?;
+        }
+        Ok(())
+    }
+
+    /// `format_rfc3339_to_write` takes a mutable reference to anything that implements `Write`
+    /// trait and a `DateTime` value, and writes the value as an RFC 3339 timestamp in UTC.
+    /// A value with a GMT offset, such as a `MockZonedDateTime`, is shifted to UTC first.
+    ///
+    /// Unlike [`format_to_write`](Self::format_to_write), the output doesn't depend on the
+    /// pattern or the locale of this `DateTimeFormat`, which makes it suitable for
+    /// machine-readable output such as logs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use icu_locid_macros::langid;
+    /// # use icu_datetime::{DateTimeFormat, DateTimeFormatOptions};
+    /// # use icu_datetime::date::MockDateTime;
+    /// # use icu_provider::inv::InvariantDataProvider;
+    /// # let lid = langid!("en");
+    /// # let provider = InvariantDataProvider;
+    /// # let options = DateTimeFormatOptions::default();
+    /// let dtf = DateTimeFormat::try_new(lid, &provider, &options)
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let mut buffer = String::new();
+    /// dtf.format_rfc3339_to_write(&mut buffer, &date_time)
+    ///     .expect("Failed to write to a buffer.");
+    /// assert_eq!(buffer, "2020-10-02T12:34:28Z");
+    /// ```
+    pub fn format_rfc3339_to_write<T>(
+        &self,
+        w: &mut impl std::fmt::Write,
+        value: &T,
+    ) -> std::fmt::Result
+    where
+        T: DateTimeType,
+    {
+        write_rfc3339(value, w)
+    }
+
+    /// `format_rfc3339` takes a `DateTime` value and returns it formatted as an RFC 3339
+    /// timestamp in UTC.
+    ///
+    /// See [`format_rfc3339_to_write`](Self::format_rfc3339_to_write) for details.
+    pub fn format_rfc3339<T>(&self, value: &T) -> String
+    where
+        T: DateTimeType,
+    {
+        let mut s = String::with_capacity(20);
+        self.format_rfc3339_to_write(&mut s, value)
+            .expect("Failed to write to a String.");
+        s
+    }
+
+    /// `format_partial_to_string` takes a `PartialDateTime` value and returns it formatted
+    /// as a string, omitting the fields of the pattern which are absent in the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::PartialDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: Some(style::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date: PartialDateTime = "2020-09-12".parse()
+    ///     .expect("Failed to parse a partial date time.");
+    /// assert_eq!(dtf.format_partial_to_string(&date), "Sep 12, 2020");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn format_partial_to_string(&self, value: &PartialDateTime) -> String {
+        let pattern = filter_pattern(&self.pattern, value);
+        let mut s = String::new();
+        write_pattern(
+            &pattern,
+            &self.data,
+            &self.locale_data,
+            &value.to_date_time(),
+            &mut s,
+        )
+        .expect("The fields which the value lacks are filtered out.");
+        s
+    }
+
+    /// `format_to_string_with_reference` takes a `DateTime` value and a reference `DateTime`,
+    /// usually the current date, and returns the value formatted as a string.
+    ///
+    /// If the value falls within the same year as the reference, the year and the era are
+    /// omitted, along with the literals separating them from the other fields. The other
+    /// fields, such as the weekday, are kept.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if the value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Long),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let now: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    ///
+    /// assert_eq!(
+    ///     dtf.format_to_string_with_reference(&date_time, &now).unwrap(),
+    ///     "September 12"
+    /// );
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn format_to_string_with_reference<T>(
+        &self,
+        value: &T,
+        reference: &T,
+    ) -> Result<String, DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        if value.year() != reference.year() {
+            return self.try_format_to_string(value);
+        }
+        let mut s = String::new();
+        write_pattern(
+            &remove_year(&self.pattern),
+            &self.data,
+            &self.locale_data,
+            value,
+            &mut s,
+        )?;
+        Ok(s)
+    }
+
+    /// `format_relative_day` takes a `DateTime` value and a reference `DateTime`, usually the
+    /// current date, and formats the value with the localized name of its day relative to the
+    /// day of the reference, such as "yesterday", "today" or "tomorrow", in place of its date.
+    ///
+    /// The time fields of the pattern are kept, along with the literal joining them to the
+    /// date, so that "Oct 13, 2020, 11:59 PM" becomes "yesterday, 11:59 PM".
+    ///
+    /// If the data has no name for the number of days between them, such as a week, the value
+    /// is formatted like in [`DateTimeFormat::format_to_string`] instead. Some languages also
+    /// name the days two days before or after the reference, such as "après-demain" in French.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if the value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: Some(style::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let now: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let date_time: MockDateTime = "2020-10-13T23:59:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dtf.format_relative_day(&date_time, &now).unwrap(), "yesterday, 11:59 PM");
+    ///
+    /// let date_time: MockDateTime = "2020-10-21T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dtf.format_relative_day(&date_time, &now).unwrap(), "Oct 21, 2020, 1:21 PM");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn format_relative_day<T>(
+        &self,
+        value: &T,
+        reference: &T,
+    ) -> Result<String, DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        fn days_since_epoch(date_time: &impl DateTimeType) -> i64 {
+            date::civil::days_from_civil(
+                i64::from(date_time.year()),
+                u8::from(date_time.month()) + 1,
+                u8::from(date_time.day()) + 1,
+            )
+        }
+
+        let days = days_since_epoch(value) - days_since_epoch(reference);
+        let name = match self.data.get_relative_day(days) {
+            Some(name) => name,
+            None => return self.try_format_to_string(value),
+        };
+        let mut s = String::new();
+        write_pattern(
+            &replace_date(&self.pattern, name),
+            &self.data,
+            &self.locale_data,
+            value,
+            &mut s,
+        )?;
+        Ok(s)
+    }
+
+    /// `parse` takes a string formatted according to the pattern of this `DateTimeFormat`
+    /// and parses it back into a `MockDateTime`.
+    ///
+    /// Fields which are not present in the pattern are set to their lowest values. The
+    /// offset of the ISO 8601 offset fields, `x` and `X`, is read but dropped, use
+    /// [`DateTimeFormat::parse_zoned`] to keep it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time = dtf.parse("Sep 12, 2020")
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(date_time, "2020-09-12T00:00:00".parse().unwrap());
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<MockDateTime, DateTimeError> {
+        parse::parse_pattern(&self.pattern, Some((&self.data, &self.locale_data)), input)
+    }
+
+    /// Like [`DateTimeFormat::parse`], but also returns the offset read from the ISO 8601
+    /// offset fields of the pattern, `x` and `X`, such as `+05:30`, or a zero offset if the
+    /// pattern has none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::DateTimeFormat;
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let dtf = DateTimeFormat::try_new_with_pattern(langid!("en"), &provider, "y-MM-dd'T'HH:mmXXX")
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time = dtf.parse_zoned("2020-10-14T13:21+05:30")
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(date_time, "2020-10-14T13:21:00+05:30".parse().unwrap());
+    /// ```
+    pub fn parse_zoned(&self, input: &str) -> Result<MockZonedDateTime, DateTimeError> {
+        parse::parse_pattern_zoned(&self.pattern, Some((&self.data, &self.locale_data)), input)
+    }
+
+    /// Returns `true` if formatting the `value` and parsing the result back with [`parse`]
+    /// returns the same value, which isn't the case if the pattern lacks some of its
+    /// fields, such as the year or the time, or if the value can't be formatted, such as
+    /// with a GMT offset field in the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date: MockDateTime = "2020-09-12T00:00:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert!(dtf.round_trips(&date));
+    ///
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert!(!dtf.round_trips(&date_time));
+    /// # } // feature = "provider_serde"
+    /// ```
+    ///
+    /// [`parse`]: Self::parse
+    pub fn round_trips(&self, value: &MockDateTime) -> bool {
+        match self.try_format_to_string(value) {
+            Ok(formatted) => matches!(self.parse(&formatted), Ok(parsed) if parsed == *value),
+            Err(_) => false,
+        }
     }
 
     /// `format_to_string` takes a `DateTime` value and returns it formatted
@@ -273,6 +959,9 @@ impl<'d> DateTimeFormat<'d> {
     ///
     /// let _ = dtf.format_to_string(&date_time);
     /// ```
+    ///
+    /// The time zone fields of the pattern are left out, along with their literals, for the
+    /// values without a GMT offset, like in [`format_to_write`](Self::format_to_write).
     pub fn format_to_string<T>(&self, value: &T) -> String
     where
         T: DateTimeType,
@@ -282,4 +971,224 @@ impl<'d> DateTimeFormat<'d> {
             .expect("Failed to write to a String.");
         s
     }
+
+    /// `try_format_to_string` takes a `DateTime` value and returns it formatted as a string,
+    /// or [`DateTimeFormatError::MissingInputField`] if the value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, DateTimeFormatError};
+    /// use icu_datetime::date::{MockDateTime, MockZonedDateTime};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let dtf = DateTimeFormat::try_new_with_pattern(langid!("en"), &provider, "HH:mm O")
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time: MockZonedDateTime = "2020-09-12T12:35:00+05:30".parse()
+    ///     .expect("Failed to parse a zoned date time.");
+    /// assert_eq!(dtf.try_format_to_string(&date_time).unwrap(), "12:35 GMT+5:30");
+    ///
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert!(matches!(
+    ///     dtf.try_format_to_string(&date_time),
+    ///     Err(DateTimeFormatError::MissingInputField("GmtOffset"))
+    /// ));
+    /// ```
+    pub fn try_format_to_string<T>(&self, value: &T) -> Result<String, DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        let mut s = String::new();
+        self.write_to(&mut s, value)?;
+        Ok(s)
+    }
+
+    /// `format_to_cow` takes a `DateTime` value and returns it formatted like
+    /// [`format_to_string`], except that the patterns without fields, which format every
+    /// value the same way, borrow their literal instead of allocating a `String`.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if the value lacks a field of the
+    /// pattern, like [`try_format_to_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use icu_locid_macros::langid;
+    /// # use icu_datetime::{DateTimeFormat, DateTimeFormatOptions};
+    /// # use icu_datetime::date::MockDateTime;
+    /// # use icu_provider::inv::InvariantDataProvider;
+    /// # let lid = langid!("en");
+    /// # let provider = InvariantDataProvider;
+    /// # let options = DateTimeFormatOptions::default();
+    /// let dtf = DateTimeFormat::try_new(lid, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// assert_eq!(
+    ///     dtf.format_to_cow(&date_time).unwrap(),
+    ///     dtf.format_to_string(&date_time)
+    /// );
+    /// ```
+    ///
+    /// [`format_to_string`]: Self::format_to_string
+    /// [`try_format_to_string`]: Self::try_format_to_string
+    pub fn format_to_cow<T>(&self, value: &T) -> Result<Cow<'_, str>, DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        format_pattern_to_cow(&self.pattern, &self.data, &self.locale_data, value)
+    }
+
+    /// Returns an upper bound of the length in bytes of the values formatted by this
+    /// `DateTimeFormat`, which can be used to preallocate buffers.
+    ///
+    /// The numeric fields count the length of the digits of the numbering system, which is
+    /// larger than one byte outside of ASCII. Years are assumed to have up to ten digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     time: Some(style::Time::Short),
+    ///     date: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// assert_eq!(dtf.format_to_string(&date_time), "12:35 PM");
+    /// assert_eq!(dtf.max_width(), "12:35 PM".len());
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn max_width(&self) -> usize {
+        max_width(&self.pattern, &self.data, &self.locale_data)
+    }
+
+    /// Returns the rendered fields of the pattern as a JSON object keyed by their types, such
+    /// as `{ "month": "Oct", "day": "14", "year": "2020" }`, for clients which combine them
+    /// on their own.
+    ///
+    /// The types are the ones of the parts of ECMA-402's `formatToParts`: `era`, `year`,
+    /// `month`, `weekday`, `day`, `dayPeriod`, `hour`, `minute`, `second` and
+    /// `timeZoneName`, along with `quarter`. The literals are left out, and a field present
+    /// more than once in the pattern keeps its first rendering.
+    ///
+    /// Returns [`DateTimeFormatError::MissingInputField`] if the value lacks a field of the
+    /// pattern, such as the GMT offset of a `MockDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, date::MockDateTime, options::style};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let options = style::Bag {
+    ///     date: Some(style::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let date_time: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a date time.");
+    /// let parts = dtf.format_structured(&date_time)
+    ///     .expect("Failed to format a date time.");
+    /// assert_eq!(parts["month"], "Oct");
+    /// assert_eq!(parts["day"], "14");
+    /// assert_eq!(parts["year"], "2020");
+    /// # } // feature = "provider_serde"
+    /// ```
+    #[cfg(feature = "structured")]
+    pub fn format_structured<T>(&self, value: &T) -> Result<serde_json::Value, DateTimeFormatError>
+    where
+        T: DateTimeType,
+    {
+        use fields::{FieldSymbol, TimeZone};
+
+        fn part_type(symbol: FieldSymbol) -> &'static str {
+            match symbol {
+                FieldSymbol::Era => "era",
+                FieldSymbol::Year(..) => "year",
+                FieldSymbol::Quarter(..) => "quarter",
+                FieldSymbol::Month(..) => "month",
+                FieldSymbol::Weekday(..) => "weekday",
+                FieldSymbol::Day(..) => "day",
+                FieldSymbol::Hour(..) => "hour",
+                FieldSymbol::Minute => "minute",
+                FieldSymbol::Second(..) => "second",
+                FieldSymbol::DayPeriod(..) => "dayPeriod",
+                FieldSymbol::TimeZone(TimeZone::LocalizedGmt)
+                | FieldSymbol::TimeZone(TimeZone::IsoWithZ)
+                | FieldSymbol::TimeZone(TimeZone::Iso) => "timeZoneName",
+            }
+        }
+
+        let mut parts = serde_json::Map::new();
+        for item in self.pattern.items() {
+            if let pattern::PatternItem::Field(field) = item {
+                let mut s = String::new();
+                format::write_field(
+                    &self.pattern,
+                    field,
+                    &self.data,
+                    &self.locale_data,
+                    value,
+                    &mut s,
+                )?;
+                parts
+                    .entry(part_type(field.symbol))
+                    .or_insert(serde_json::Value::String(s));
+            }
+        }
+        Ok(serde_json::Value::Object(parts))
+    }
+
+    /// Returns each field of the pattern along with the locale which supplied its data, as
+    /// reported by the `DataProvider`, or `None` if it wasn't reported.
+    ///
+    /// The patterns and the symbols are loaded together, so all of the fields currently
+    /// share the same locale, which is a fallback of the requested one when the provider
+    /// has no data for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::{DateTimeFormat, DateTimeFormatOptions};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let provider = InvariantDataProvider;
+    /// let dtf = DateTimeFormat::try_new(langid!("en"), &provider, &DateTimeFormatOptions::default())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// for (_, langid) in dtf.provenance() {
+    ///     assert_eq!(langid, None);
+    /// }
+    /// ```
+    #[cfg(feature = "debug_provenance")]
+    pub fn provenance(&self) -> Vec<(&pattern::PatternItem, Option<&LanguageIdentifier>)> {
+        self.pattern
+            .items()
+            .iter()
+            .filter(|item| matches!(item, pattern::PatternItem::Field(..)))
+            .map(|item| (item, self.data_langid.as_ref()))
+            .collect()
+    }
 }
@@ -0,0 +1,107 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::calendars;
+use crate::date::WeekDay;
+use crate::day_periods::{self, FlexibleDayPeriod};
+use crate::fields::FieldLength;
+use crate::gmt_format::{self, GmtFormat};
+use crate::numbering_systems::{self, NumberingSystem};
+use crate::options::preferences::{self, Calendar};
+use crate::options::DateTimeFormatOptions;
+use crate::ordinals::OrdinalSuffixes;
+use crate::quarters::{self, QuarterNames};
+use crate::week_data;
+use icu_locid::LanguageIdentifier;
+
+/// The pivot of two-digit years, which are parsed within `2000..=2099`.
+pub const DEFAULT_TWO_DIGIT_YEAR_PIVOT: i32 = 2050;
+
+/// Locale data used for formatting which is not yet available through the `DataProvider`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LocaleData {
+    pub calendar: Calendar,
+    pub first_weekday: WeekDay,
+    pub day_periods: &'static [FlexibleDayPeriod],
+    pub gmt_format: GmtFormat,
+    pub numbering_system: NumberingSystem,
+    /// The minimum number of digits of the years which aren't two-digit years.
+    pub min_year_digits: usize,
+    /// The middle of the century in which two-digit years are parsed.
+    pub two_digit_year_pivot: i32,
+    /// The width of the month names overriding the one of the pattern, if any.
+    pub month_width: Option<FieldLength>,
+    /// Whether the numeric fields other than the year are written without padding.
+    pub suppress_leading_zeros: bool,
+    /// The suffixes for the day of the month, present only if an ordinal day was requested
+    /// and the language supports it.
+    pub day_ordinals: Option<OrdinalSuffixes>,
+    /// The names of the quarters, if the language has them, or `None` to render the text
+    /// quarter fields as numbers.
+    pub quarters: Option<&'static QuarterNames>,
+}
+
+impl LocaleData {
+    /// Collects the data for the `langid`, where the `calendar` selected by the locale is
+    /// overridden by the one of the preferences, and defaults to the one of the region.
+    pub fn new(
+        langid: &LanguageIdentifier,
+        calendar: Option<Calendar>,
+        options: &DateTimeFormatOptions,
+    ) -> Self {
+        let ordinal_day = matches!(
+            options.preferences(),
+            Some(preferences::Bag {
+                ordinal_day: true,
+                ..
+            })
+        );
+        let calendar = options
+            .preferences()
+            .and_then(|preferences| preferences.calendar)
+            .or(calendar)
+            .unwrap_or_else(|| calendars::default_calendar(langid));
+        Self {
+            calendar,
+            first_weekday: week_data::first_weekday(langid),
+            day_periods: day_periods::get_flexible_day_periods(langid),
+            gmt_format: gmt_format::get_gmt_format(langid),
+            numbering_system: numbering_systems::get_numbering_system(langid),
+            min_year_digits: options
+                .preferences()
+                .and_then(|preferences| preferences.min_year_digits)
+                .map_or(1, usize::from),
+            two_digit_year_pivot: options
+                .preferences()
+                .and_then(|preferences| preferences.two_digit_year_pivot)
+                .unwrap_or(DEFAULT_TWO_DIGIT_YEAR_PIVOT),
+            month_width: options
+                .preferences()
+                .and_then(|preferences| preferences.month_width)
+                .map(FieldLength::from),
+            suppress_leading_zeros: matches!(
+                options.preferences(),
+                Some(preferences::Bag {
+                    suppress_leading_zeros: true,
+                    ..
+                })
+            ),
+            day_ordinals: if ordinal_day {
+                OrdinalSuffixes::try_new(langid)
+            } else {
+                None
+            },
+            quarters: quarters::get_quarter_names(langid),
+        }
+    }
+
+    /// Returns the length by which a numeric field other than the year is padded, which
+    /// is the `length` of the pattern unless the leading zeros are suppressed.
+    pub fn numeric_length(&self, length: FieldLength) -> FieldLength {
+        if self.suppress_leading_zeros {
+            FieldLength::One
+        } else {
+            length
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use icu_locid::{LanguageIdentifier, Locale};
+
+/// A decimal numbering system, whose digits are consecutive code points.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct NumberingSystem {
+    /// The identifier of the numbering system, used by the `nu` keyword, such as `latn`.
+    pub id: &'static str,
+    /// The digit for zero.
+    pub zero: char,
+}
+
+// The decimal digits of the supported numbering systems of the CLDR `numberingSystems` data.
+pub const LATN: NumberingSystem = NumberingSystem {
+    id: "latn",
+    zero: '0',
+};
+pub const ARAB: NumberingSystem = NumberingSystem {
+    id: "arab",
+    zero: '\u{660}',
+};
+pub const ARABEXT: NumberingSystem = NumberingSystem {
+    id: "arabext",
+    zero: '\u{6F0}',
+};
+pub const BENG: NumberingSystem = NumberingSystem {
+    id: "beng",
+    zero: '\u{9E6}',
+};
+pub const DEVA: NumberingSystem = NumberingSystem {
+    id: "deva",
+    zero: '\u{966}',
+};
+pub const MYMR: NumberingSystem = NumberingSystem {
+    id: "mymr",
+    zero: '\u{1040}',
+};
+pub const THAI: NumberingSystem = NumberingSystem {
+    id: "thai",
+    zero: '\u{E50}',
+};
+
+const NUMBERING_SYSTEMS: &[NumberingSystem] = &[LATN, ARAB, ARABEXT, BENG, DEVA, MYMR, THAI];
+
+// Languages not listed here use the Latin digits by default.
+const DEFAULTS: &[(&str, NumberingSystem)] = &[
+    ("ar", ARAB),
+    ("bn", BENG),
+    ("fa", ARABEXT),
+    ("mr", DEVA),
+    ("my", MYMR),
+    ("ne", DEVA),
+    ("ps", ARABEXT),
+];
+
+/// Returns the default numbering system of the language of the given `LanguageIdentifier`.
+pub fn get_numbering_system(langid: &LanguageIdentifier) -> NumberingSystem {
+    get_default(langid.language.as_str())
+}
+
+fn get_default(language: &str) -> NumberingSystem {
+    DEFAULTS
+        .iter()
+        .find(|(default_language, _)| *default_language == language)
+        .map_or(LATN, |(_, numbering_system)| *numbering_system)
+}
+
+/// Returns the numbering system selected by the `nu` keyword of the `locale`, or the default
+/// numbering system of its language if the keyword is absent or unknown.
+///
+/// This is the numbering system in which [`DateTimeFormat`](crate::DateTimeFormat) formats
+/// and parses the numeric fields.
+///
+/// # Examples
+///
+/// ```
+/// use icu_datetime::default_numbering_system;
+/// use icu_locid::Locale;
+///
+/// let locale: Locale = "ar".parse().expect("Failed to parse a locale.");
+/// assert_eq!(default_numbering_system(&locale).id, "arab");
+///
+/// let locale: Locale = "ar-u-nu-latn".parse().expect("Failed to parse a locale.");
+/// assert_eq!(default_numbering_system(&locale).id, "latn");
+/// ```
+pub fn default_numbering_system(locale: &Locale) -> NumberingSystem {
+    let keyword = locale
+        .extensions
+        .unicode
+        .keywords
+        .iter()
+        .find(|(key, _)| key.as_str() == "nu")
+        .map(|(_, value)| value.to_string());
+    if let Some(keyword) = keyword {
+        if let Some(numbering_system) = NUMBERING_SYSTEMS.iter().find(|ns| ns.id == keyword) {
+            return *numbering_system;
+        }
+    }
+    get_default(locale.language.as_str())
+}
+
+impl NumberingSystem {
+    /// Returns the character for the `digit`, which must be less than `10`.
+    pub fn digit(self, digit: u8) -> char {
+        debug_assert!(digit < 10);
+        std::char::from_u32(self.zero as u32 + u32::from(digit)).unwrap_or(self.zero)
+    }
+
+    /// Returns the value of the digit `c` of the numbering system, if it is one.
+    pub fn digit_value(self, c: char) -> Option<u8> {
+        match (c as u32).checked_sub(self.zero as u32) {
+            Some(value) if value < 10 => Some(value as u8),
+            _ => None,
+        }
+    }
+
+    /// Returns the length in bytes of the longest digit, which is more than one outside
+    /// of ASCII.
+    pub fn max_digit_len(self) -> usize {
+        (0..10)
+            .map(|digit| self.digit(digit).len_utf8())
+            .max()
+            .unwrap_or(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_numbering_system() {
+        let samples = &[
+            ("en", "latn"),
+            ("en-US", "latn"),
+            ("ar", "arab"),
+            ("ar-EG", "arab"),
+            ("fa", "arabext"),
+            ("ar-u-nu-latn", "latn"),
+            ("en-u-nu-thai", "thai"),
+            ("ar-u-nu-unknown", "arab"),
+        ];
+        for (locale, expected) in samples {
+            let locale: Locale = locale.parse().unwrap();
+            assert_eq!(
+                default_numbering_system(&locale).id,
+                *expected,
+                "{}",
+                locale
+            );
+        }
+    }
+
+    #[test]
+    fn test_digit() {
+        assert_eq!(LATN.digit(7), '7');
+        assert_eq!(ARAB.digit(7), '\u{667}');
+        assert_eq!(ARAB.digit_value('\u{667}'), Some(7));
+        assert_eq!(ARAB.digit_value('7'), None);
+        assert_eq!(LATN.digit_value('/'), None);
+        assert_eq!(ARAB.max_digit_len(), 2);
+        assert_eq!(MYMR.max_digit_len(), 3);
+    }
+}
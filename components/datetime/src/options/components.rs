@@ -55,7 +55,7 @@
 //! and it is strongly recommended to never write tests that expect a particular formatted output.
 use super::preferences;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bag {
     pub era: Option<Text>,
     pub year: Option<Numeric>,
@@ -50,7 +50,7 @@ pub mod style;
 ///
 /// At the moment only the `Style` bag works, and we plan to extend that to support
 /// `ECMA 402` like components bag later.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum DateTimeFormatOptions {
     /// Bag of styles for date and time
     Style(style::Bag),
@@ -58,6 +58,16 @@ pub enum DateTimeFormatOptions {
     Components(components::Bag),
 }
 
+impl DateTimeFormatOptions {
+    /// Returns the user preferences of the bag, if any.
+    pub(crate) fn preferences(&self) -> Option<&preferences::Bag> {
+        match self {
+            Self::Style(bag) => bag.preferences.as_ref(),
+            Self::Components(bag) => bag.preferences.as_ref(),
+        }
+    }
+}
+
 impl Default for DateTimeFormatOptions {
     fn default() -> Self {
         Self::Style(style::Bag::default())
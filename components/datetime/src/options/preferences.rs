@@ -15,10 +15,13 @@
 //! use icu_datetime::options::preferences;
 //!
 //! let prefs = preferences::Bag {
-//!     hour_cycle: Some(preferences::HourCycle::H23)
+//!     hour_cycle: Some(preferences::HourCycle::H23),
+//!     ..Default::default()
 //! };
 //! ```
+use crate::error::DateTimeFormatError;
 use crate::fields;
+use icu_locid::extensions::unicode::Keywords;
 
 /// Bag of preferences stores user preferences which may affect the result of date and time formatting.
 ///
@@ -28,12 +31,138 @@ use crate::fields;
 /// use icu_datetime::options::preferences;
 ///
 /// let prefs = preferences::Bag {
-///     hour_cycle: Some(preferences::HourCycle::H23)
+///     hour_cycle: Some(preferences::HourCycle::H23),
+///     ordinal_day: true,
+///     calendar: Some(preferences::Calendar::Buddhist),
+///     min_year_digits: Some(4),
+///     two_digit_year_pivot: Some(2000),
+///     month_width: Some(preferences::MonthWidth::Abbreviated),
+///     suppress_leading_zeros: false,
 /// };
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct Bag {
     pub hour_cycle: Option<HourCycle>,
+    /// Renders the day of the month as an ordinal, such as "14th", in languages which
+    /// support it.
+    pub ordinal_day: bool,
+    /// The calendar in which the dates are displayed, the one selected by the locale or
+    /// the default one of its region, or of the likely region of its language, such as the
+    /// Buddhist calendar for `th-TH` and `th`, if `None`.
+    pub calendar: Option<Calendar>,
+    /// The minimum number of digits of the year, which are padded with zeros.
+    ///
+    /// This overrides the number of `y` of the pattern when it is larger, so that `y`
+    /// renders the year `5` as "0005" with `Some(4)`. Two-digit years, `yy`, are not affected
+    /// and always keep their last two digits.
+    pub min_year_digits: Option<u8>,
+    /// The middle of the century in which two-digit years, `yy`, are parsed, `2050` if
+    /// `None`.
+    ///
+    /// Two digits are expanded to the year ending with them within 50 years before and
+    /// 49 years after the pivot, so that "20" is parsed as 2020 with `Some(2000)`, and as
+    /// 1920 with `Some(1930)`.
+    pub two_digit_year_pivot: Option<i32>,
+    /// The width of the month names, which overrides the one of the pattern, so that
+    /// `MMMM` renders "Oct" with `Some(MonthWidth::Abbreviated)`. Numeric months are not
+    /// affected.
+    pub month_width: Option<MonthWidth>,
+    /// Renders the numeric fields without leading zeros, overriding the padding of the
+    /// pattern, so that `dd-MM` renders "4-7" rather than "04-07", as `d-M` would.
+    ///
+    /// The years keep the width of the pattern and the `min_year_digits`, so that `yyyy` still
+    /// renders "0005", and `yy` its last two digits.
+    pub suppress_leading_zeros: bool,
+}
+
+/// User Preference for the width of the month names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MonthWidth {
+    /// Such as "October", as rendered by `MMMM`.
+    Wide,
+    /// Such as "Oct", as rendered by `MMM`.
+    Abbreviated,
+    /// Such as "O", as rendered by `MMMMM`.
+    Narrow,
+}
+
+impl From<MonthWidth> for fields::FieldLength {
+    fn from(input: MonthWidth) -> Self {
+        match input {
+            MonthWidth::Wide => Self::Wide,
+            MonthWidth::Abbreviated => Self::Abbreviated,
+            MonthWidth::Narrow => Self::Narrow,
+        }
+    }
+}
+
+/// User Preference for the calendar in which the dates are displayed, such as the one
+/// selected by the `ca` keyword of the `en-u-ca-buddhist` locale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Calendar {
+    /// The proleptic Gregorian calendar.
+    Gregorian,
+    /// The Thai Buddhist calendar, which shares the months and days of the Gregorian
+    /// calendar, and counts the years from 543 BC.
+    Buddhist,
+}
+
+impl Calendar {
+    /// The values of the `ca` keyword of the supported calendars.
+    pub const KEYWORDS: &'static [(&'static str, Calendar)] = &[
+        ("gregory", Calendar::Gregorian),
+        ("buddhist", Calendar::Buddhist),
+    ];
+
+    /// Returns the calendar selected by the `ca` keyword, if any, or an error if it isn't
+    /// supported.
+    pub(crate) fn from_keywords(keywords: &Keywords) -> Result<Option<Self>, DateTimeFormatError> {
+        let value = match keywords.iter().find(|(key, _)| key.as_str() == "ca") {
+            Some((_, value)) => value.to_string(),
+            None => return Ok(None),
+        };
+        Self::KEYWORDS
+            .iter()
+            .find(|(keyword, _)| *keyword == value)
+            .map(|(_, calendar)| Some(*calendar))
+            .ok_or(DateTimeFormatError::UnsupportedCalendar(value))
+    }
+
+    /// Returns the number of months in the `year` of the calendar.
+    ///
+    /// All of the supported calendars share the twelve Gregorian months, but lunisolar
+    /// calendars add a leap month to some of their years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::options::preferences::Calendar;
+    ///
+    /// assert_eq!(Calendar::Gregorian.months_in_year(2020), 12);
+    /// ```
+    pub fn months_in_year(self, _year: i32) -> u8 {
+        match self {
+            Self::Gregorian | Self::Buddhist => 12,
+        }
+    }
+}
+
+/// The fields of the patterns which a calendar defines, checked by
+/// [`Pattern::validate_for_calendar`](crate::pattern::Pattern::validate_for_calendar).
+///
+/// All of the supported calendars share the fields of the Gregorian calendar, but other
+/// calendars don't define some of them, such as the quarters.
+pub trait CalendarFields {
+    /// Returns `true` if the calendar defines the field of the `symbol`.
+    fn has_field(&self, symbol: fields::FieldSymbol) -> bool;
+}
+
+impl CalendarFields for Calendar {
+    fn has_field(&self, _symbol: fields::FieldSymbol) -> bool {
+        match self {
+            Self::Gregorian | Self::Buddhist => true,
+        }
+    }
 }
 
 /// User Preference for adjusting how hour component is displayed.
@@ -95,3 +224,16 @@ impl HourCycle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_months_in_year() {
+        for year in &[-44, 0, 1900, 2000, 2020, 2021] {
+            assert_eq!(Calendar::Gregorian.months_in_year(*year), 12, "{}", year);
+            assert_eq!(Calendar::Buddhist.months_in_year(*year), 12, "{}", year);
+        }
+    }
+}
@@ -71,7 +71,7 @@ use super::preferences;
 ///
 /// [`UTS #35: Unicode LDML 4. Dates`]: https://unicode.org/reports/tr35/tr35-dates.html
 /// [`Element dateFormats`]: https://unicode.org/reports/tr35/tr35-dates.html#dateFormats
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Bag {
     pub date: Option<Date>,
     pub time: Option<Time>,
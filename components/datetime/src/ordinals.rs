@@ -0,0 +1,98 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use icu_locid::LanguageIdentifier;
+use icu_plurals::rules::{ast::Condition, parse_condition, test_condition};
+use icu_plurals::PluralOperands;
+
+// The English rules of the CLDR ordinal `plurals` supplemental data, with the suffix of
+// the day of the month in each category.
+//
+// Each entry pairs the condition of an ordinal plural category with its suffix, and the
+// `other` category, which has no condition, is listed last.
+const EN: &[(&str, &str)] = &[
+    ("n % 10 = 1 and n % 100 != 11", "st"),
+    ("n % 10 = 2 and n % 100 != 12", "nd"),
+    ("n % 10 = 3 and n % 100 != 13", "rd"),
+    ("", "th"),
+];
+
+/// The suffixes used to render the day of the month as an ordinal, such as "14th".
+#[derive(Debug, PartialEq, Clone)]
+pub struct OrdinalSuffixes(Vec<(Option<Condition>, &'static str)>);
+
+impl OrdinalSuffixes {
+    /// Returns the ordinal suffixes for the language of the given `LanguageIdentifier`,
+    /// or `None` if the language doesn't use ordinal days.
+    pub fn try_new(langid: &LanguageIdentifier) -> Option<Self> {
+        let rules = match langid.language.as_str() {
+            "en" => EN,
+            _ => return None,
+        };
+        let suffixes = rules
+            .iter()
+            .map(|(condition, suffix)| {
+                let condition = match *condition {
+                    "" => None,
+                    condition => Some(
+                        parse_condition(condition.as_bytes())
+                            .expect("Failed to parse an ordinal plural rule."),
+                    ),
+                };
+                (condition, *suffix)
+            })
+            .collect();
+        Some(Self(suffixes))
+    }
+
+    /// Returns the suffix for the ordinal plural category of `n`.
+    pub fn get(&self, n: usize) -> &'static str {
+        let operands = PluralOperands::from(n);
+        self.0
+            .iter()
+            .find(|(condition, _)| match condition {
+                Some(condition) => test_condition(condition, &operands),
+                None => true,
+            })
+            .map_or("", |(_, suffix)| suffix)
+    }
+
+    /// Returns the length in bytes of the longest suffix.
+    pub fn max_len(&self) -> usize {
+        self.0
+            .iter()
+            .map(|(_, suffix)| suffix.len())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ordinal_suffixes() {
+        let langid: LanguageIdentifier = "en-US".parse().unwrap();
+        let suffixes = OrdinalSuffixes::try_new(&langid).unwrap();
+        let samples = &[
+            (1, "st"),
+            (2, "nd"),
+            (3, "rd"),
+            (4, "th"),
+            (11, "th"),
+            (12, "th"),
+            (13, "th"),
+            (21, "st"),
+            (22, "nd"),
+            (23, "rd"),
+            (31, "st"),
+        ];
+        for (n, expected) in samples {
+            assert_eq!(suffixes.get(*n), *expected, "{}", n);
+        }
+
+        let langid: LanguageIdentifier = "fr".parse().unwrap();
+        assert!(OrdinalSuffixes::try_new(&langid).is_none());
+    }
+}
@@ -0,0 +1,548 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::date::{DateTimeError, GmtOffset, MockDateTime, MockZonedDateTime};
+use crate::day_periods::DayPeriodRule;
+use crate::eras;
+use crate::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::locale_data::{LocaleData, DEFAULT_TWO_DIGIT_YEAR_PIVOT};
+use crate::numbering_systems::{self, NumberingSystem};
+use crate::pattern::{Pattern, PatternItem};
+use crate::provider;
+use std::convert::TryFrom;
+
+/// The values collected while parsing an input, before they are validated
+/// and turned into a `MockDateTime`.
+#[derive(Default)]
+struct ParsedFields {
+    year: Option<i32>,
+    month: Option<usize>,
+    day: Option<usize>,
+    hour: Option<(fields::Hour, usize)>,
+    is_pm: Option<bool>,
+    day_period: Option<DayPeriodRule>,
+    minute: Option<usize>,
+    second: Option<usize>,
+    offset: Option<GmtOffset>,
+}
+
+impl ParsedFields {
+    fn into_date_time(self) -> Result<MockDateTime, DateTimeError> {
+        let hour = match self.hour {
+            None => 0,
+            Some((fields::Hour::H23, h)) => h,
+            Some((fields::Hour::H24, h)) => h % 24,
+            Some((fields::Hour::H11, h)) | Some((fields::Hour::H12, h)) => {
+                if h > 12 {
+                    return Err(DateTimeError::Overflow {
+                        field: "Hour",
+                        max: 12,
+                    });
+                }
+                let h = h % 12;
+                match (self.is_pm, self.day_period) {
+                    (Some(is_pm), _) => h + if is_pm { 12 } else { 0 },
+                    // A flexible day period disambiguates between the morning and
+                    // the afternoon hour.
+                    (None, Some(rule)) => [h, h + 12]
+                        .iter()
+                        .copied()
+                        .find(|h| rule.contains(*h as u8))
+                        .ok_or(DateTimeError::InvalidFormat)?,
+                    (None, None) => h,
+                }
+            }
+        };
+        let month = self.month.unwrap_or(1);
+        if !(1..=12).contains(&month) {
+            return Err(DateTimeError::Overflow {
+                field: "Month",
+                max: 12,
+            });
+        }
+        let day = self.day.unwrap_or(1);
+        if !(1..=31).contains(&day) {
+            return Err(DateTimeError::Overflow {
+                field: "Day",
+                max: 31,
+            });
+        }
+        MockDateTime::try_new(
+            self.year.unwrap_or(0),
+            month - 1,
+            day - 1,
+            hour,
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+        )
+    }
+}
+
+/// Reads a decimal number of `min..=max` digits of the `numbering_system` from the front
+/// of `input`.
+fn read_number(
+    input: &mut &str,
+    min: usize,
+    max: usize,
+    numbering_system: NumberingSystem,
+) -> Result<usize, DateTimeError> {
+    let mut digits = String::new();
+    let mut len = 0;
+    for c in input.chars().take(max) {
+        match numbering_system.digit_value(c) {
+            Some(digit) => digits.push(char::from(b'0' + digit)),
+            None => break,
+        }
+        len += c.len_utf8();
+    }
+    if digits.len() < min {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    let value = digits.parse()?;
+    *input = &input[len..];
+    Ok(value)
+}
+
+/// Reads a numeric field, where a single-letter field accepts one or two digits,
+/// and a longer field requires exactly as many digits as its length.
+fn read_numeric_field(
+    input: &mut &str,
+    length: FieldLength,
+    numbering_system: NumberingSystem,
+) -> Result<usize, DateTimeError> {
+    match length {
+        FieldLength::One => read_number(input, 1, 2, numbering_system),
+        length => read_number(input, length as usize, length as usize, numbering_system),
+    }
+}
+
+/// Finds the longest symbol which `input` starts with, consumes it, and returns its index,
+/// or returns an error if several symbols are that long, such as the narrow month names
+/// `J` of January, June and July.
+fn read_symbol<S: AsRef<str>>(
+    input: &mut &str,
+    symbols: impl Iterator<Item = S>,
+) -> Result<usize, DateTimeError> {
+    let mut found: Option<(usize, usize)> = None;
+    let mut ambiguous = false;
+    for (idx, symbol) in symbols.enumerate() {
+        let symbol = symbol.as_ref();
+        if symbol.is_empty() || !input.starts_with(symbol) {
+            continue;
+        }
+        match found {
+            Some((_, len)) if len > symbol.len() => {}
+            Some((_, len)) if len == symbol.len() => ambiguous = true,
+            _ => {
+                found = Some((idx, symbol.len()));
+                ambiguous = false;
+            }
+        }
+    }
+    let (idx, len) = found.ok_or(DateTimeError::InvalidFormat)?;
+    if ambiguous {
+        return Err(DateTimeError::AmbiguousSymbol(input[..len].to_string()));
+    }
+    *input = &input[len..];
+    Ok(idx)
+}
+
+/// Consumes the longest symbol which `input` starts with, for the fields which are implied
+/// by the others, such as the weekday, whose symbols may thus be ambiguous.
+fn skip_symbol<S: AsRef<str>>(
+    input: &mut &str,
+    symbols: impl Iterator<Item = S>,
+) -> Result<(), DateTimeError> {
+    let len = symbols
+        .filter(|symbol| !symbol.as_ref().is_empty() && input.starts_with(symbol.as_ref()))
+        .map(|symbol| symbol.as_ref().len())
+        .max()
+        .ok_or(DateTimeError::InvalidFormat)?;
+    *input = &input[len..];
+    Ok(())
+}
+
+/// Reads an offset in the ISO 8601 format selected by the `length` of an `x` or `X` field,
+/// as written by the formatter, where `with_z` accepts `Z` for a zero offset.
+fn read_iso8601_offset(
+    input: &mut &str,
+    length: FieldLength,
+    with_z: bool,
+) -> Result<GmtOffset, DateTimeError> {
+    /// Reads the `separator` followed by two digits, if `input` starts with them.
+    fn read_component(input: &mut &str, separator: &str) -> Result<Option<usize>, DateTimeError> {
+        match input.strip_prefix(separator) {
+            Some(rest) if rest.starts_with(|c: char| c.is_ascii_digit()) => {
+                *input = rest;
+                read_number(input, 2, 2, numbering_systems::LATN).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    if with_z {
+        if let Some(rest) = input.strip_prefix('Z') {
+            *input = rest;
+            return Ok(GmtOffset::default());
+        }
+    }
+    let sign = match input.as_bytes().first() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(DateTimeError::InvalidFormat),
+    };
+    *input = &input[1..];
+    let separator = match length {
+        FieldLength::Abbreviated | FieldLength::Narrow => ":",
+        _ => "",
+    };
+    let hours = read_number(input, 2, 2, numbering_systems::LATN)?;
+    let minutes = match (length, read_component(input, separator)?) {
+        (_, Some(minutes)) => minutes,
+        (FieldLength::One, None) => 0,
+        _ => return Err(DateTimeError::InvalidFormat),
+    };
+    let seconds = match length {
+        FieldLength::Wide | FieldLength::Narrow => read_component(input, separator)?.unwrap_or(0),
+        _ => 0,
+    };
+    if minutes > 59 || seconds > 59 {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    GmtOffset::try_new(sign * (hours * 3600 + minutes * 60 + seconds) as i32)
+}
+
+/// Expands the last two digits of a year to the year ending with them within 50 years
+/// before and 49 years after the `pivot`, or returns an error if that year is out of the
+/// range of `i32`, which happens for pivots near its ends.
+fn expand_two_digit_year(two_digits: i32, pivot: i32) -> Result<i32, DateTimeError> {
+    let start = i64::from(pivot) - 50;
+    let year = start + (i64::from(two_digits) - start).rem_euclid(100);
+    i32::try_from(year).map_err(|_| DateTimeError::Overflow {
+        field: "Year",
+        max: i32::MAX as usize,
+    })
+}
+
+fn read_field(
+    field: &Field,
+    data: Option<(&provider::gregory::DatesV1, &LocaleData)>,
+    input: &mut &str,
+    result: &mut ParsedFields,
+) -> Result<(), DateTimeError> {
+    use crate::provider::helpers::DateTimeDates;
+
+    let numeric_length = data.map_or(field.length, |(_, locale_data)| {
+        locale_data.numeric_length(field.length)
+    });
+    let numbering_system = data.map_or(numbering_systems::LATN, |(_, locale_data)| {
+        locale_data.numbering_system
+    });
+    match field.symbol {
+        FieldSymbol::Year(..) => {
+            let year = match field.length {
+                FieldLength::TwoDigit => {
+                    let pivot = data.map_or(DEFAULT_TWO_DIGIT_YEAR_PIVOT, |(_, data)| {
+                        data.two_digit_year_pivot
+                    });
+                    expand_two_digit_year(
+                        read_number(input, 2, 2, numbering_system)? as i32,
+                        pivot,
+                    )?
+                }
+                length => {
+                    let min_year_digits = data.map_or(1, |(_, data)| data.min_year_digits);
+                    let min = (length as usize).max(min_year_digits);
+                    let year = match length {
+                        FieldLength::One => read_number(input, min, usize::MAX, numbering_system)?,
+                        _ => read_number(input, min, min, numbering_system)?,
+                    };
+                    i32::try_from(year).map_err(|_| DateTimeError::Overflow {
+                        field: "Year",
+                        max: i32::MAX as usize,
+                    })?
+                }
+            };
+            result.year = Some(match data {
+                Some((_, locale_data)) => eras::gregorian_year(year, locale_data.calendar),
+                None => year,
+            });
+        }
+        FieldSymbol::Month(month) => {
+            let value = match field.length {
+                FieldLength::One | FieldLength::TwoDigit => {
+                    read_numeric_field(input, numeric_length, numbering_system)?
+                }
+                length => {
+                    let (data, locale_data) = data.ok_or(DateTimeError::InvalidFormat)?;
+                    let length = locale_data.month_width.unwrap_or(length);
+                    let symbols = (0..12).map(|idx| {
+                        data.get_symbol_for_month(
+                            month,
+                            length,
+                            crate::date::Month::new_unchecked(idx),
+                        )
+                    });
+                    read_symbol(input, symbols)? + 1
+                }
+            };
+            result.month = Some(value);
+        }
+        FieldSymbol::Quarter(quarter) => {
+            // The quarter is implied by the month, so it is consumed but not stored.
+            match field.length {
+                FieldLength::One | FieldLength::TwoDigit => {
+                    read_numeric_field(input, numeric_length, numbering_system)?;
+                }
+                length => {
+                    let (_, locale_data) = data.ok_or(DateTimeError::InvalidFormat)?;
+                    match locale_data.quarters {
+                        Some(names) => {
+                            skip_symbol(input, names.get_symbols(quarter, length).iter())?
+                        }
+                        None => {
+                            read_numeric_field(input, FieldLength::One, numbering_system)?;
+                        }
+                    }
+                }
+            }
+        }
+        FieldSymbol::Weekday(weekday) => match (weekday, field.length) {
+            (fields::Weekday::StandAlone, FieldLength::One)
+            | (fields::Weekday::StandAlone, FieldLength::TwoDigit)
+            | (fields::Weekday::Local, FieldLength::One)
+            | (fields::Weekday::Local, FieldLength::TwoDigit) => {
+                read_numeric_field(input, numeric_length, numbering_system)?;
+            }
+            (weekday, length) => {
+                // The weekday is implied by the date, so it is consumed but not stored.
+                let (data, _) = data.ok_or(DateTimeError::InvalidFormat)?;
+                let symbols = (0..7).map(|idx| {
+                    data.get_symbol_for_weekday(
+                        weekday,
+                        length,
+                        crate::date::WeekDay::new_unchecked(idx),
+                    )
+                });
+                skip_symbol(input, symbols)?;
+            }
+        },
+        FieldSymbol::Day(fields::Day::DayOfMonth) => {
+            let day = read_numeric_field(input, numeric_length, numbering_system)?;
+            if let Some((
+                _,
+                LocaleData {
+                    day_ordinals: Some(suffixes),
+                    ..
+                },
+            )) = data
+            {
+                *input = input
+                    .strip_prefix(suffixes.get(day))
+                    .ok_or(DateTimeError::InvalidFormat)?;
+            }
+            result.day = Some(day);
+        }
+        FieldSymbol::Hour(hour) => {
+            result.hour = Some((
+                hour,
+                read_numeric_field(input, numeric_length, numbering_system)?,
+            ));
+        }
+        FieldSymbol::Minute => {
+            result.minute = Some(read_numeric_field(input, numeric_length, numbering_system)?);
+        }
+        FieldSymbol::Second(fields::Second::Second) => {
+            result.second = Some(read_numeric_field(input, numeric_length, numbering_system)?);
+        }
+        FieldSymbol::DayPeriod(day_period) => {
+            let (data, locale_data) = data.ok_or(DateTimeError::InvalidFormat)?;
+            let day_periods = locale_data.day_periods;
+            if day_period == fields::DayPeriod::Flexible && !day_periods.is_empty() {
+                let idx = read_symbol(input, day_periods.iter().map(|p| p.name))?;
+                result.day_period = Some(day_periods[idx].rule);
+                return Ok(());
+            }
+            let am = data.get_symbol_for_day_period(
+                day_period,
+                field.length,
+                crate::date::Hour::new_unchecked(1),
+                false,
+            );
+            let pm = data.get_symbol_for_day_period(
+                day_period,
+                field.length,
+                crate::date::Hour::new_unchecked(13),
+                false,
+            );
+            result.is_pm = Some(read_symbol(input, [am, pm].iter().copied())? == 1);
+        }
+        FieldSymbol::TimeZone(zone @ fields::TimeZone::Iso)
+        | FieldSymbol::TimeZone(zone @ fields::TimeZone::IsoWithZ) => {
+            result.offset = Some(read_iso8601_offset(
+                input,
+                field.length,
+                zone == fields::TimeZone::IsoWithZ,
+            )?);
+        }
+        _ => return Err(DateTimeError::InvalidFormat),
+    }
+    Ok(())
+}
+
+/// Parses `input` according to the `pattern`, using `data` to recognize localized
+/// symbols such as month names.
+///
+/// If `data` is `None`, only numeric fields can be parsed.
+pub fn parse_pattern(
+    pattern: &Pattern,
+    data: Option<(&provider::gregory::DatesV1, &LocaleData)>,
+    input: &str,
+) -> Result<MockDateTime, DateTimeError> {
+    read_fields(pattern, data, input)?.into_date_time()
+}
+
+/// Like [`parse_pattern`], but also returns the offset of the ISO 8601 offset fields, `x`
+/// and `X`, which is zero if the `pattern` has none.
+pub fn parse_pattern_zoned(
+    pattern: &Pattern,
+    data: Option<(&provider::gregory::DatesV1, &LocaleData)>,
+    input: &str,
+) -> Result<MockZonedDateTime, DateTimeError> {
+    let result = read_fields(pattern, data, input)?;
+    let offset = result.offset.unwrap_or_default();
+    Ok(MockZonedDateTime::new(result.into_date_time()?, offset))
+}
+
+fn read_fields(
+    pattern: &Pattern,
+    data: Option<(&provider::gregory::DatesV1, &LocaleData)>,
+    mut input: &str,
+) -> Result<ParsedFields, DateTimeError> {
+    let mut result = ParsedFields::default();
+    for item in pattern.items() {
+        match item {
+            PatternItem::Field(field) => read_field(field, data, &mut input, &mut result)?,
+            PatternItem::Literal(literal) => {
+                if !input.starts_with(literal.as_str()) {
+                    return Err(DateTimeError::InvalidFormat);
+                }
+                input = &input[literal.len()..];
+            }
+        }
+    }
+    if !input.is_empty() {
+        return Err(DateTimeError::InvalidFormat);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pattern_numeric() {
+        let samples = &[
+            ("y-MM-dd", "2020-10-14", (2020, 9, 13, 0, 0, 0)),
+            ("MM/dd/y", "10/14/2020", (2020, 9, 13, 0, 0, 0)),
+            ("d.M.yy", "4.7.21", (2021, 6, 3, 0, 0, 0)),
+            ("yyyyMMdd", "20200101", (2020, 0, 0, 0, 0, 0)),
+            ("HH:mm:ss", "13:21:05", (0, 0, 0, 13, 21, 5)),
+            ("h:mm", "12:05", (0, 0, 0, 0, 5, 0)),
+            ("k:mm", "24:05", (0, 0, 0, 0, 5, 0)),
+            ("y-MM (QQ)", "2021-05 (02)", (2021, 4, 0, 0, 0, 0)),
+        ];
+        for (pattern, input, (year, month, day, hour, minute, second)) in samples {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            let expected =
+                MockDateTime::try_new(*year, *month, *day, *hour, *minute, *second).unwrap();
+            assert_eq!(parse_pattern(&pattern, None, input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_pattern_invalid() {
+        let samples = &[
+            ("y-MM-dd", "2020/10/14"),
+            ("y-MM-dd", "2020-10-14T00"),
+            ("y-MM-dd", "2020-1-14"),
+            ("y-MM-dd", "2020-00-14"),
+            ("y-MM-dd", "2020-13-14"),
+            ("MMM d, y", "Oct 14, 2020"),
+        ];
+        for (pattern, input) in samples {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            assert!(parse_pattern(&pattern, None, input).is_err());
+        }
+    }
+
+    #[test]
+    fn test_read_iso8601_offset() {
+        let samples = &[
+            ("+05", FieldLength::One, false, 18000),
+            ("+0530", FieldLength::One, false, 19800),
+            ("-0800", FieldLength::TwoDigit, false, -28800),
+            ("+05:30", FieldLength::Abbreviated, false, 19800),
+            ("+001932", FieldLength::Wide, false, 1172),
+            ("+0530", FieldLength::Wide, false, 19800),
+            ("+00:19:32", FieldLength::Narrow, true, 1172),
+            ("Z", FieldLength::Narrow, true, 0),
+            ("+00", FieldLength::One, false, 0),
+        ];
+        for (input, length, with_z, expected) in samples {
+            let mut rest = *input;
+            let offset = read_iso8601_offset(&mut rest, *length, *with_z).unwrap();
+            assert_eq!(offset.seconds(), *expected, "{}", input);
+            assert_eq!(rest, "", "{}", input);
+        }
+
+        let samples = &[
+            ("Z", FieldLength::One, false),
+            ("05:30", FieldLength::Abbreviated, false),
+            ("+05", FieldLength::TwoDigit, false),
+            ("+0530", FieldLength::Abbreviated, false),
+            ("+05:60", FieldLength::Abbreviated, false),
+            ("+19:00", FieldLength::Abbreviated, false),
+        ];
+        for (input, length, with_z) in samples {
+            let mut rest = *input;
+            assert!(
+                read_iso8601_offset(&mut rest, *length, *with_z).is_err(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_expand_two_digit_year() {
+        let samples = &[
+            (20, 2050, 2020),
+            (0, 2050, 2000),
+            (99, 2050, 2099),
+            (20, 2000, 2020),
+            (49, 2000, 2049),
+            (50, 2000, 1950),
+            (20, 1930, 1920),
+            (80, 1930, 1880),
+            (79, 1930, 1979),
+            (5, -50, -95),
+        ];
+        for (two_digits, pivot, expected) in samples {
+            assert_eq!(
+                expand_two_digit_year(*two_digits, *pivot).unwrap(),
+                *expected,
+                "{} {}",
+                two_digits,
+                pivot
+            );
+        }
+
+        // The pivots near the ends of `i32` don't overflow.
+        assert_eq!(expand_two_digit_year(47, i32::MAX).unwrap(), 2_147_483_647);
+        assert!(expand_two_digit_year(48, i32::MAX).is_err());
+        assert_eq!(expand_two_digit_year(98, i32::MIN).unwrap(), -2_147_483_602);
+        assert!(expand_two_digit_year(2, i32::MIN).is_err());
+    }
+}
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 use crate::fields;
+use std::fmt;
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
@@ -9,6 +10,24 @@ pub enum Error {
     UnknownSubstitution(char),
     UnclosedLiteral,
     UnclosedPlaceholder,
+    /// An ASCII letter which is not a field symbol is not quoted, in strict mode.
+    UnquotedLetter(char),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FieldTooLong(symbol) => write!(f, "field {:?} is too long", symbol),
+            Self::UnknownSubstitution(ch) => write!(f, "unknown substitution `{{{}}}`", ch),
+            Self::UnclosedLiteral => write!(f, "unclosed literal"),
+            Self::UnclosedPlaceholder => write!(f, "unclosed placeholder"),
+            Self::UnquotedLetter(ch) => write!(
+                f,
+                "`{}` is not a field symbol, quote it as `'{}'` to use it as a literal",
+                ch, ch
+            ),
+        }
+    }
 }
 
 impl From<fields::Error> for Error {
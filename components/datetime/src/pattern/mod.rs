@@ -4,9 +4,12 @@
 mod error;
 mod parser;
 
+use crate::date::DateTimeError;
 use crate::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::options::preferences::CalendarFields;
 pub use error::Error;
 use parser::Parser;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::iter::FromIterator;
 
@@ -86,6 +89,12 @@ impl Pattern {
         Parser::new(input).parse().map(Pattern::from)
     }
 
+    /// Like [`Pattern::from_bytes`], but rejects the ASCII letters which are neither
+    /// field symbols nor quoted.
+    pub fn from_bytes_strict(input: &str) -> Result<Self, Error> {
+        Parser::new(input).strict().parse().map(Pattern::from)
+    }
+
     // TODO(#277): This should be turned into a utility for all ICU4X.
     pub fn from_bytes_combination(input: &str, date: Self, time: Self) -> Result<Self, Error> {
         Parser::new(input)
@@ -93,6 +102,45 @@ impl Pattern {
             .map(Pattern::from)
     }
 
+    /// Replaces each literal of the pattern which is a key of `overrides` by its value.
+    /// The literals are matched as a whole, such as `", "` between two fields.
+    pub(crate) fn replace_literals(&mut self, overrides: &HashMap<String, String>) {
+        for item in self.items.iter_mut() {
+            if let PatternItem::Literal(literal) = item {
+                if let Some(replacement) = overrides.get(literal) {
+                    *literal = replacement.clone();
+                }
+            }
+        }
+    }
+
+    /// Checks that the `calendar` defines each field of the pattern, and returns
+    /// [`DateTimeError::UnsupportedField`] for the first one it doesn't.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::options::preferences::Calendar;
+    /// use icu_datetime::pattern::Pattern;
+    ///
+    /// let pattern = Pattern::from_bytes("QQQ y")
+    ///     .expect("Failed to parse a pattern.");
+    /// assert!(pattern.validate_for_calendar(Calendar::Buddhist).is_ok());
+    /// ```
+    pub fn validate_for_calendar(
+        &self,
+        calendar: impl CalendarFields,
+    ) -> Result<(), DateTimeError> {
+        for item in &self.items {
+            if let PatternItem::Field(field) = item {
+                if !calendar.has_field(field.symbol) {
+                    return Err(DateTimeError::UnsupportedField(field.symbol));
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(super) fn most_granular_time(&self) -> Option<TimeGranularity> {
         self.time_granularity
     }
@@ -15,6 +15,7 @@ enum Segment {
 pub struct Parser<'p> {
     source: &'p str,
     state: Segment,
+    strict: bool,
 }
 
 impl<'p> Parser<'p> {
@@ -25,9 +26,19 @@ impl<'p> Parser<'p> {
                 literal: String::new(),
                 quoted: false,
             },
+            strict: false,
         }
     }
 
+    /// Makes the parser reject the ASCII letters which are not field symbols, such as the `T`
+    /// of `y-MM-dd'T'HH:mm`, unless they are quoted.
+    ///
+    /// By default, they are treated as literals.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
     fn handle_quoted_literal(
         &mut self,
         ch: char,
@@ -100,7 +111,7 @@ impl<'p> Parser<'p> {
 
         while let Some(ch) = chars.next() {
             if !self.handle_quoted_literal(ch, &mut chars, &mut result)? {
-                if let Ok(new_symbol) = FieldSymbol::try_from(ch as u8) {
+                if let Ok(new_symbol) = FieldSymbol::try_from(ch) {
                     match self.state {
                         Segment::Symbol {
                             ref symbol,
@@ -116,6 +127,8 @@ impl<'p> Parser<'p> {
                             };
                         }
                     }
+                } else if self.strict && ch.is_ascii_alphabetic() {
+                    return Err(Error::UnquotedLetter(ch));
                 } else {
                     match self.state {
                         Segment::Symbol { symbol, length } => {
@@ -486,4 +499,35 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn pattern_parse_unquoted_letters() {
+        let expected: Vec<PatternItem> = vec![
+            (fields::Year::Calendar.into(), FieldLength::One).into(),
+            "-".into(),
+            (fields::Month::Format.into(), FieldLength::TwoDigit).into(),
+            "T".into(),
+            (fields::Hour::H23.into(), FieldLength::TwoDigit).into(),
+        ];
+
+        assert_eq!(Parser::new("y-MMTHH").parse(), Ok(expected.clone()));
+        assert_eq!(Parser::new("y-MM'T'HH").parse(), Ok(expected.clone()));
+        assert_eq!(Parser::new("y-MM'T'HH").strict().parse(), Ok(expected));
+
+        let error = Parser::new("y-MMTHH").strict().parse().unwrap_err();
+        assert_eq!(error, Error::UnquotedLetter('T'));
+        assert_eq!(
+            error.to_string(),
+            "`T` is not a field symbol, quote it as `'T'` to use it as a literal"
+        );
+
+        // Non-letters, and letters which are not ASCII, remain literals.
+        assert_eq!(
+            Parser::new("y, ż").strict().parse(),
+            Ok(vec![
+                (fields::Year::Calendar.into(), FieldLength::One).into(),
+                ", ż".into(),
+            ])
+        );
+    }
 }
@@ -41,6 +41,7 @@ pub trait DateTimeDates {
         hour: date::Hour,
         is_top_of_hour: bool,
     ) -> &Cow<str>;
+    fn get_relative_day(&self, days: i64) -> Option<&Cow<str>>;
 }
 
 impl DateTimeDates for provider::gregory::DatesV1 {
@@ -111,7 +112,8 @@ impl DateTimeDates for provider::gregory::DatesV1 {
         day: date::WeekDay,
     ) -> &Cow<str> {
         let widths = match weekday {
-            fields::Weekday::Format => &self.symbols.weekdays.format,
+            // The names of the local day of the week are the format ones.
+            fields::Weekday::Format | fields::Weekday::Local => &self.symbols.weekdays.format,
             fields::Weekday::StandAlone => {
                 if let Some(ref widths) = self.symbols.weekdays.stand_alone {
                     let symbols = match length {
@@ -132,7 +134,6 @@ impl DateTimeDates for provider::gregory::DatesV1 {
                     return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                 }
             }
-            fields::Weekday::Local => unimplemented!(),
         };
         let symbols = match length {
             fields::FieldLength::Wide => &widths.wide,
@@ -197,4 +198,16 @@ impl DateTimeDates for provider::gregory::DatesV1 {
             _ => &symbols.pm,
         }
     }
+
+    fn get_relative_day(&self, days: i64) -> Option<&Cow<str>> {
+        let names = self.symbols.relative_days.as_ref()?;
+        match days {
+            -2 => names.before_yesterday.as_ref(),
+            -1 => Some(&names.yesterday),
+            0 => Some(&names.today),
+            1 => Some(&names.tomorrow),
+            2 => names.after_tomorrow.as_ref(),
+            _ => None,
+        }
+    }
 }
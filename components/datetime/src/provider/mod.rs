@@ -38,6 +38,18 @@ pub mod gregory {
         pub weekdays: weekdays::ContextsV1,
 
         pub day_periods: day_periods::ContextsV1,
+
+        #[cfg_attr(
+            all(feature = "provider_serde", not(feature = "serialize_none")),
+            serde(skip_serializing_if = "Option::is_none")
+        )]
+        pub eras: Option<eras::ErasV1>,
+
+        #[cfg_attr(
+            all(feature = "provider_serde", not(feature = "serialize_none")),
+            serde(skip_serializing_if = "Option::is_none")
+        )]
+        pub relative_days: Option<relative_days::RelativeDaysV1>,
     }
 
     #[derive(Debug, PartialEq, Clone, Default)]
@@ -180,6 +192,60 @@ pub mod gregory {
         }
     );
 
+    pub mod eras {
+        use super::*;
+
+        /// The names of the eras of the Gregorian calendar, for each width of the `G` field.
+        #[derive(Debug, PartialEq, Clone, Default)]
+        #[cfg_attr(
+            feature = "provider_serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub struct ErasV1 {
+            pub abbreviated: EraNamesV1,
+            pub narrow: EraNamesV1,
+            pub wide: EraNamesV1,
+        }
+
+        /// The names of the eras before and after the year `1`.
+        #[derive(Debug, PartialEq, Clone, Default)]
+        #[cfg_attr(
+            feature = "provider_serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub struct EraNamesV1 {
+            pub bc: Cow<'static, str>,
+            pub ad: Cow<'static, str>,
+        }
+    }
+
+    pub mod relative_days {
+        use super::*;
+
+        /// The names of the days relative to today, such as "yesterday". Only some languages
+        /// name the days two days before and after today.
+        #[derive(Debug, PartialEq, Clone, Default)]
+        #[cfg_attr(
+            feature = "provider_serde",
+            derive(serde::Serialize, serde::Deserialize)
+        )]
+        pub struct RelativeDaysV1 {
+            #[cfg_attr(
+                all(feature = "provider_serde", not(feature = "serialize_none")),
+                serde(skip_serializing_if = "Option::is_none")
+            )]
+            pub before_yesterday: Option<Cow<'static, str>>,
+            pub yesterday: Cow<'static, str>,
+            pub today: Cow<'static, str>,
+            pub tomorrow: Cow<'static, str>,
+            #[cfg_attr(
+                all(feature = "provider_serde", not(feature = "serialize_none")),
+                serde(skip_serializing_if = "Option::is_none")
+            )]
+            pub after_tomorrow: Option<Cow<'static, str>>,
+        }
+    }
+
     pub mod patterns {
         use super::*;
         #[derive(Debug, PartialEq, Clone, Default)]
@@ -0,0 +1,103 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::date::Month;
+use crate::fields::{self, FieldLength};
+use icu_locid::LanguageIdentifier;
+
+/// The names of the quarters of a language, in the format and stand-alone contexts.
+#[derive(Debug, PartialEq)]
+pub struct QuarterNames {
+    format_abbreviated: [&'static str; 4],
+    format_wide: [&'static str; 4],
+    format_narrow: [&'static str; 4],
+    stand_alone_abbreviated: [&'static str; 4],
+    stand_alone_wide: [&'static str; 4],
+    stand_alone_narrow: [&'static str; 4],
+}
+
+// The CLDR quarter names are not part of the `gregory@1` data yet, so only the languages
+// copied here have them. The other languages render the text quarter fields as numbers.
+const EN: QuarterNames = QuarterNames {
+    format_abbreviated: ["Q1", "Q2", "Q3", "Q4"],
+    format_wide: ["1st quarter", "2nd quarter", "3rd quarter", "4th quarter"],
+    format_narrow: ["1", "2", "3", "4"],
+    stand_alone_abbreviated: ["Q1", "Q2", "Q3", "Q4"],
+    stand_alone_wide: ["1st quarter", "2nd quarter", "3rd quarter", "4th quarter"],
+    stand_alone_narrow: ["1", "2", "3", "4"],
+};
+
+/// Returns the quarter names for the language of the given `LanguageIdentifier`, or `None`
+/// if they aren't available, in which case the quarters are rendered as numbers.
+pub fn get_quarter_names(langid: &LanguageIdentifier) -> Option<&'static QuarterNames> {
+    match langid.language.as_str() {
+        "en" => Some(&EN),
+        _ => None,
+    }
+}
+
+/// Returns the zero-based quarter of the year of the zero-based `month`.
+pub fn quarter_of(month: Month) -> usize {
+    usize::from(month) / 3
+}
+
+impl QuarterNames {
+    /// Returns the names of the four quarters for the `quarter` field and the `length`.
+    pub fn get_symbols(&self, quarter: fields::Quarter, length: FieldLength) -> &[&'static str; 4] {
+        match (quarter, length) {
+            (fields::Quarter::Format, FieldLength::Wide) => &self.format_wide,
+            (fields::Quarter::Format, FieldLength::Narrow) => &self.format_narrow,
+            (fields::Quarter::Format, _) => &self.format_abbreviated,
+            (fields::Quarter::StandAlone, FieldLength::Wide) => &self.stand_alone_wide,
+            (fields::Quarter::StandAlone, FieldLength::Narrow) => &self.stand_alone_narrow,
+            (fields::Quarter::StandAlone, _) => &self.stand_alone_abbreviated,
+        }
+    }
+
+    /// Returns the name of the quarter containing the zero-based `month`, in the format or
+    /// the stand-alone context of the `quarter` field.
+    pub fn get_symbol(
+        &self,
+        quarter: fields::Quarter,
+        length: FieldLength,
+        month: Month,
+    ) -> &'static str {
+        self.get_symbols(quarter, length)[quarter_of(month)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_symbol() {
+        let samples = &[
+            (0, FieldLength::Wide, "1st quarter"),
+            (2, FieldLength::Wide, "1st quarter"),
+            (3, FieldLength::Abbreviated, "Q2"),
+            (8, FieldLength::Narrow, "3"),
+            (11, FieldLength::Wide, "4th quarter"),
+        ];
+        for (month, length, expected) in samples {
+            let month = Month::new_unchecked(*month);
+            for quarter in &[fields::Quarter::Format, fields::Quarter::StandAlone] {
+                assert_eq!(
+                    EN.get_symbol(*quarter, *length, month),
+                    *expected,
+                    "{:?} {:?}",
+                    quarter,
+                    month
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_get_quarter_names() {
+        let en: LanguageIdentifier = "en-GB".parse().unwrap();
+        assert_eq!(get_quarter_names(&en), Some(&EN));
+        let fr: LanguageIdentifier = "fr".parse().unwrap();
+        assert_eq!(get_quarter_names(&fr), None);
+    }
+}
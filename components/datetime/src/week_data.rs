@@ -0,0 +1,122 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::date::WeekDay;
+use icu_locid::LanguageIdentifier;
+
+// The regions of the CLDR `weekData` supplemental data whose first day of the week or
+// weekend differs from the one of the `001` region.
+//
+// Regions not listed here use Monday, the default for the `001` region.
+const SUNDAY_FIRST: &[&str] = &[
+    "AG", "AS", "AU", "BD", "BR", "BS", "BT", "BW", "BZ", "CA", "CN", "CO", "DM", "DO", "ET", "GT",
+    "GU", "HK", "HN", "ID", "IL", "IN", "JM", "JP", "KE", "KH", "KR", "LA", "MH", "MM", "MO", "MT",
+    "MX", "MZ", "NI", "NP", "PA", "PE", "PH", "PK", "PR", "PT", "PY", "SA", "SG", "SV", "TH", "TT",
+    "TW", "UM", "US", "VE", "VI", "WS", "YE", "ZA", "ZW",
+];
+
+const SATURDAY_FIRST: &[&str] = &[
+    "AE", "AF", "BH", "DJ", "DZ", "EG", "IQ", "IR", "JO", "KW", "LY", "OM", "QA", "SD", "SY",
+];
+
+const FRIDAY_FIRST: &[&str] = &["MV"];
+
+// Regions not listed here have a Saturday and Sunday weekend, the default for the `001` region.
+const FRIDAY_SATURDAY_WEEKEND: &[&str] = &[
+    "AE", "BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE",
+];
+
+const THURSDAY_FRIDAY_WEEKEND: &[&str] = &["AF"];
+
+const FRIDAY_WEEKEND: &[&str] = &["IR"];
+
+const SUNDAY_WEEKEND: &[&str] = &["IN", "UG"];
+
+/// Returns the first day of the week for the region of the given `LanguageIdentifier`.
+///
+/// If the `LanguageIdentifier` has no region, the `001` (world) default is used.
+pub fn first_weekday(langid: &LanguageIdentifier) -> WeekDay {
+    let region = match langid.region {
+        Some(ref region) => region.as_str(),
+        None => return WeekDay::new_unchecked(1),
+    };
+    let day = if SUNDAY_FIRST.contains(&region) {
+        0
+    } else if SATURDAY_FIRST.contains(&region) {
+        6
+    } else if FRIDAY_FIRST.contains(&region) {
+        5
+    } else {
+        1
+    };
+    WeekDay::new_unchecked(day)
+}
+
+/// Returns the first and the last days of the weekend for the region of the given
+/// `LanguageIdentifier`, which are the same day for a single-day weekend.
+///
+/// If the `LanguageIdentifier` has no region, the `001` (world) default is used.
+pub fn weekend(langid: &LanguageIdentifier) -> (WeekDay, WeekDay) {
+    let region = langid.region.as_ref().map(|region| region.as_str());
+    let (start, end) = match region {
+        Some(region) if FRIDAY_SATURDAY_WEEKEND.contains(&region) => (5, 6),
+        Some(region) if THURSDAY_FRIDAY_WEEKEND.contains(&region) => (4, 5),
+        Some(region) if FRIDAY_WEEKEND.contains(&region) => (5, 5),
+        Some(region) if SUNDAY_WEEKEND.contains(&region) => (0, 0),
+        _ => (6, 0),
+    };
+    (WeekDay::new_unchecked(start), WeekDay::new_unchecked(end))
+}
+
+/// Returns `true` if the `day` is part of the weekend in the region of the given
+/// `LanguageIdentifier`.
+pub fn is_weekend(langid: &LanguageIdentifier, day: WeekDay) -> bool {
+    let (start, end) = weekend(langid);
+    let (start, end, day) = (u8::from(start), u8::from(end), u8::from(day));
+    // The weekend may wrap around the end of the week, from Saturday to Sunday.
+    (day + 7 - start) % 7 <= (end + 7 - start) % 7
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_weekday() {
+        let samples = &[
+            ("en", 1),
+            ("en-US", 0),
+            ("en-GB", 1),
+            ("ar-EG", 6),
+            ("dv-MV", 5),
+        ];
+        for (langid, expected) in samples {
+            let langid: LanguageIdentifier = langid.parse().unwrap();
+            assert_eq!(first_weekday(&langid), WeekDay::new_unchecked(*expected));
+        }
+    }
+
+    #[test]
+    fn test_is_weekend() {
+        let samples: &[(&str, &[u8])] = &[
+            ("en", &[6, 0]),
+            ("en-US", &[6, 0]),
+            ("ar-EG", &[5, 6]),
+            ("fa-AF", &[4, 5]),
+            ("fa-IR", &[5]),
+            ("hi-IN", &[0]),
+        ];
+        for (langid, weekend) in samples {
+            let langid: LanguageIdentifier = langid.parse().unwrap();
+            for day in 0..7 {
+                assert_eq!(
+                    is_weekend(&langid, WeekDay::new_unchecked(day)),
+                    weekend.contains(&day),
+                    "{} {}",
+                    langid,
+                    day
+                );
+            }
+        }
+    }
+}
@@ -4,25 +4,32 @@
 mod fixtures;
 mod patterns;
 
-use icu_datetime::{date::MockDateTime, DateTimeFormatOptions};
+use icu_datetime::{
+    date::{DateTimeError, MockDateTime, MockZonedDateTime, PartialDateTime},
+    options::{preferences, style},
+    DateTimeFormatError, DateTimeFormatOptions,
+};
 use icu_datetime::{
     provider::{gregory::DatesV1, key::GREGORY_V1},
-    DateTimeFormat,
+    DateTimeFormat, LazyDateTimeFormat,
 };
-use icu_locid::LanguageIdentifier;
+use icu_locid::{LanguageIdentifier, Locale};
 use icu_provider::{
     struct_provider::StructProvider, DataProvider, DataRequest, ResourceOptions, ResourcePath,
 };
-use std::{borrow::Cow, fmt::Write};
+use std::{
+    borrow::Cow,
+    fmt::{self, Write},
+};
 
 #[test]
 fn test_fixtures() {
     let provider = icu_testdata::get_provider();
 
     for fx in fixtures::get_fixture("styles").unwrap().0 {
-        let langid = fx.input.locale.parse().unwrap();
+        let locale: Locale = fx.input.locale.parse().unwrap();
         let options = fixtures::get_options(&fx.input.options);
-        let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+        let dtf = DateTimeFormat::try_new_with_locale(locale, &provider, &options).unwrap();
 
         let value: MockDateTime = fx.input.value.parse().unwrap();
 
@@ -43,25 +50,758 @@ fn test_fixtures() {
 }
 
 #[test]
-fn test_dayperiod_patterns() {
-    use patterns::structs::Expectation;
+fn test_format_partial() {
     let provider = icu_testdata::get_provider();
-    let format_options = DateTimeFormatOptions::default();
-    for test in patterns::get_tests("dayperiods").unwrap().0 {
-        let langid: LanguageIdentifier = test.locale.parse().unwrap();
-        let mut data: Cow<DatesV1> = provider
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Full),
+        time: Some(style::Time::Medium),
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+
+    let samples = &[
+        (
+            "2020-09-12T12:35:07",
+            "Saturday, September 12, 2020 at 12:35:07 PM",
+        ),
+        ("2020-09-12", "Saturday, September 12, 2020"),
+        ("12:35:07", "12:35:07 PM"),
+        ("12:35", "12:35 PM"),
+    ];
+    for (input, expected) in samples {
+        let value: PartialDateTime = input.parse().unwrap();
+        assert_eq!(dtf.format_partial_to_string(&value), *expected);
+    }
+}
+
+#[test]
+fn test_format_with_reference() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+
+    let samples = &[
+        (
+            "2020-09-12T12:35:00",
+            "2020-10-14T13:21:00",
+            "Sep 12, 12:35 PM",
+        ),
+        (
+            "2019-09-12T12:35:00",
+            "2020-10-14T13:21:00",
+            "Sep 12, 2019, 12:35 PM",
+        ),
+        (
+            "2020-12-31T23:59:00",
+            "2021-01-01T00:01:00",
+            "Dec 31, 2020, 11:59 PM",
+        ),
+        (
+            "2021-01-01T00:01:00",
+            "2021-01-01T00:01:00",
+            "Jan 1, 12:01 AM",
+        ),
+    ];
+    for (value, reference, expected) in samples {
+        let value: MockDateTime = value.parse().unwrap();
+        let reference: MockDateTime = reference.parse().unwrap();
+        assert_eq!(
+            dtf.format_to_string_with_reference(&value, &reference)
+                .unwrap(),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_format_with_reference_full() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let reference: MockDateTime = "2020-10-14T13:21:00".parse().unwrap();
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+
+    let samples = &[
+        (style::Date::Full, "Saturday, September 12"),
+        (style::Date::Long, "September 12"),
+    ];
+    for (date, expected) in samples {
+        let options = style::Bag {
+            date: Some(*date),
+            time: None,
+            ..Default::default()
+        };
+        let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+        assert_eq!(
+            dtf.format_to_string_with_reference(&value, &reference)
+                .unwrap(),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_pattern() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+
+    let dtf =
+        DateTimeFormat::try_new_with_pattern(langid.clone(), &provider, "MMMM d, yTHH:mm").unwrap();
+    assert_eq!(dtf.format_to_string(&value), "September 12, 2020T12:35");
+
+    let result =
+        DateTimeFormat::try_new_with_pattern_strict(langid.clone(), &provider, "MMMM d, yTHH:mm");
+    match result {
+        Err(err @ DateTimeFormatError::Pattern(_)) => assert!(err.to_string().contains("'T'")),
+        _ => panic!("The unquoted `T` should be rejected."),
+    }
+
+    let dtf = DateTimeFormat::try_new_with_pattern_strict(langid, &provider, "MMMM d, y'T'HH:mm")
+        .unwrap();
+    assert_eq!(dtf.format_to_string(&value), "September 12, 2020T12:35");
+}
+
+#[test]
+fn test_format_offset_without_offset() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+    let reference: MockDateTime = "2020-10-14T13:21:00".parse().unwrap();
+
+    for pattern in &["HH:mm O", "HH:mm xxx", "HH:mm X"] {
+        let dtf = DateTimeFormat::try_new_with_pattern(langid.clone(), &provider, pattern).unwrap();
+        assert!(
+            matches!(
+                dtf.try_format_to_string(&value),
+                Err(DateTimeFormatError::MissingInputField("GmtOffset"))
+            ),
+            "{}",
+            pattern
+        );
+        // The infallible methods leave the time zone field out.
+        assert_eq!(dtf.format_to_string(&value), "12:35", "{}", pattern);
+        assert_eq!(dtf.format(&value).to_string(), "12:35", "{}", pattern);
+        let mut s = String::new();
+        dtf.format_to_write(&mut s, &value).unwrap();
+        assert_eq!(s, "12:35", "{}", pattern);
+        assert!(!dtf.round_trips(&value));
+        assert!(dtf
+            .format_all_into(&[value.clone()], &mut Vec::new())
+            .is_err());
+        assert!(dtf
+            .format_all_joined_into(&[value.clone()], ", ", &mut String::new())
+            .is_err());
+        assert!(dtf
+            .format_to_string_with_reference(&value, &reference)
+            .is_err());
+
+        // The partial values are formatted without the offset.
+        let partial = PartialDateTime::from(&value);
+        assert_eq!(dtf.format_partial_to_string(&partial), "12:35");
+
+        let zoned: MockZonedDateTime = "2020-09-12T12:35:00+05:30".parse().unwrap();
+        assert!(dtf.try_format_to_string(&zoned).is_ok());
+    }
+}
+
+#[test]
+fn test_format_ordinal_day() {
+    let provider = icu_testdata::get_provider();
+    let options = style::Bag {
+        date: Some(style::Date::Long),
+        time: None,
+        preferences: Some(preferences::Bag {
+            ordinal_day: true,
+            ..Default::default()
+        }),
+    };
+    let options = options.into();
+
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+    let samples = &[
+        ("2020-10-01T00:00:00", "October 1st, 2020"),
+        ("2020-10-02T00:00:00", "October 2nd, 2020"),
+        ("2020-10-03T00:00:00", "October 3rd, 2020"),
+        ("2020-10-11T00:00:00", "October 11th, 2020"),
+        ("2020-10-21T00:00:00", "October 21st, 2020"),
+        ("2020-10-22T00:00:00", "October 22nd, 2020"),
+    ];
+    for (value, expected) in samples {
+        let value: MockDateTime = value.parse().unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+        assert_eq!(dtf.parse(expected).unwrap(), value);
+    }
+    assert!(dtf.parse("October 1th, 2020").is_err());
+
+    // Languages without ordinal days keep the numeric day.
+    let langid: LanguageIdentifier = "fr".parse().unwrap();
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+    let value: MockDateTime = "2020-10-01T00:00:00".parse().unwrap();
+    assert_eq!(dtf.format_to_string(&value), "1 octobre 2020");
+}
+
+#[test]
+fn test_format_buddhist_calendar() {
+    let provider = icu_testdata::get_provider();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: Some(preferences::Bag {
+            calendar: Some(preferences::Calendar::Buddhist),
+            ..Default::default()
+        }),
+    };
+    let options = options.into();
+
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+    let value: MockDateTime = "2020-10-14T00:00:00".parse().unwrap();
+    assert_eq!(dtf.format_to_string(&value), "Oct 14, 2563");
+    assert_eq!(dtf.parse("Oct 14, 2563").unwrap(), value);
+
+    // The years before the Buddhist era are signed.
+    let value: MockDateTime = "-0600-03-15T00:00:00".parse().unwrap();
+    assert_eq!(dtf.format_to_string(&value), "Mar 15, -57");
+}
+
+#[test]
+fn test_calendar_keyword() {
+    let provider = icu_testdata::get_provider();
+    let value: MockDateTime = "2020-10-14T00:00:00".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: None,
+    };
+    let options = options.into();
+
+    for (locale, expected) in &[
+        ("en-u-ca-buddhist", "Oct 14, 2563"),
+        ("en-u-ca-gregory", "Oct 14, 2020"),
+    ] {
+        let locale: Locale = locale.parse().unwrap();
+        let dtf = DateTimeFormat::try_new_with_locale(locale, &provider, &options).unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+    }
+
+    let locale: Locale = "en-u-ca-persian".parse().unwrap();
+    match DateTimeFormat::try_new_with_locale(locale, &provider, &options) {
+        Err(err @ DateTimeFormatError::UnsupportedCalendar(_)) => assert_eq!(
+            err.to_string(),
+            "unsupported calendar `persian`, expected one of `gregory`, `buddhist`"
+        ),
+        Err(err) => panic!("unexpected error {:?}", err),
+        Ok(_) => panic!("the Persian calendar is not supported"),
+    }
+
+    // The preferences take precedence over the locale.
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: Some(preferences::Bag {
+            calendar: Some(preferences::Calendar::Gregorian),
+            ..Default::default()
+        }),
+    };
+    let locale: Locale = "en-u-ca-buddhist".parse().unwrap();
+    let dtf = DateTimeFormat::try_new_with_locale(locale, &provider, &options.into()).unwrap();
+    assert_eq!(dtf.format_to_string(&value), "Oct 14, 2020");
+}
+
+#[test]
+fn test_numbering_system() {
+    let provider = icu_testdata::get_provider();
+    let value: MockDateTime = "2020-10-14T13:21:00".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: None,
+    };
+    let options = options.into();
+
+    for (locale, expected) in &[
+        ("bn", "১৪ অক্টোবর, ২০২০"),
+        ("bn-u-nu-latn", "14 অক্টোবর, 2020"),
+        ("en-u-nu-beng", "Oct ১৪, ২০২০"),
+    ] {
+        let locale: Locale = locale.parse().unwrap();
+        let dtf = DateTimeFormat::try_new_with_locale(locale, &provider, &options).unwrap();
+        let formatted = dtf.format_to_string(&value);
+        assert_eq!(formatted, *expected);
+        assert_eq!(
+            dtf.parse(&formatted).unwrap(),
+            "2020-10-14T00:00:00".parse().unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_validate_for_calendar() {
+    use icu_datetime::date::DateTimeError;
+    use icu_datetime::fields::FieldSymbol;
+    use icu_datetime::options::preferences::CalendarFields;
+    use icu_datetime::pattern::Pattern;
+
+    // A calendar which doesn't define the quarters.
+    struct NoQuarters;
+    impl CalendarFields for NoQuarters {
+        fn has_field(&self, symbol: FieldSymbol) -> bool {
+            !matches!(symbol, FieldSymbol::Quarter(..))
+        }
+    }
+
+    let pattern = Pattern::from_bytes("QQQ y").unwrap();
+    assert!(matches!(
+        pattern.validate_for_calendar(NoQuarters),
+        Err(DateTimeError::UnsupportedField(FieldSymbol::Quarter(..)))
+    ));
+    let pattern = Pattern::from_bytes("MMM d, y").unwrap();
+    assert!(pattern.validate_for_calendar(NoQuarters).is_ok());
+
+    // The supported calendars define all of the fields, including the quarters.
+    let pattern =
+        Pattern::from_bytes("G y Y QQQ qqqq MMM LLL d D F g EEE c e a b B h H m s xxx O").unwrap();
+    for calendar in &[
+        preferences::Calendar::Gregorian,
+        preferences::Calendar::Buddhist,
+    ] {
+        assert!(pattern.validate_for_calendar(*calendar).is_ok());
+    }
+}
+
+#[test]
+fn test_region_default_calendar() {
+    let provider = icu_testdata::get_provider();
+    let value: MockDateTime = "2020-10-14T00:00:00".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: None,
+    }
+    .into();
+
+    // Thailand uses the Buddhist calendar without a `ca` keyword, which may still select
+    // another one, and so does Thai without a region, whose likely region is Thailand.
+    let data = load_dates(&provider, &"th-TH".parse().unwrap());
+    let provider = StructProvider {
+        key: GREGORY_V1,
+        data: data.as_ref(),
+    };
+    for (locale, expected) in &[
+        ("th-TH", "14 ต.ค. 2563"),
+        ("th-TH-u-ca-gregory", "14 ต.ค. 2020"),
+        ("th", "14 ต.ค. 2563"),
+        ("th-US", "14 ต.ค. 2020"),
+    ] {
+        let locale: Locale = locale.parse().unwrap();
+        let dtf = DateTimeFormat::try_new_with_locale(locale, &provider, &options).unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+    }
+}
+
+#[test]
+fn test_format_relative_day() {
+    let provider = icu_testdata::get_provider();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: None,
+    }
+    .into();
+    let reference: MockDateTime = "2020-10-14T13:21:00".parse().unwrap();
+
+    let samples = &[
+        ("en", "2020-10-13T23:59:59", "yesterday"),
+        ("en", "2020-10-14T00:00:00", "today"),
+        ("en", "2020-10-15T08:00:00", "tomorrow"),
+        // English has no name for the day after tomorrow.
+        ("en", "2020-10-16T08:00:00", "Oct 16, 2020"),
+        ("fr", "2020-10-12T08:00:00", "avant-hier"),
+        ("fr", "2020-10-16T08:00:00", "après-demain"),
+        ("fr", "2020-10-17T08:00:00", "17 oct. 2020"),
+    ];
+    for (langid, value, expected) in samples {
+        let langid: LanguageIdentifier = langid.parse().unwrap();
+        let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+        let value: MockDateTime = value.parse().unwrap();
+        assert_eq!(
+            dtf.format_relative_day(&value, &reference).unwrap(),
+            *expected
+        );
+    }
+
+    // The time of day is kept next to the name of the day.
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        preferences: None,
+    }
+    .into();
+    let samples = &[
+        ("en", "2020-10-13T23:59:00", "yesterday, 11:59 PM"),
+        ("en", "2020-10-16T08:00:00", "Oct 16, 2020, 8:00 AM"),
+        ("fr", "2020-10-15T08:00:00", "demain, 08:00"),
+    ];
+    for (langid, value, expected) in samples {
+        let langid: LanguageIdentifier = langid.parse().unwrap();
+        let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+        let value: MockDateTime = value.parse().unwrap();
+        assert_eq!(
+            dtf.format_relative_day(&value, &reference).unwrap(),
+            *expected
+        );
+    }
+}
+
+#[test]
+fn test_format_with_preparsed_pattern() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Full),
+        time: Some(style::Time::Medium),
+        ..Default::default()
+    };
+    let options = options.into();
+
+    // A `DateTimeFormat` reused across values formats them the same way as a new one.
+    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options).unwrap();
+    let mut result = String::new();
+    for value in &[
+        "2020-10-14T13:21:06",
+        "2021-01-01T00:00:00",
+        "1999-12-31T23:59:59",
+    ] {
+        let value: MockDateTime = value.parse().unwrap();
+        result.clear();
+        dtf.format_to_write(&mut result, &value).unwrap();
+        let fresh = DateTimeFormat::try_new(langid.clone(), &provider, &options).unwrap();
+        assert_eq!(result, fresh.format_to_string(&value));
+    }
+    assert_eq!(result, "Friday, December 31, 1999 at 11:59:59 PM");
+}
+
+#[test]
+fn test_round_trips() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let value: MockDateTime = "2020-10-14T13:21:06".parse().unwrap();
+
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Medium),
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+    assert!(dtf.round_trips(&value));
+
+    // The time is lost by a date-only pattern.
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+    assert!(!dtf.round_trips(&value));
+    assert!(dtf.round_trips(&"2020-10-14T00:00:00".parse().unwrap()));
+}
+
+#[test]
+fn test_format_min_year_digits() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let value: MockDateTime = "0005-10-14T00:00:00".parse().unwrap();
+
+    // The medium date pattern of "en" uses a single `y`.
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: None,
+    };
+    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+    assert_eq!(dtf.format_to_string(&value), "Oct 14, 5");
+
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        preferences: Some(preferences::Bag {
+            min_year_digits: Some(4),
+            ..Default::default()
+        }),
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+    assert_eq!(dtf.format_to_string(&value), "Oct 14, 0005");
+    assert_eq!(dtf.parse("Oct 14, 0005").unwrap(), value);
+    assert!(dtf.parse("Oct 14, 5").is_err());
+}
+
+#[test]
+fn test_parse_two_digit_year_pivot() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let samples = &[
+        (None, "2020-10-14T00:00:00"),
+        (Some(2000), "2020-10-14T00:00:00"),
+        (Some(1930), "1920-10-14T00:00:00"),
+    ];
+    for (pivot, expected) in samples {
+        // The short date pattern of "en" uses a two-digit year.
+        let options = style::Bag {
+            date: Some(style::Date::Short),
+            time: None,
+            preferences: Some(preferences::Bag {
+                two_digit_year_pivot: *pivot,
+                ..Default::default()
+            }),
+        };
+        let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+        let expected: MockDateTime = expected.parse().unwrap();
+        assert_eq!(dtf.parse("10/14/20").unwrap(), expected, "{:?}", pivot);
+        assert_eq!(dtf.format_to_string(&expected), "10/14/20");
+    }
+}
+
+#[test]
+fn test_format_month_width() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let value: MockDateTime = "2020-10-14T00:00:00".parse().unwrap();
+    // The long date pattern of "en" uses `MMMM`.
+    let samples = &[
+        (None, "October 14, 2020"),
+        (Some(preferences::MonthWidth::Abbreviated), "Oct 14, 2020"),
+        (Some(preferences::MonthWidth::Narrow), "O 14, 2020"),
+    ];
+    for (month_width, expected) in samples {
+        let options = style::Bag {
+            date: Some(style::Date::Long),
+            time: None,
+            preferences: Some(preferences::Bag {
+                month_width: *month_width,
+                ..Default::default()
+            }),
+        };
+        let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+        assert_eq!(dtf.parse(expected).unwrap(), value, "{}", expected);
+        if *month_width == Some(preferences::MonthWidth::Narrow) {
+            // January, June and July share their narrow name.
+            assert!(matches!(
+                dtf.parse("J 14, 2020"),
+                Err(DateTimeError::AmbiguousSymbol(symbol)) if symbol == "J"
+            ));
+        }
+    }
+}
+
+#[test]
+fn test_suppress_leading_zeros() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "fr".parse().unwrap();
+    let value: MockDateTime = "2020-07-04T09:05:00".parse().unwrap();
+    // The short date and time patterns of "fr" are `dd/MM/y` and `HH:mm`.
+    let samples = &[(false, "04/07/2020 09:05"), (true, "4/7/2020 9:5")];
+    for (suppress_leading_zeros, expected) in samples {
+        let options = style::Bag {
+            date: Some(style::Date::Short),
+            time: Some(style::Time::Short),
+            preferences: Some(preferences::Bag {
+                suppress_leading_zeros: *suppress_leading_zeros,
+                ..Default::default()
+            }),
+        };
+        let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options.into()).unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+        assert_eq!(dtf.parse(expected).unwrap(), value, "{}", expected);
+    }
+}
+
+/// A provider which falls back to the data of the language of the requested locale, and
+/// reports it in the metadata.
+#[cfg(feature = "debug_provenance")]
+struct LanguageFallbackProvider<P>(P);
+
+#[cfg(feature = "debug_provenance")]
+impl<'d, P: DataProvider<'d, DatesV1>> DataProvider<'d, DatesV1> for LanguageFallbackProvider<P> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<icu_provider::DataResponse<'d, DatesV1>, icu_provider::DataError> {
+        let langid = req
+            .resource_path
+            .options
+            .langid
+            .as_ref()
+            .map(|langid| LanguageIdentifier {
+                language: langid.language,
+                ..Default::default()
+            });
+        let mut fallback = req.clone();
+        fallback.resource_path.options.langid = langid.clone();
+        let mut response = self.0.load_payload(&fallback)?;
+        response.metadata.data_langid = langid;
+        Ok(response)
+    }
+}
+
+#[cfg(feature = "debug_provenance")]
+#[test]
+fn test_provenance() {
+    use icu_datetime::pattern::{Pattern, PatternItem};
+
+    let provider = LanguageFallbackProvider(icu_testdata::get_provider());
+    let langid: LanguageIdentifier = "en-GB".parse().unwrap();
+    let options: DateTimeFormatOptions = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        ..Default::default()
+    }
+    .into();
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+
+    // The month names and the pattern both come from "en".
+    let en: LanguageIdentifier = "en".parse().unwrap();
+    let provenance = dtf.provenance();
+    let expected = Pattern::from_bytes("MMM d, y").unwrap();
+    let fields: Vec<&PatternItem> = expected
+        .items()
+        .iter()
+        .filter(|item| matches!(item, PatternItem::Field(..)))
+        .collect();
+    assert_eq!(provenance.len(), fields.len());
+    for ((item, langid), expected) in provenance.iter().zip(fields) {
+        assert_eq!(*item, expected);
+        assert_eq!(*langid, Some(&en));
+    }
+
+    // A provider which doesn't report the locale of its data.
+    let data = load_dates(&icu_testdata::get_provider(), &en);
+    let provider = StructProvider {
+        key: GREGORY_V1,
+        data: data.as_ref(),
+    };
+    let dtf = DateTimeFormat::try_new(en, &provider, &options).unwrap();
+    assert!(dtf.provenance().iter().all(|(_, langid)| langid.is_none()));
+}
+
+#[test]
+fn test_write_fmt() {
+    struct Event<'a> {
+        dtf: &'a DateTimeFormat<'a>,
+        name: &'static str,
+        start: MockDateTime,
+    }
+
+    impl fmt::Display for Event<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} (", self.name)?;
+            self.dtf.write_fmt(f, &self.start)?;
+            f.write_char(')')
+        }
+    }
+
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+    let event = Event {
+        dtf: &dtf,
+        name: "Release",
+        start: "2020-10-14T13:21:00".parse().unwrap(),
+    };
+    assert_eq!(event.to_string(), "Release (Oct 14, 2020, 1:21 PM)");
+}
+
+#[test]
+fn test_format_all() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+
+    let values: Vec<MockDateTime> = [
+        "2020-09-12T12:35:00",
+        "2020-10-14T13:21:00",
+        "2021-01-01T00:01:00",
+    ]
+    .iter()
+    .map(|value| value.parse().unwrap())
+    .collect();
+    let expected = &[
+        "Sep 12, 2020, 12:35 PM",
+        "Oct 14, 2020, 1:21 PM",
+        "Jan 1, 2021, 12:01 AM",
+    ];
+
+    // The existing strings are overwritten, and the extra ones are removed.
+    let mut out = vec![String::from("foo"); 5];
+    dtf.format_all_into(&values, &mut out).unwrap();
+    assert_eq!(out, expected);
+    dtf.format_all_into(&values[..1], &mut out).unwrap();
+    assert_eq!(out, &expected[..1]);
+    dtf.format_all_into(&values, &mut out).unwrap();
+    assert_eq!(out, expected);
+
+    let mut out = String::from("> ");
+    dtf.format_all_joined_into(&values, "; ", &mut out).unwrap();
+    assert_eq!(out, format!("> {}", expected.join("; ")));
+
+    let mut out = String::new();
+    dtf.format_all_joined_into::<MockDateTime>(&[], "; ", &mut out)
+        .unwrap();
+    assert!(out.is_empty());
+}
+
+fn load_dates(
+    provider: &impl DataProvider<'static, DatesV1>,
+    langid: &LanguageIdentifier,
+) -> Cow<'static, DatesV1> {
+    let load = |langid: LanguageIdentifier| {
+        provider
             .load_payload(&DataRequest {
                 resource_path: ResourcePath {
                     key: GREGORY_V1,
                     options: ResourceOptions {
                         variant: None,
-                        langid: Some(langid.clone()),
+                        langid: Some(langid),
                     },
                 },
             })
-            .unwrap()
-            .take_payload()
-            .unwrap();
+            .and_then(|mut response| response.take_payload())
+    };
+    // TODO: Remove once the provider supports locale fallback.
+    load(langid.clone())
+        .or_else(|_| {
+            load(LanguageIdentifier {
+                region: None,
+                ..langid.clone()
+            })
+        })
+        .unwrap()
+}
+
+fn test_patterns(name: &str) {
+    use patterns::structs::Expectation;
+    let provider = icu_testdata::get_provider();
+    let format_options = DateTimeFormatOptions::default();
+    for test in patterns::get_tests(name).unwrap().0 {
+        let langid: LanguageIdentifier = test.locale.parse().unwrap();
+        let mut data = load_dates(&provider, &langid);
         *data.to_mut().patterns.date_time.long.to_mut() = String::from("{0}");
         for test_case in &test.test_cases {
             for dt_input in &test_case.date_times {
@@ -93,3 +833,363 @@ fn test_dayperiod_patterns() {
         }
     }
 }
+
+#[test]
+fn test_dayperiod_patterns() {
+    test_patterns("dayperiods");
+}
+
+#[test]
+fn test_flexible_dayperiod_patterns() {
+    test_patterns("flexible_dayperiods");
+}
+
+#[test]
+fn test_parse_flexible_dayperiods() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let mut data = load_dates(&provider, &langid);
+    *data.to_mut().patterns.date_time.long.to_mut() = String::from("{0}");
+    *data.to_mut().patterns.time.long.to_mut() = String::from("h:mm B");
+    let provider = StructProvider {
+        key: GREGORY_V1,
+        data: data.as_ref(),
+    };
+    let dtf =
+        DateTimeFormat::try_new(langid, &provider, &DateTimeFormatOptions::default()).unwrap();
+
+    let samples = &[
+        ("9:15 in the morning", "0000-01-01T09:15:00"),
+        ("1:30 in the afternoon", "0000-01-01T13:30:00"),
+        ("7:45 in the evening", "0000-01-01T19:45:00"),
+        ("11:05 at night", "0000-01-01T23:05:00"),
+        ("1:00 at night", "0000-01-01T01:00:00"),
+        ("12:00 noon", "0000-01-01T12:00:00"),
+        ("12:00 midnight", "0000-01-01T00:00:00"),
+    ];
+    for (input, expected) in samples {
+        let expected: MockDateTime = expected.parse().unwrap();
+        assert_eq!(dtf.parse(input).unwrap(), expected, "{}", input);
+    }
+
+    for input in &["3:00 noon", "9:15 in the afternoon", "9:15 PM"] {
+        assert!(dtf.parse(input).is_err(), "{}", input);
+    }
+}
+
+#[test]
+fn test_gmt_offset_patterns() {
+    let provider = icu_testdata::get_provider();
+    let samples = &[
+        (
+            "en",
+            "HH:mm O",
+            "2020-10-14T13:21:00+05:30",
+            "13:21 GMT+5:30",
+        ),
+        (
+            "en",
+            "HH:mm OOOO",
+            "2020-10-14T13:21:00+05:30",
+            "13:21 GMT+05:30",
+        ),
+        ("en", "HH:mm O", "2020-10-14T13:21:00-08:00", "13:21 GMT-8"),
+        (
+            "en",
+            "HH:mm OOOO",
+            "2020-10-14T13:21:00-08:00",
+            "13:21 GMT-08:00",
+        ),
+        ("en", "HH:mm O", "2020-10-14T13:21:00Z", "13:21 GMT"),
+        ("en", "HH:mm OOOO", "2020-10-14T13:21:00Z", "13:21 GMT"),
+        (
+            "fr",
+            "HH:mm O",
+            "2020-10-14T13:21:00+05:30",
+            "13:21 UTC+5:30",
+        ),
+    ];
+    for (langid, pattern, value, expected) in samples {
+        let langid: LanguageIdentifier = langid.parse().unwrap();
+        let mut data = load_dates(&provider, &langid);
+        *data.to_mut().patterns.date_time.long.to_mut() = String::from("{0}");
+        *data.to_mut().patterns.time.long.to_mut() = String::from(*pattern);
+        let provider = StructProvider {
+            key: GREGORY_V1,
+            data: data.as_ref(),
+        };
+        let dtf =
+            DateTimeFormat::try_new(langid, &provider, &DateTimeFormatOptions::default()).unwrap();
+
+        let value: MockZonedDateTime = value.parse().unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected);
+
+        // Values without an offset are rejected, unless the offset is left out.
+        assert!(dtf.try_format_to_string(&value.date_time).is_err());
+        assert_eq!(dtf.format_to_string(&value.date_time), "13:21");
+    }
+}
+
+#[test]
+fn test_iso8601_offset_patterns() {
+    let provider = icu_testdata::get_provider();
+    // Amsterdam Mean Time was 19 minutes and 32 seconds ahead of GMT until 1937.
+    let historical = "1930-05-01T12:00:00+00:19:32";
+    let samples = &[
+        ("HH:mm x", "2020-10-14T13:21:00+05:30", "13:21 +0530"),
+        ("HH:mm x", "2020-10-14T13:21:00-08:00", "13:21 -08"),
+        ("HH:mm xx", "2020-10-14T13:21:00-08:00", "13:21 -0800"),
+        ("HH:mm xxx", "2020-10-14T13:21:00+05:30", "13:21 +05:30"),
+        ("HH:mm xxxx", historical, "12:00 +001932"),
+        ("HH:mm xxxxx", historical, "12:00 +00:19:32"),
+        ("HH:mm XXXXX", historical, "12:00 +00:19:32"),
+        ("HH:mm xxx", historical, "12:00 +00:19"),
+        ("HH:mm xxxxx", "2020-10-14T13:21:00+05:30", "13:21 +05:30"),
+        // Only the `X` fields write a zero offset as `Z`.
+        ("HH:mm x", "2020-10-14T13:21:00Z", "13:21 +00"),
+        ("HH:mm xxxxx", "2020-10-14T13:21:00Z", "13:21 +00:00"),
+        ("HH:mm X", "2020-10-14T13:21:00Z", "13:21 Z"),
+        ("HH:mm XXXXX", "2020-10-14T13:21:00Z", "13:21 Z"),
+    ];
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    for (pattern, value, expected) in samples {
+        let mut data = load_dates(&provider, &langid);
+        *data.to_mut().patterns.date_time.long.to_mut() = String::from("{0}");
+        *data.to_mut().patterns.time.long.to_mut() = String::from(*pattern);
+        let provider = StructProvider {
+            key: GREGORY_V1,
+            data: data.as_ref(),
+        };
+        let dtf =
+            DateTimeFormat::try_new(langid.clone(), &provider, &DateTimeFormatOptions::default())
+                .unwrap();
+
+        let value: MockZonedDateTime = value.parse().unwrap();
+        assert_eq!(dtf.format_to_string(&value), *expected, "{}", pattern);
+
+        // The offsets are parsed back as written.
+        let parsed = dtf.parse_zoned(expected).unwrap();
+        assert_eq!(dtf.format_to_string(&parsed), *expected, "{}", pattern);
+    }
+
+    let dtf =
+        DateTimeFormat::try_new_with_pattern(langid, &provider, "y-MM-dd'T'HH:mm:ssxxxxx").unwrap();
+    let value: MockZonedDateTime = historical.parse().unwrap();
+    assert_eq!(
+        dtf.parse_zoned("1930-05-01T12:00:00+00:19:32").unwrap(),
+        value
+    );
+    assert_eq!(
+        dtf.parse("1930-05-01T12:00:00+00:19:32").unwrap(),
+        value.date_time
+    );
+    assert!(dtf.parse_zoned("1930-05-01T12:00:00Z").is_err());
+}
+
+#[test]
+fn test_region_offset() {
+    use icu_datetime::date::FixedOffsetProvider;
+
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let mut data = load_dates(&provider, &langid);
+    *data.to_mut().patterns.date_time.long.to_mut() = String::from("{0}");
+    *data.to_mut().patterns.time.long.to_mut() = String::from("d HH:mm xxxxx");
+    let provider = StructProvider {
+        key: GREGORY_V1,
+        data: data.as_ref(),
+    };
+    let dtf =
+        DateTimeFormat::try_new(langid, &provider, &DateTimeFormatOptions::default()).unwrap();
+
+    let offsets = FixedOffsetProvider {
+        offsets: vec![("JP".parse().unwrap(), "+09:00".parse().unwrap())],
+    };
+    let instant: MockDateTime = "2020-10-14T20:30:00".parse().unwrap();
+    let value =
+        MockZonedDateTime::from_utc_in_region(&instant, "JP".parse().unwrap(), &offsets).unwrap();
+    assert_eq!(dtf.format_to_string(&value), "15 05:30 +09:00");
+    assert!(
+        MockZonedDateTime::from_utc_in_region(&instant, "US".parse().unwrap(), &offsets).is_none()
+    );
+}
+
+#[test]
+fn test_weekday_patterns() {
+    test_patterns("weekdays");
+}
+
+#[test]
+fn test_era_patterns() {
+    test_patterns("eras");
+}
+
+#[test]
+fn test_quarter_patterns() {
+    test_patterns("quarters");
+}
+
+#[test]
+#[cfg(feature = "graphemes")]
+fn test_grapheme_len() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "bn".parse().unwrap();
+    let options = style::Bag {
+        date: Some(style::Date::Long),
+        time: None,
+        ..Default::default()
+    };
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options.into()).unwrap();
+
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+    let fdt = dtf.format(&value);
+    // The Bengali month name contains vowel signs and a virama, which combine with
+    // the preceding consonants.
+    assert_eq!(fdt.to_string(), "১২ সেপ্টেম্বর, ২০২০");
+    assert_eq!(fdt.to_string().chars().count(), 19);
+    assert_eq!(fdt.grapheme_len(), 15);
+}
+
+struct CountingProvider<P> {
+    provider: P,
+    loads: std::cell::Cell<usize>,
+}
+
+impl<'d, P: DataProvider<'d, DatesV1>> DataProvider<'d, DatesV1> for CountingProvider<P> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<icu_provider::DataResponse<'d, DatesV1>, icu_provider::DataError> {
+        self.loads.set(self.loads.get() + 1);
+        self.provider.load_payload(req)
+    }
+}
+
+#[test]
+fn test_lazy_construction() {
+    let provider = CountingProvider {
+        provider: icu_testdata::get_provider(),
+        loads: Default::default(),
+    };
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options: DateTimeFormatOptions = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        ..Default::default()
+    }
+    .into();
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+
+    let mut lazy = LazyDateTimeFormat::new(langid.clone(), &provider, &options);
+    assert_eq!(provider.loads.get(), 0);
+
+    let eager = DateTimeFormat::try_new(langid, &provider, &options).unwrap();
+    assert_eq!(provider.loads.get(), 1);
+
+    let dtf = lazy.get().unwrap();
+    assert_eq!(provider.loads.get(), 2);
+    assert_eq!(dtf.format_to_string(&value), eager.format_to_string(&value));
+    assert_eq!(dtf.format_to_string(&value), "Sep 12, 2020, 12:35 PM");
+    lazy.get().unwrap();
+    assert_eq!(provider.loads.get(), 2);
+
+    // The errors are returned on first use.
+    let locale: Locale = "en-u-ca-persian".parse().unwrap();
+    let mut lazy = LazyDateTimeFormat::new(locale, &provider, &options);
+    assert!(matches!(
+        lazy.get(),
+        Err(DateTimeFormatError::UnsupportedCalendar(_))
+    ));
+}
+
+#[test]
+fn test_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<DateTimeFormat>();
+}
+
+#[test]
+fn test_literal_overrides() {
+    use std::collections::HashMap;
+
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options: DateTimeFormatOptions = style::Bag {
+        date: Some(style::Date::Short),
+        time: None,
+        ..Default::default()
+    }
+    .into();
+    let value: MockDateTime = "2020-09-12T12:35:00".parse().unwrap();
+
+    // The fields are never replaced, even when they look like a key.
+    let overrides: HashMap<String, String> = vec![("/", "."), ("M", "?"), ("d", "?")]
+        .into_iter()
+        .map(|(literal, replacement)| (literal.to_string(), replacement.to_string()))
+        .collect();
+    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options)
+        .unwrap()
+        .with_literal_overrides(overrides.clone());
+    assert_eq!(dtf.format_to_string(&value), "9.12.20");
+
+    // The overrides are applied to the data loaded later, and compose.
+    let mut next = HashMap::new();
+    next.insert(".".to_string(), "-".to_string());
+    let mut lazy = LazyDateTimeFormat::new(langid.clone(), &provider, &options)
+        .with_literal_overrides(overrides.clone())
+        .with_literal_overrides(next.clone());
+    let eager = DateTimeFormat::try_new(langid.clone(), &provider, &options)
+        .unwrap()
+        .with_literal_overrides(overrides)
+        .with_literal_overrides(next);
+    assert_eq!(lazy.get().unwrap().format_to_string(&value), "9-12-20");
+    assert_eq!(eager.format_to_string(&value), "9-12-20");
+
+    // The literals are matched as a whole.
+    let options: DateTimeFormatOptions = style::Bag {
+        date: Some(style::Date::Medium),
+        time: None,
+        ..Default::default()
+    }
+    .into();
+    let mut overrides = HashMap::new();
+    overrides.insert(",".to_string(), ";".to_string());
+    let dtf = DateTimeFormat::try_new(langid, &provider, &options)
+        .unwrap()
+        .with_literal_overrides(overrides);
+    assert_eq!(dtf.format_to_string(&value), "Sep 12, 2020");
+}
+
+#[cfg(feature = "structured")]
+#[test]
+fn test_format_structured() {
+    let provider = icu_testdata::get_provider();
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    let options: DateTimeFormatOptions = style::Bag {
+        date: Some(style::Date::Medium),
+        time: Some(style::Time::Short),
+        ..Default::default()
+    }
+    .into();
+    let dtf = DateTimeFormat::try_new(langid.clone(), &provider, &options).unwrap();
+    let value: MockDateTime = "2020-09-12T15:05:00".parse().unwrap();
+
+    assert_eq!(dtf.format_to_string(&value), "Sep 12, 2020, 3:05 PM");
+    assert_eq!(
+        dtf.format_structured(&value).unwrap(),
+        serde_json::json!({
+            "month": "Sep",
+            "day": "12",
+            "year": "2020",
+            "hour": "3",
+            "minute": "05",
+            "dayPeriod": "PM",
+        })
+    );
+
+    let dtf = DateTimeFormat::try_new_with_pattern(langid, &provider, "HH:mm O").unwrap();
+    assert!(matches!(
+        dtf.format_structured(&value),
+        Err(DateTimeFormatError::MissingInputField("GmtOffset"))
+    ));
+}
@@ -0,0 +1,137 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use crate::{LocaleCanonicalizer, LocaleCanonicalizerError};
+use icu_locid::Locale;
+use std::cell::RefCell;
+
+/// A cache of locales keyed by the input string, which evicts the least recently used
+/// entry once full.
+///
+/// The entries are searched linearly, which is faster than hashing for the small
+/// capacities this is meant for.
+struct LruCache {
+    capacity: usize,
+    // Ordered from the least to the most recently used.
+    entries: Vec<(String, Locale)>,
+}
+
+impl LruCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn get(&mut self, input: &str) -> Option<Locale> {
+        let index = self.entries.iter().position(|(key, _)| key == input)?;
+        let entry = self.entries.remove(index);
+        let locale = entry.1.clone();
+        self.entries.push(entry);
+        Some(locale)
+    }
+
+    fn insert(&mut self, input: &str, locale: Locale) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push((input.to_string(), locale));
+    }
+}
+
+/// A wrapper around a [`LocaleCanonicalizer`] which canonicalizes locale strings, caching
+/// the results of the most recently used inputs.
+///
+/// The caches are updated through a shared reference using a `RefCell`, so a
+/// `CachingLocaleCanonicalizer` can't be shared between threads. Use one instance per
+/// thread, or wrap it in a `Mutex`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "provider_serde")] {
+/// use icu_locale_canonicalizer::{CachingLocaleCanonicalizer, LocaleCanonicalizer};
+///
+/// let provider = icu_testdata::get_provider();
+/// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+/// let clc = CachingLocaleCanonicalizer::new(lc, 16);
+///
+/// let locale = clc.maximize("en-US").expect("Failed to parse a locale.");
+/// assert_eq!(locale.to_string(), "en-Latn-US");
+/// assert_eq!(clc.len(), 1);
+/// # } // feature = "provider_serde"
+/// ```
+pub struct CachingLocaleCanonicalizer<'a> {
+    canonicalizer: LocaleCanonicalizer<'a>,
+    maximized: RefCell<LruCache>,
+    minimized: RefCell<LruCache>,
+}
+
+impl<'a> CachingLocaleCanonicalizer<'a> {
+    /// A constructor which takes a `LocaleCanonicalizer` and the number of results
+    /// to cache for each of the operations.
+    pub fn new(canonicalizer: LocaleCanonicalizer<'a>, capacity: usize) -> Self {
+        Self {
+            canonicalizer,
+            maximized: RefCell::new(LruCache::new(capacity)),
+            minimized: RefCell::new(LruCache::new(capacity)),
+        }
+    }
+
+    /// Parses the `input` locale and returns the result of
+    /// [`LocaleCanonicalizer::maximize`], from the cache if it was already maximized.
+    ///
+    /// Inputs which fail to parse, or have more variants than
+    /// [`LocaleCanonicalizer::with_max_variants`] allows, are not cached.
+    pub fn maximize(&self, input: &str) -> Result<Locale, LocaleCanonicalizerError> {
+        self.canonicalize(&self.maximized, input, |locale| {
+            self.canonicalizer.maximize(locale);
+        })
+    }
+
+    /// Parses the `input` locale and returns the result of
+    /// [`LocaleCanonicalizer::minimize`], from the cache if it was already minimized.
+    ///
+    /// Inputs which fail to parse, or have more variants than
+    /// [`LocaleCanonicalizer::with_max_variants`] allows, are not cached.
+    pub fn minimize(&self, input: &str) -> Result<Locale, LocaleCanonicalizerError> {
+        self.canonicalize(&self.minimized, input, |locale| {
+            self.canonicalizer.minimize(locale);
+        })
+    }
+
+    /// Returns the number of results currently cached, for all of the operations.
+    pub fn len(&self) -> usize {
+        self.maximized.borrow().entries.len() + self.minimized.borrow().entries.len()
+    }
+
+    /// Returns `true` if no result is currently cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all of the cached results.
+    pub fn clear(&self) {
+        self.maximized.borrow_mut().entries.clear();
+        self.minimized.borrow_mut().entries.clear();
+    }
+
+    fn canonicalize(
+        &self,
+        cache: &RefCell<LruCache>,
+        input: &str,
+        operation: impl FnOnce(&mut Locale),
+    ) -> Result<Locale, LocaleCanonicalizerError> {
+        if let Some(locale) = cache.borrow_mut().get(input) {
+            return Ok(locale);
+        }
+        let mut locale = self.canonicalizer.parse(input)?;
+        operation(&mut locale);
+        cache.borrow_mut().insert(input, locale.clone());
+        Ok(locale)
+    }
+}
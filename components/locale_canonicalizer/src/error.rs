@@ -0,0 +1,37 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
+use icu_locid::ParserError;
+use std::error::Error;
+use std::fmt;
+
+/// A list of possible error outcomes of the [`LocaleCanonicalizer`](crate::LocaleCanonicalizer)
+/// methods which read locale strings.
+///
+#[derive(Debug, PartialEq)]
+pub enum LocaleCanonicalizerError {
+    /// An error coming from the parsing of the locale string
+    Parser(ParserError),
+    /// The locale string has more variant subtags than the maximum set with
+    /// [`LocaleCanonicalizer::with_max_variants`](crate::LocaleCanonicalizer::with_max_variants)
+    TooManyVariants { max: usize },
+}
+
+impl Error for LocaleCanonicalizerError {}
+
+impl fmt::Display for LocaleCanonicalizerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parser(err) => write!(f, "{}", err),
+            Self::TooManyVariants { max } => {
+                write!(f, "Too many variant subtags, the maximum is {}", max)
+            }
+        }
+    }
+}
+
+impl From<ParserError> for LocaleCanonicalizerError {
+    fn from(err: ParserError) -> Self {
+        Self::Parser(err)
+    }
+}
@@ -2,7 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 
+pub mod caching_locale_canonicalizer;
+mod error;
 pub mod locale_canonicalizer;
 pub mod provider;
 
-pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+pub use caching_locale_canonicalizer::CachingLocaleCanonicalizer;
+pub use error::LocaleCanonicalizerError;
+pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer, UnknownLanguage};
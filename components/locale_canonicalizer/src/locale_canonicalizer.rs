@@ -2,11 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 use crate::provider::*;
+use crate::LocaleCanonicalizerError;
 use icu_locid::subtags;
 use icu_locid::LanguageIdentifier;
 use icu_locid::Locale;
+use icu_locid::ParserError;
 use icu_provider::prelude::*;
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 /// CanonicalizationResult is used to track the result of a canonicalization
 /// operation that potentially modifies its argument in place.
@@ -14,13 +17,40 @@ use std::borrow::Cow;
 pub enum CanonicalizationResult {
     Modified,
     Unmodified,
+    /// The argument was left unchanged, because there is no data for it, such as the
+    /// likely subtags of an unknown language.
+    MissingData,
+}
+
+/// The behavior of [`LocaleCanonicalizer::maximize`] for a language which has no likely
+/// subtags, such as a private-use language `qaa`.
+///
+/// It only applies when every lookup of the specification fails, so that `qaa-Cyrl` still
+/// maximizes to `qaa-Cyrl-RU` with the likely subtags of its script.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum UnknownLanguage {
+    /// Leaves the locale unchanged and returns [`CanonicalizationResult::MissingData`].
+    #[default]
+    LeaveUnchanged,
+    /// Keeps the language and fills in the script and the region of the undetermined
+    /// language `und`, maximized with the script and the region of the locale, so that
+    /// `qaa` maximizes to `qaa-Latn-US`, and `qaa-FR` to `qaa-Latn-FR`.
+    UndFallback,
 }
 
 pub struct LocaleCanonicalizer<'a> {
     likely_subtags: Cow<'a, LikelySubtagsV1>,
+    default_locale: Option<LanguageIdentifier>,
+    fold_private_use_case: bool,
+    unknown_language: UnknownLanguage,
+    max_variants: usize,
 }
 
 impl LocaleCanonicalizer<'_> {
+    /// The default maximum number of variant subtags of the locale strings, which is far
+    /// above the number used by real locales.
+    pub const DEFAULT_MAX_VARIANTS: usize = 32;
+
     /// A constructor which takes a DataProvider and creates a
     /// LocaleCanonicalizer.
     pub fn new<'d>(
@@ -32,9 +62,124 @@ impl LocaleCanonicalizer<'_> {
 
         Ok(LocaleCanonicalizer {
             likely_subtags: payload,
+            default_locale: None,
+            fold_private_use_case: true,
+            unknown_language: UnknownLanguage::default(),
+            max_variants: Self::DEFAULT_MAX_VARIANTS,
         })
     }
 
+    /// Sets the locale which fully undetermined input, such as `und`, maximizes to,
+    /// instead of the CLDR default-content locale `en-Latn-US`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .unwrap()
+    ///     .with_default_locale("de-Latn-DE".parse().unwrap());
+    ///
+    /// let mut locale : Locale = "und".parse().unwrap();
+    /// assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Modified);
+    /// assert_eq!(locale.to_string(), "de-Latn-DE");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn with_default_locale(mut self, default_locale: LanguageIdentifier) -> Self {
+        self.default_locale = Some(default_locale);
+        self
+    }
+
+    /// Sets whether the private-use subtags, following `-x-`, are lowercased by
+    /// [`LocaleCanonicalizer::canonicalize_dedup`], which is the default. Private-use
+    /// subtags are case-insensitive, but some workflows rely on their original casing.
+    ///
+    /// *Note:* This only applies to [`LocaleCanonicalizer::canonicalize_dedup`], which returns
+    /// strings. A [`Locale`] always stores its private-use subtags lowercased, so the methods
+    /// which return or modify one, such as [`LocaleCanonicalizer::canonicalize_trace`] and
+    /// [`LocaleCanonicalizer::maximize`], fold them regardless of this option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .unwrap()
+    ///     .with_private_use_case_folding(false);
+    ///
+    /// let inputs = vec!["EN-gb-x-FooBar".to_string()];
+    /// assert_eq!(lc.canonicalize_dedup(&inputs)["EN-gb-x-FooBar"], "en-GB-x-FooBar");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn with_private_use_case_folding(mut self, fold: bool) -> Self {
+        self.fold_private_use_case = fold;
+        self
+    }
+
+    /// Sets how [`LocaleCanonicalizer::maximize`] handles a language which has no likely
+    /// subtags, which is left unchanged by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer, UnknownLanguage};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .unwrap()
+    ///     .with_unknown_language(UnknownLanguage::UndFallback);
+    ///
+    /// let mut locale : Locale = "qaa".parse().unwrap();
+    /// assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Modified);
+    /// assert_eq!(locale.to_string(), "qaa-Latn-US");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn with_unknown_language(mut self, unknown_language: UnknownLanguage) -> Self {
+        self.unknown_language = unknown_language;
+        self
+    }
+
+    /// Sets the maximum number of variant subtags of the locale strings read by the
+    /// canonicalizer, which is [`LocaleCanonicalizer::DEFAULT_MAX_VARIANTS`] by default.
+    ///
+    /// The strings with more variants are rejected with
+    /// [`LocaleCanonicalizerError::TooManyVariants`] before being parsed, which bounds the
+    /// memory used by untrusted input. It only applies to the methods which read strings,
+    /// such as [`LocaleCanonicalizer::canonicalize_trace`], since a [`Locale`] is already
+    /// parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{LocaleCanonicalizer, LocaleCanonicalizerError};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .unwrap()
+    ///     .with_max_variants(1);
+    ///
+    /// assert!(lc.canonicalize_trace("ca-ES-valencia").is_ok());
+    /// assert_eq!(
+    ///     lc.canonicalize_trace("sl-rozaj-biske"),
+    ///     Err(LocaleCanonicalizerError::TooManyVariants { max: 1 })
+    /// );
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn with_max_variants(mut self, max: usize) -> Self {
+        self.max_variants = max;
+        self
+    }
+
     /// The maximize method potentially updates a passed in locale in place
     /// depending up the results of running the 'Add Likely Subtags' algorithm
     /// from https://www.unicode.org/reports/tr35/#Likely_Subtags.
@@ -45,6 +190,15 @@ impl LocaleCanonicalizer<'_> {
     /// returns `CanonicalizationResult::Unmodified` and the locale argument is
     /// unchanged.
     ///
+    /// No alias replacement is performed, so legacy codes such as `iw` are preserved,
+    /// e.g. `iw` maximizes to `iw-Hebr-IL` rather than `he-Hebr-IL`. A language without
+    /// likely subtags returns `CanonicalizationResult::MissingData`, unless configured
+    /// otherwise with [`LocaleCanonicalizer::with_unknown_language`].
+    ///
+    /// Only the missing language, script and region are filled in. The variants and the
+    /// extensions are kept as they are, e.g. `ca-ES-valencia` maximizes to
+    /// `ca-Latn-ES-valencia`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -65,6 +219,48 @@ impl LocaleCanonicalizer<'_> {
     /// # } // feature = "provider_serde"
     /// ```
     pub fn maximize(&self, locale: &mut Locale) -> CanonicalizationResult {
+        self.maximize_traced(locale, None)
+    }
+
+    /// Runs [`LocaleCanonicalizer::maximize`] on the locale argument, and records in `trail`
+    /// the key of each data it consulted which isn't already there, such as
+    /// [`key::LIKELY_SUBTAGS_V1`], so that its result may be invalidated when this data
+    /// changes.
+    ///
+    /// *Note:* The canonicalizer doesn't load alias data yet, so the likely subtags are the
+    /// only data recorded. The key of the alias data will be recorded once it is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+    /// use icu_locale_canonicalizer::provider::key;
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// let mut trail = vec![];
+    /// let mut locale : Locale = "en-US".parse().unwrap();
+    /// assert_eq!(lc.maximize_with_trail(&mut locale, &mut trail), CanonicalizationResult::Modified);
+    /// assert_eq!(locale.to_string(), "en-Latn-US");
+    /// assert_eq!(trail, vec![key::LIKELY_SUBTAGS_V1]);
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn maximize_with_trail(
+        &self,
+        locale: &mut Locale,
+        trail: &mut Vec<ResourceKey>,
+    ) -> CanonicalizationResult {
+        self.maximize_traced(locale, Some(trail))
+    }
+
+    fn maximize_traced(
+        &self,
+        locale: &mut Locale,
+        mut trail: Option<&mut Vec<ResourceKey>>,
+    ) -> CanonicalizationResult {
         let mut key = LanguageIdentifier {
             language: locale.language,
             script: locale.script,
@@ -72,8 +268,9 @@ impl LocaleCanonicalizer<'_> {
             variants: subtags::Variants::default(),
         };
 
-        let maybe_update_locale = |index: usize, locale: &mut Locale| -> CanonicalizationResult {
-            let entry = &self.likely_subtags.entries[index].1;
+        let maybe_update_locale = |entry: &LanguageIdentifier,
+                                   locale: &mut Locale|
+         -> CanonicalizationResult {
             if locale.language.is_empty() || locale.script.is_none() || locale.region.is_none() {
                 if locale.language.is_empty() {
                     locale.language = entry.language;
@@ -88,12 +285,8 @@ impl LocaleCanonicalizer<'_> {
 
         // languages_scripts_regions
         if locale.script.is_some() && locale.region.is_some() {
-            if let Ok(index) = self
-                .likely_subtags
-                .entries
-                .binary_search_by_key(&&key, |(l, _)| l)
-            {
-                return maybe_update_locale(index, locale);
+            if let Some(entry) = self.likely_subtags_for(&key, &mut trail) {
+                return maybe_update_locale(entry, locale);
             }
         }
 
@@ -101,52 +294,288 @@ impl LocaleCanonicalizer<'_> {
         if locale.script.is_some() {
             key.script = locale.script;
             key.region = None;
-            if let Ok(index) = self
-                .likely_subtags
-                .entries
-                .binary_search_by_key(&&key, |(l, _)| l)
-            {
-                return maybe_update_locale(index, locale);
+            if let Some(entry) = self.likely_subtags_for(&key, &mut trail) {
+                return maybe_update_locale(entry, locale);
             }
         }
 
         // languages_regions
         if locale.region.is_some() {
             key.script = None;
-            if let Ok(index) = self
-                .likely_subtags
-                .entries
-                .binary_search_by_key(&&key, |(l, _)| l)
-            {
-                return maybe_update_locale(index, locale);
+            if let Some(entry) = self.likely_subtags_for(&key, &mut trail) {
+                return maybe_update_locale(entry, locale);
             }
         }
 
         // languages
         key.script = None;
         key.region = None;
-        if let Ok(index) = self
-            .likely_subtags
-            .entries
-            .binary_search_by_key(&&key, |(l, _)| l)
-        {
-            return maybe_update_locale(index, locale);
+        if key.language.is_empty() {
+            if let Some(default_locale) = &self.default_locale {
+                return maybe_update_locale(default_locale, locale);
+            }
+        }
+        if let Some(entry) = self.likely_subtags_for(&key, &mut trail) {
+            return maybe_update_locale(entry, locale);
         }
 
         // und_scripts
         if locale.script.is_some() {
             key.language = subtags::Language::default();
             key.script = locale.script;
-            key.region = None;
-            if let Ok(index) = self
-                .likely_subtags
-                .entries
-                .binary_search_by_key(&&key, |(l, _)| l)
-            {
-                return maybe_update_locale(index, locale);
+            if let Some(entry) = self.likely_subtags_for(&key, &mut trail) {
+                return maybe_update_locale(entry, locale);
+            }
+        }
+
+        // None of the lookups of the specification found likely subtags.
+        match self.unknown_language {
+            UnknownLanguage::LeaveUnchanged => CanonicalizationResult::MissingData,
+            UnknownLanguage::UndFallback => {
+                let mut und: Locale = LanguageIdentifier {
+                    language: subtags::Language::default(),
+                    script: locale.script,
+                    region: locale.region,
+                    variants: subtags::Variants::default(),
+                }
+                .into();
+                match self.maximize_traced(&mut und, trail) {
+                    CanonicalizationResult::Modified => maybe_update_locale(&und.into(), locale),
+                    _ => CanonicalizationResult::MissingData,
+                }
+            }
+        }
+    }
+
+    /// Parses the `input` locale, unless it has more variants than allowed.
+    pub(crate) fn parse(&self, input: &str) -> Result<Locale, LocaleCanonicalizerError> {
+        self.check_variants(input)?;
+        Ok(input.parse()?)
+    }
+
+    /// Counts the variants of the `input` locale without parsing it, which are the subtags
+    /// preceding the extensions and shaped like variants.
+    fn check_variants(&self, input: &str) -> Result<(), LocaleCanonicalizerError> {
+        let variants = input
+            .split(['-', '_'])
+            .skip(1)
+            .take_while(|subtag| subtag.len() != 1)
+            .filter(|subtag| {
+                subtag.len() >= 5
+                    || (subtag.len() == 4 && subtag.starts_with(|c: char| c.is_ascii_digit()))
+            })
+            .count();
+        if variants > self.max_variants {
+            Err(LocaleCanonicalizerError::TooManyVariants {
+                max: self.max_variants,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Looks up the likely subtags of the `key`, recording their data key in the `trail`.
+    fn likely_subtags_for(
+        &self,
+        key: &LanguageIdentifier,
+        trail: &mut Option<&mut Vec<ResourceKey>>,
+    ) -> Option<&LanguageIdentifier> {
+        if let Some(trail) = trail {
+            if !trail.contains(&key::LIKELY_SUBTAGS_V1) {
+                trail.push(key::LIKELY_SUBTAGS_V1);
+            }
+        }
+        self.likely_subtags
+            .entries
+            .binary_search_by_key(&key, |(l, _)| l)
+            .ok()
+            .map(|index| &self.likely_subtags.entries[index].1)
+    }
+
+    /// Runs the steps of the canonicalizer on the `input` one at a time, and returns the name
+    /// of each step along with the locale it resulted in, for debugging and teaching.
+    ///
+    /// The steps are:
+    ///
+    /// 1. `parse`, which results in the canonical syntax of the locale, with normalized case
+    ///    and sorted extensions;
+    /// 2. `maximize`, which adds the likely subtags with [`LocaleCanonicalizer::maximize`];
+    /// 3. `minimize`, which removes them with [`LocaleCanonicalizer::minimize`].
+    ///
+    /// Each step is listed even when it leaves the locale unchanged. No alias replacement is
+    /// performed yet. An input which fails to parse, or has more variants than
+    /// [`LocaleCanonicalizer::with_max_variants`] allows, returns the
+    /// [`LocaleCanonicalizerError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// let trace = lc.canonicalize_trace("EN-us").unwrap();
+    /// let steps: Vec<(&str, String)> = trace
+    ///     .iter()
+    ///     .map(|(step, locale)| (step.as_str(), locale.to_string()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     steps,
+    ///     vec![
+    ///         ("parse", "en-US".to_string()),
+    ///         ("maximize", "en-Latn-US".to_string()),
+    ///         ("minimize", "en".to_string()),
+    ///     ]
+    /// );
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn canonicalize_trace(
+        &self,
+        input: &str,
+    ) -> Result<Vec<(String, Locale)>, LocaleCanonicalizerError> {
+        let mut locale = self.parse(input)?;
+        let mut trace = vec![("parse".to_string(), locale.clone())];
+        self.maximize(&mut locale);
+        trace.push(("maximize".to_string(), locale.clone()));
+        self.minimize(&mut locale);
+        trace.push(("minimize".to_string(), locale));
+        Ok(trace)
+    }
+
+    /// Returns the canonical form of each distinct locale string of `inputs`, canonicalizing
+    /// each of them only once, which saves work on lists with many repeats.
+    ///
+    /// The canonical form is the result of [`LocaleCanonicalizer::canonicalize_trace`]: the
+    /// canonical syntax of the locale, with normalized case and sorted extensions, minimized
+    /// with [`LocaleCanonicalizer::minimize`]. The case of the private-use subtags may be
+    /// preserved with [`LocaleCanonicalizer::with_private_use_case_folding`]. The inputs which
+    /// fail to parse, or have more variants than [`LocaleCanonicalizer::with_max_variants`]
+    /// allows, are left out of the map, and are only parsed once as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// let inputs = vec!["EN-latn-us".to_string(), "en-GB".to_string(), "EN-latn-us".to_string()];
+    /// let canonical = lc.canonicalize_dedup(&inputs);
+    /// assert_eq!(canonical.len(), 2);
+    /// assert_eq!(canonical["EN-latn-us"], "en");
+    /// assert_eq!(canonical["en-GB"], "en-GB");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn canonicalize_dedup(&self, inputs: &[String]) -> HashMap<String, String> {
+        dedup(inputs, |input| {
+            let mut locale = self.parse(input).ok()?;
+            self.minimize(&mut locale);
+            let canonical = locale.to_string();
+            if self.fold_private_use_case {
+                Some(canonical)
+            } else {
+                Some(restore_private_use_case(canonical, input))
             }
+        })
+    }
+
+    /// Checks that `input` is a well-formed locale of a known language, reporting every
+    /// problem instead of stopping at the first one like [`Locale`]'s parser, without
+    /// producing a locale.
+    ///
+    /// The subtags are classified by [`Locale::validate_bytes`], as the parser does, so that
+    /// `en-1X` reports an invalid region. The extensions are checked as a whole, which
+    /// reports at most one error for them.
+    ///
+    /// A well-formed language which has no likely subtags in the data of the canonicalizer is
+    /// reported as [`ParserError::InvalidLanguage`], except for the undetermined language
+    /// `und` and the private-use languages `qaa` to `qtz`.
+    ///
+    /// *Note:* The scripts, regions and variants are only checked for being well-formed,
+    /// since the data has no registry of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    /// use icu_locid::{InvalidSubtagReason, ParserError, SubtagKind};
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// assert_eq!(lc.validate("en-Latn-US-u-ca-buddhist"), Ok(()));
+    /// assert_eq!(
+    ///     lc.validate("en-1X"),
+    ///     Err(vec![ParserError::InvalidSubtag {
+    ///         subtag: SubtagKind::Region,
+    ///         reason: InvalidSubtagReason::Characters,
+    ///     }])
+    /// );
+    /// assert_eq!(lc.validate("xzz-US"), Err(vec![ParserError::InvalidLanguage]));
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn validate(&self, input: &str) -> Result<(), Vec<ParserError>> {
+        let mut errors = Locale::validate_bytes(input.as_bytes())
+            .err()
+            .unwrap_or_default();
+        let language = input
+            .split(['-', '_'])
+            .next()
+            .and_then(|subtag| subtag.parse::<subtags::Language>().ok());
+        if let Some(language) = language {
+            if !self.is_known_language(language) {
+                errors.insert(0, ParserError::InvalidLanguage);
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
-        CanonicalizationResult::Unmodified
+    }
+
+    /// Returns `true` if the `language` has likely subtags, or doesn't need any.
+    fn is_known_language(&self, language: subtags::Language) -> bool {
+        let private_use =
+            language.as_str().len() == 3 && ("qaa"..="qtz").contains(&language.as_str());
+        let key = LanguageIdentifier {
+            language,
+            script: None,
+            region: None,
+            variants: subtags::Variants::default(),
+        };
+        language.is_empty() || private_use || self.likely_subtags_for(&key, &mut None).is_some()
+    }
+
+    /// Returns a copy of the locale argument maximized by [`LocaleCanonicalizer::maximize`],
+    /// along with the result of the operation, leaving the argument unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// let locale : Locale = "en-US".parse().unwrap();
+    /// let (maximized, result) = lc.maximized(&locale);
+    /// assert_eq!(result, CanonicalizationResult::Modified);
+    /// assert_eq!(maximized.to_string(), "en-Latn-US");
+    /// assert_eq!(locale.to_string(), "en-US");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn maximized(&self, locale: &Locale) -> (Locale, CanonicalizationResult) {
+        let mut locale = locale.clone();
+        let result = self.maximize(&mut locale);
+        (locale, result)
     }
 
     /// This returns a new Locale that is the result of running the
@@ -179,14 +608,32 @@ impl LocaleCanonicalizer<'_> {
     /// # } // feature = "provider_serde"
     /// ```
     pub fn minimize(&self, locale: &mut Locale) -> CanonicalizationResult {
+        self.minimize_traced(locale, None)
+    }
+
+    /// Runs [`LocaleCanonicalizer::minimize`] on the locale argument, and records in `trail`
+    /// the key of each data it consulted, like [`LocaleCanonicalizer::maximize_with_trail`].
+    pub fn minimize_with_trail(
+        &self,
+        locale: &mut Locale,
+        trail: &mut Vec<ResourceKey>,
+    ) -> CanonicalizationResult {
+        self.minimize_traced(locale, Some(trail))
+    }
+
+    fn minimize_traced(
+        &self,
+        locale: &mut Locale,
+        mut trail: Option<&mut Vec<ResourceKey>>,
+    ) -> CanonicalizationResult {
         let mut max = locale.clone();
-        self.maximize(&mut max);
+        self.maximize_traced(&mut max, trail.as_deref_mut());
         max.variants.clear();
         let mut trial = max.clone();
 
         trial.script = None;
         trial.region = None;
-        self.maximize(&mut trial);
+        self.maximize_traced(&mut trial, trail.as_deref_mut());
         if trial == max {
             if locale.script.is_some() || locale.script.is_some() {
                 locale.script = None;
@@ -199,7 +646,7 @@ impl LocaleCanonicalizer<'_> {
 
         trial.script = None;
         trial.region = max.region;
-        self.maximize(&mut trial);
+        self.maximize_traced(&mut trial, trail.as_deref_mut());
         if trial == max {
             if locale.script.is_some() || locale.region != max.region {
                 locale.script = None;
@@ -212,7 +659,7 @@ impl LocaleCanonicalizer<'_> {
 
         trial.script = max.script;
         trial.region = None;
-        self.maximize(&mut trial);
+        self.maximize_traced(&mut trial, trail);
         if trial == max {
             if locale.script != max.script || locale.region.is_some() {
                 locale.script = max.script;
@@ -231,4 +678,90 @@ impl LocaleCanonicalizer<'_> {
             CanonicalizationResult::Unmodified
         }
     }
+
+    /// Returns a copy of the locale argument minimized by [`LocaleCanonicalizer::minimize`],
+    /// along with the result of the operation, leaving the argument unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "provider_serde")] {
+    /// use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    ///
+    /// let locale : Locale = "en-Latn-US".parse().unwrap();
+    /// let (minimized, result) = lc.minimized(&locale);
+    /// assert_eq!(result, CanonicalizationResult::Modified);
+    /// assert_eq!(minimized.to_string(), "en");
+    /// assert_eq!(locale.to_string(), "en-Latn-US");
+    /// # } // feature = "provider_serde"
+    /// ```
+    pub fn minimized(&self, locale: &Locale) -> (Locale, CanonicalizationResult) {
+        let mut locale = locale.clone();
+        let result = self.minimize(&mut locale);
+        (locale, result)
+    }
+}
+
+/// Maps each distinct string of `inputs` to its `canonicalize`d form, calling `canonicalize`
+/// once for each of them, including the ones it fails on, which are left out of the map.
+fn dedup(
+    inputs: &[String],
+    mut canonicalize: impl FnMut(&str) -> Option<String>,
+) -> HashMap<String, String> {
+    let mut results: HashMap<&str, Option<String>> = HashMap::new();
+    for input in inputs {
+        results.entry(input).or_insert_with(|| canonicalize(input));
+    }
+    results
+        .into_iter()
+        .filter_map(|(input, canonical)| Some((input.to_string(), canonical?)))
+        .collect()
+}
+
+/// Replaces the private-use subtags of the `canonical` form of the `input` with those of
+/// the `input`, keeping their original case.
+fn restore_private_use_case(canonical: String, input: &str) -> String {
+    let original: Vec<&str> = input
+        .split(['-', '_'])
+        .skip_while(|subtag| !subtag.eq_ignore_ascii_case("x"))
+        .skip(1)
+        .collect();
+    match canonical.find("-x-") {
+        Some(idx) if !original.is_empty() => {
+            format!("{}-x-{}", &canonical[..idx], original.join("-"))
+        }
+        _ => canonical,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedup() {
+        let inputs: Vec<String> = ["en-us", "EN-US", "en-us", "en-$", "EN-US", "en-$"]
+            .iter()
+            .map(|input| input.to_string())
+            .collect();
+        let mut calls = vec![];
+        let canonical = dedup(&inputs, |input| {
+            calls.push(input.to_string());
+            input
+                .parse::<Locale>()
+                .ok()
+                .map(|locale| locale.to_string())
+        });
+
+        // Each distinct input is canonicalized once, even if it fails.
+        calls.sort();
+        assert_eq!(calls, vec!["EN-US", "en-$", "en-us"]);
+        assert_eq!(canonical.len(), 2);
+        assert_eq!(canonical["en-us"], "en-US");
+        assert_eq!(canonical["EN-US"], "en-US");
+    }
 }
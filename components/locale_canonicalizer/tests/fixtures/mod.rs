@@ -4,6 +4,7 @@
 use serde::Deserialize;
 
 #[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct LikelySubtagsTest {
     pub input: String,
     pub output: String,
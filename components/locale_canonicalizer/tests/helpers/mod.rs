@@ -2,13 +2,36 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 use std::fs::File;
-use std::io::{BufReader, Error};
+use std::io::{BufReader, Error, ErrorKind, Read};
 
-pub fn read_fixture<T>(path: &str) -> Result<T, Error>
+/// Reads the array of test cases of the fixture at `path`.
+pub fn read_fixture<T>(path: &str) -> Result<Vec<T>, Error>
 where
     T: serde::de::DeserializeOwned,
 {
     let file = File::open(path)?;
-    let reader = BufReader::new(file);
-    Ok(serde_json::from_reader(reader)?)
+    parse_fixture(path, BufReader::new(file))
+}
+
+/// Parses an array of test cases, reporting the `path` and the index of the first entry
+/// which fails to deserialize.
+pub fn parse_fixture<T, R>(path: &str, reader: R) -> Result<Vec<T>, Error>
+where
+    T: serde::de::DeserializeOwned,
+    R: Read,
+{
+    let entries: Vec<serde_json::Value> = serde_json::from_reader(reader)
+        .map_err(|err| Error::new(ErrorKind::InvalidData, format!("{}: {}", path, err)))?;
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            serde_json::from_value(entry).map_err(|err| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}: entry {}: {}", path, index, err),
+                )
+            })
+        })
+        .collect()
 }
@@ -4,8 +4,33 @@
 mod fixtures;
 mod helpers;
 
-use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
-use icu_locid::Locale;
+use icu_locale_canonicalizer::provider::key;
+use icu_locale_canonicalizer::{
+    CachingLocaleCanonicalizer, CanonicalizationResult, LocaleCanonicalizer,
+    LocaleCanonicalizerError, UnknownLanguage,
+};
+use icu_locid::{InvalidSubtagReason, Locale, ParserError, SubtagKind};
+
+#[test]
+fn test_malformed_fixture() {
+    let input = r#"[{ "input": "en", "output": "en-Latn-US" }, { "input": "fr" }]"#;
+    let err =
+        helpers::parse_fixture::<fixtures::LikelySubtagsTest, _>("broken.json", input.as_bytes())
+            .err()
+            .expect("Failed to report a missing field");
+    let message = err.to_string();
+    assert!(message.starts_with("broken.json: entry 1: "), "{}", message);
+    assert!(message.contains("missing field `output`"), "{}", message);
+
+    let input = r#"[{ "input": "en", "output": "en-Latn-US", "outptu": "en" }]"#;
+    let err =
+        helpers::parse_fixture::<fixtures::LikelySubtagsTest, _>("broken.json", input.as_bytes())
+            .err()
+            .expect("Failed to report an unknown field");
+    let message = err.to_string();
+    assert!(message.starts_with("broken.json: entry 0: "), "{}", message);
+    assert!(message.contains("unknown field `outptu`"), "{}", message);
+}
 
 #[test]
 fn test_maximize() {
@@ -29,6 +54,64 @@ fn test_maximize() {
     }
 }
 
+#[test]
+fn test_maximize_with_default_locale() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider)
+        .unwrap()
+        .with_default_locale("fr-Latn-FR".parse().unwrap());
+
+    let testcases = &[
+        ("und", "fr-Latn-FR"),
+        ("und-u-ca-gregory", "fr-Latn-FR-u-ca-gregory"),
+        ("und-Cyrl", "ru-Cyrl-RU"),
+        ("en", "en-Latn-US"),
+    ];
+    for (input, output) in testcases {
+        let mut locale: Locale = input.parse().unwrap();
+        assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Modified);
+        assert_eq!(locale.to_string(), *output);
+    }
+}
+
+#[test]
+fn test_maximize_unknown_language() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    // A made-up language, and one reserved for private use.
+    for input in &["qqq", "qaa-FR", "qqq-Latn-US-u-ca-gregory"] {
+        let mut locale: Locale = input.parse().unwrap();
+        assert_eq!(
+            lc.maximize(&mut locale),
+            CanonicalizationResult::MissingData
+        );
+        assert_eq!(locale.to_string(), *input);
+    }
+
+    // The likely subtags of the script are found before falling back.
+    let mut locale: Locale = "qaa-Cyrl".parse().unwrap();
+    assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Modified);
+    assert_eq!(locale.to_string(), "qaa-Cyrl-RU");
+
+    let lc = lc.with_unknown_language(UnknownLanguage::UndFallback);
+    let testcases = &[
+        ("qqq", "qqq-Latn-US", CanonicalizationResult::Modified),
+        ("qaa-Cyrl", "qaa-Cyrl-RU", CanonicalizationResult::Modified),
+        ("qqq-FR", "qqq-Latn-FR", CanonicalizationResult::Modified),
+        (
+            "qqq-Latn-US-u-ca-gregory",
+            "qqq-Latn-US-u-ca-gregory",
+            CanonicalizationResult::Unmodified,
+        ),
+    ];
+    for (input, output, result) in testcases {
+        let mut locale: Locale = input.parse().unwrap();
+        assert_eq!(lc.maximize(&mut locale), *result, "{}", input);
+        assert_eq!(locale.to_string(), *output);
+    }
+}
+
 #[test]
 fn test_minimize() {
     let provider = icu_testdata::get_provider();
@@ -50,3 +133,311 @@ fn test_minimize() {
         }
     }
 }
+
+#[test]
+fn test_maximized_minimized() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    let locale: Locale = "zh-TW-u-ca-chinese".parse().unwrap();
+    let unmodified = locale.clone();
+    let (maximized, result) = lc.maximized(&locale);
+    assert_eq!(result, CanonicalizationResult::Modified);
+    assert_eq!(maximized.to_string(), "zh-Hant-TW-u-ca-chinese");
+    assert_eq!(locale, unmodified);
+
+    let (minimized, result) = lc.minimized(&maximized);
+    assert_eq!(result, CanonicalizationResult::Modified);
+    assert_eq!(minimized.to_string(), "zh-TW-u-ca-chinese");
+    assert_eq!(maximized.to_string(), "zh-Hant-TW-u-ca-chinese");
+
+    let (same, result) = lc.maximized(&maximized);
+    assert_eq!(result, CanonicalizationResult::Unmodified);
+    assert_eq!(same, maximized);
+}
+
+#[test]
+fn test_caching_canonicalizer() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    let clc = CachingLocaleCanonicalizer::new(lc, 2);
+    assert!(clc.is_empty());
+
+    // A miss populates the cache.
+    let first = clc.maximize("en-US").unwrap();
+    assert_eq!(first.to_string(), "en-Latn-US");
+    assert_eq!(clc.len(), 1);
+
+    // A hit returns the identical result without growing the cache.
+    assert_eq!(clc.maximize("en-US").unwrap(), first);
+    assert_eq!(clc.len(), 1);
+
+    // Each operation has its own cache.
+    assert_eq!(clc.minimize("en-Latn-US").unwrap().to_string(), "en");
+    assert_eq!(clc.len(), 2);
+
+    // The least recently used entry is evicted once full.
+    assert_eq!(clc.maximize("zh-TW").unwrap().to_string(), "zh-Hant-TW");
+    assert_eq!(clc.maximize("sr").unwrap().to_string(), "sr-Cyrl-RS");
+    assert_eq!(clc.len(), 3);
+    assert_eq!(clc.maximize("en-US").unwrap(), first);
+    assert_eq!(clc.len(), 3);
+
+    // Invalid inputs are not cached.
+    assert!(clc.maximize("en-$").is_err());
+    assert_eq!(clc.len(), 3);
+
+    clc.clear();
+    assert!(clc.is_empty());
+}
+
+#[test]
+fn test_transform_extension() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    let clc = CachingLocaleCanonicalizer::new(lc, 4);
+
+    // The tlang and the tfields are case-normalized and the tfields are sorted by key,
+    // and neither is affected by the canonicalization of the language identifier.
+    let samples = &[
+        ("EN-t-DE", "en-Latn-US-t-de", "en-t-de"),
+        (
+            "en-US-T-DE-latn-at-M0-UNGEGN-h0-Hybrid",
+            "en-Latn-US-t-de-Latn-AT-h0-hybrid-m0-ungegn",
+            "en-t-de-Latn-AT-h0-hybrid-m0-ungegn",
+        ),
+        (
+            "und-t-s0-ASCII-d0-Fwidth",
+            "en-Latn-US-t-d0-fwidth-s0-ascii",
+            "en-t-d0-fwidth-s0-ascii",
+        ),
+    ];
+    for (input, maximized, minimized) in samples {
+        assert_eq!(clc.maximize(input).unwrap().to_string(), *maximized);
+        assert_eq!(clc.minimize(maximized).unwrap().to_string(), *minimized);
+    }
+}
+
+#[test]
+fn test_data_key_trail() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    // Each key is recorded once, however many lookups it took.
+    let mut trail = vec![];
+    let mut locale: Locale = "zh-TW".parse().unwrap();
+    assert_eq!(
+        lc.maximize_with_trail(&mut locale, &mut trail),
+        CanonicalizationResult::Modified
+    );
+    assert_eq!(locale.to_string(), "zh-Hant-TW");
+    assert_eq!(trail, vec![key::LIKELY_SUBTAGS_V1]);
+
+    let mut locale: Locale = "zh-Hant-TW".parse().unwrap();
+    assert_eq!(
+        lc.minimize_with_trail(&mut locale, &mut trail),
+        CanonicalizationResult::Modified
+    );
+    assert_eq!(locale.to_string(), "zh-TW");
+    assert_eq!(trail, vec![key::LIKELY_SUBTAGS_V1]);
+
+    // The default locale replaces the data for fully undetermined input.
+    let lc = lc.with_default_locale("de-Latn-DE".parse().unwrap());
+    let mut trail = vec![];
+    let mut locale: Locale = "und".parse().unwrap();
+    assert_eq!(
+        lc.maximize_with_trail(&mut locale, &mut trail),
+        CanonicalizationResult::Modified
+    );
+    assert_eq!(locale.to_string(), "de-Latn-DE");
+    assert!(trail.is_empty());
+}
+
+#[test]
+fn test_canonicalize_trace() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    let testcases: &[(&str, &[(&str, &str)])] = &[
+        (
+            "ZH_tw-U-CA-buddhist",
+            &[
+                ("parse", "zh-TW-u-ca-buddhist"),
+                ("maximize", "zh-Hant-TW-u-ca-buddhist"),
+                ("minimize", "zh-TW-u-ca-buddhist"),
+            ],
+        ),
+        (
+            "sr-cyrl",
+            &[
+                ("parse", "sr-Cyrl"),
+                ("maximize", "sr-Cyrl-RS"),
+                ("minimize", "sr"),
+            ],
+        ),
+    ];
+    for (input, expected) in testcases {
+        let trace: Vec<(String, String)> = lc
+            .canonicalize_trace(input)
+            .unwrap()
+            .into_iter()
+            .map(|(step, locale)| (step, locale.to_string()))
+            .collect();
+        let expected: Vec<(String, String)> = expected
+            .iter()
+            .map(|(step, locale)| (step.to_string(), locale.to_string()))
+            .collect();
+        assert_eq!(trace, expected, "{}", input);
+    }
+
+    assert!(lc.canonicalize_trace("en-1X").is_err());
+}
+
+#[test]
+fn test_canonicalize_dedup() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    let inputs: Vec<String> = [
+        "en-gb",
+        "EN-GB",
+        "en-gb",
+        "de-Latn-DE-t-M0-ungegn",
+        "en-Latn-US",
+        "en-$",
+        "EN-GB",
+        "en-$",
+    ]
+    .iter()
+    .map(|input| input.to_string())
+    .collect();
+    let canonical = lc.canonicalize_dedup(&inputs);
+
+    // One entry per distinct valid input, minimized.
+    assert_eq!(canonical.len(), 4);
+    assert_eq!(canonical["en-gb"], "en-GB");
+    assert_eq!(canonical["EN-GB"], "en-GB");
+    assert_eq!(canonical["de-Latn-DE-t-M0-ungegn"], "de-t-m0-ungegn");
+    assert_eq!(canonical["en-Latn-US"], "en");
+    assert!(!canonical.contains_key("en-$"));
+}
+
+#[test]
+fn test_validate() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    for input in &[
+        "en",
+        "en-US",
+        "sr-Cyrl-RS-1996-u-ca-buddhist",
+        "de-t-m0-ungegn",
+        "und-Latn",
+        "qaa-x-private",
+    ] {
+        assert_eq!(lc.validate(input), Ok(()), "{}", input);
+    }
+
+    // Both a region with invalid characters and a variant which is too long.
+    assert_eq!(
+        lc.validate("en-Latn-1X-abcdefghi"),
+        Err(vec![
+            ParserError::InvalidSubtag {
+                subtag: SubtagKind::Region,
+                reason: InvalidSubtagReason::Characters,
+            },
+            ParserError::InvalidSubtag {
+                subtag: SubtagKind::Variant,
+                reason: InvalidSubtagReason::Length,
+            },
+        ])
+    );
+
+    let errors = lc.validate("e-Lat$-u-ca").unwrap_err();
+    assert_eq!(errors.len(), 2, "{:?}", errors);
+    assert_eq!(
+        errors[1],
+        ParserError::InvalidSubtag {
+            subtag: SubtagKind::Script,
+            reason: InvalidSubtagReason::Characters,
+        }
+    );
+
+    assert_eq!(
+        lc.validate("en-US-u-$"),
+        Err(vec![ParserError::InvalidExtension])
+    );
+
+    // A language which isn't in the data, along with an invalid region.
+    assert_eq!(
+        lc.validate("xzz-1X"),
+        Err(vec![
+            ParserError::InvalidLanguage,
+            ParserError::InvalidSubtag {
+                subtag: SubtagKind::Region,
+                reason: InvalidSubtagReason::Characters,
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_private_use_case_folding() {
+    let provider = icu_testdata::get_provider();
+    let inputs: Vec<String> = ["en-x-FooBar", "EN_us_u_CA_buddhist_X_FooBar_BAZ", "en-US"]
+        .iter()
+        .map(|input| input.to_string())
+        .collect();
+
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    let canonical = lc.canonicalize_dedup(&inputs);
+    assert_eq!(canonical["en-x-FooBar"], "en-x-foobar");
+    assert_eq!(
+        canonical["EN_us_u_CA_buddhist_X_FooBar_BAZ"],
+        "en-US-u-ca-buddhist-x-foobar-baz"
+    );
+
+    let lc = LocaleCanonicalizer::new(&provider)
+        .unwrap()
+        .with_private_use_case_folding(false);
+    let canonical = lc.canonicalize_dedup(&inputs);
+    assert_eq!(canonical["en-x-FooBar"], "en-x-FooBar");
+    assert_eq!(
+        canonical["EN_us_u_CA_buddhist_X_FooBar_BAZ"],
+        "en-US-u-ca-buddhist-x-FooBar-BAZ"
+    );
+    assert_eq!(canonical["en-US"], "en-US");
+
+    // A `Locale` always stores its private-use subtags lowercased.
+    let trace = lc.canonicalize_trace("en-x-FooBar").unwrap();
+    assert_eq!(trace.last().unwrap().1.to_string(), "en-x-foobar");
+}
+
+#[test]
+fn test_max_variants() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+    let excessive = format!("en{}", "-fonipa".repeat(1000));
+    let max = LocaleCanonicalizer::DEFAULT_MAX_VARIANTS;
+    assert_eq!(
+        lc.canonicalize_trace(&excessive),
+        Err(LocaleCanonicalizerError::TooManyVariants { max })
+    );
+    assert!(lc.canonicalize_dedup(&[excessive]).is_empty());
+
+    // The extensions are not counted as variants.
+    let lc = lc.with_max_variants(1);
+    assert!(lc
+        .canonicalize_trace("ca-ES-valencia-u-ca-buddhist-x-abcdefgh")
+        .is_ok());
+    assert_eq!(
+        lc.canonicalize_trace("sl-rozaj-biske"),
+        Err(LocaleCanonicalizerError::TooManyVariants { max: 1 })
+    );
+
+    let caching = CachingLocaleCanonicalizer::new(lc, 4);
+    assert_eq!(
+        caching.maximize("sl-rozaj-biske"),
+        Err(LocaleCanonicalizerError::TooManyVariants { max: 1 })
+    );
+}
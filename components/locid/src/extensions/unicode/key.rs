@@ -49,7 +49,7 @@ impl Key {
             return Err(ParserError::InvalidExtension);
         }
 
-        let key = TinyStr4::from_bytes(key).map_err(|_| ParserError::InvalidSubtag)?;
+        let key = TinyStr4::from_bytes(key).map_err(|_| ParserError::InvalidExtension)?;
         Ok(Self(key.to_ascii_lowercase()))
     }
 
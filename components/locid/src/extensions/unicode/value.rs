@@ -74,7 +74,7 @@ impl Value {
     }
 
     pub(crate) fn parse_subtag(t: &[u8]) -> Result<Option<TinyStr8>, ParserError> {
-        let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
+        let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidExtension)?;
         if !VALUE_LENGTH.contains(&t.len()) || !s.is_ascii_alphanumeric() {
             return Err(ParserError::InvalidExtension);
         }
@@ -62,4 +62,4 @@ pub mod subtags;
 
 pub use langid::LanguageIdentifier;
 pub use locale::Locale;
-pub use parser::errors::ParserError;
+pub use parser::errors::{InvalidSubtagReason, ParserError, SubtagKind};
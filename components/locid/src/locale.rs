@@ -1,7 +1,7 @@
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
-use crate::parser::{get_subtag_iterator, parse_locale, ParserError};
+use crate::parser::{get_subtag_iterator, parse_locale, validate_locale, ParserError};
 use crate::{extensions, subtags, LanguageIdentifier};
 use std::str::FromStr;
 
@@ -96,6 +96,38 @@ impl Locale {
         parse_locale(v)
     }
 
+    /// Checks that a utf8 slice is a well-formed `Locale`, like [`Locale::from_bytes`], but
+    /// reports every invalid subtag instead of stopping at the first one.
+    ///
+    /// Each subtag of the language identifier is reported as the kind of subtag expected at
+    /// its position, like [`Locale::from_bytes`] does, and the parser goes on as if it were
+    /// of that kind. The extensions are checked as a whole, which reports at most one error
+    /// for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locid::{InvalidSubtagReason, Locale, ParserError, SubtagKind};
+    ///
+    /// assert_eq!(Locale::validate_bytes(b"en-Latn-US-u-ca-buddhist"), Ok(()));
+    /// assert_eq!(
+    ///     Locale::validate_bytes(b"en-1X-abcdefghi"),
+    ///     Err(vec![
+    ///         ParserError::InvalidSubtag {
+    ///             subtag: SubtagKind::Region,
+    ///             reason: InvalidSubtagReason::Characters,
+    ///         },
+    ///         ParserError::InvalidSubtag {
+    ///             subtag: SubtagKind::Variant,
+    ///             reason: InvalidSubtagReason::Length,
+    ///         },
+    ///     ])
+    /// );
+    /// ```
+    pub fn validate_bytes(v: &[u8]) -> Result<(), Vec<ParserError>> {
+        validate_locale(v)
+    }
+
     /// Returns the default undefined locale "und". Same as `Default`, but is `const`.
     ///
     /// # Example
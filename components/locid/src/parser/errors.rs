@@ -9,7 +9,7 @@ use std::fmt::{self, Display};
 ///
 #[derive(Debug, PartialEq)]
 pub enum ParserError {
-    /// Invalid language subtag.
+    /// Invalid language subtag, such as one containing digits, or missing language subtag.
     ///
     /// # Examples
     ///
@@ -23,19 +23,48 @@ pub enum ParserError {
     /// ```
     InvalidLanguage,
 
-    /// Invalid script, region or variant subtag.
+    /// Invalid script, region or variant subtag, or language subtag of an invalid length.
+    ///
+    /// A subtag which is neither a valid script, region nor variant while parsing a
+    /// [`LanguageIdentifier`](crate::LanguageIdentifier) is reported for the kind allowed at
+    /// its position which has its length, such as a region for `pl-Latn-12`. Its length is
+    /// only reported as invalid if none of the kinds allowed at its position accepts it, for
+    /// the kind closest to its length.
     ///
     /// # Examples
     ///
     /// ```
     /// use std::str::FromStr;
     ///
-    /// use icu_locid::subtags::Region;
-    /// use icu_locid::ParserError;
+    /// use icu_locid::subtags::{Language, Region};
+    /// use icu_locid::{InvalidSubtagReason, ParserError, SubtagKind};
     ///
-    /// assert_eq!(Region::from_str("#@2X"), Err(ParserError::InvalidSubtag));
+    /// assert_eq!(
+    ///     Region::from_str("#@2X"),
+    ///     Err(ParserError::InvalidSubtag {
+    ///         subtag: SubtagKind::Region,
+    ///         reason: InvalidSubtagReason::Length,
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Region::from_str("#@2"),
+    ///     Err(ParserError::InvalidSubtag {
+    ///         subtag: SubtagKind::Region,
+    ///         reason: InvalidSubtagReason::Characters,
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     Language::from_str("e"),
+    ///     Err(ParserError::InvalidSubtag {
+    ///         subtag: SubtagKind::Language,
+    ///         reason: InvalidSubtagReason::Length,
+    ///     })
+    /// );
     /// ```
-    InvalidSubtag,
+    InvalidSubtag {
+        subtag: SubtagKind,
+        reason: InvalidSubtagReason,
+    },
 
     /// Invalid extension subtag.
     ///
@@ -52,13 +81,59 @@ pub enum ParserError {
     InvalidExtension,
 }
 
+/// The kind of subtag reported by [`ParserError::InvalidSubtag`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SubtagKind {
+    Language,
+    Script,
+    Region,
+    Variant,
+}
+
+/// The reason why a subtag is reported by [`ParserError::InvalidSubtag`].
+///
+/// *Note:* Subtags are only checked for being well-formed, unknown or deprecated
+/// subtags are not errors.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InvalidSubtagReason {
+    /// The subtag is too long or too short for its kind.
+    Length,
+    /// The subtag contains characters which are not allowed for its kind.
+    Characters,
+}
+
+impl ParserError {
+    pub(crate) const fn invalid_subtag(subtag: SubtagKind, reason: InvalidSubtagReason) -> Self {
+        Self::InvalidSubtag { subtag, reason }
+    }
+}
+
 impl Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let value = match self {
             Self::InvalidLanguage => "The given language subtag is invalid",
-            Self::InvalidSubtag => "Invalid subtag",
+            Self::InvalidSubtag { subtag, reason } => {
+                let subtag = match subtag {
+                    SubtagKind::Language => "language",
+                    SubtagKind::Script => "script",
+                    SubtagKind::Region => "region",
+                    SubtagKind::Variant => "variant",
+                };
+                return match reason {
+                    InvalidSubtagReason::Length => {
+                        write!(f, "Invalid subtag: wrong length for a {} subtag", subtag)
+                    }
+                    InvalidSubtagReason::Characters => {
+                        write!(
+                            f,
+                            "Invalid subtag: invalid characters in a {} subtag",
+                            subtag
+                        )
+                    }
+                };
+            }
             Self::InvalidExtension => "Invalid extension",
         };
         f.write_str(value)
@@ -4,6 +4,7 @@
 use std::iter::Peekable;
 
 pub use super::errors::ParserError;
+use crate::parser::errors::{InvalidSubtagReason, SubtagKind};
 use crate::parser::get_subtag_iterator;
 use crate::subtags;
 use crate::LanguageIdentifier;
@@ -22,6 +23,34 @@ enum ParserPosition {
     Variant,
 }
 
+/// Returns the error for a `subtag` which is none of the kinds of subtags allowed at the
+/// `position`. It is reported for the allowed kind whose length the subtag has, with invalid
+/// characters, or else for the allowed kind closest to its length.
+fn invalid_subtag_error(subtag: &[u8], position: ParserPosition) -> ParserError {
+    let (subtag, reason) = invalid_subtag_kind(subtag, position);
+    ParserError::InvalidSubtag { subtag, reason }
+}
+
+fn invalid_subtag_kind(
+    subtag: &[u8],
+    position: ParserPosition,
+) -> (SubtagKind, InvalidSubtagReason) {
+    let starts_with_digit = matches!(subtag.first(), Some(b'0'..=b'9'));
+    match subtag.len() {
+        4 if position == ParserPosition::Script && !starts_with_digit => {
+            (SubtagKind::Script, InvalidSubtagReason::Characters)
+        }
+        2 | 3 if position != ParserPosition::Variant => {
+            (SubtagKind::Region, InvalidSubtagReason::Characters)
+        }
+        4..=8 => (SubtagKind::Variant, InvalidSubtagReason::Characters),
+        0 | 1 if position != ParserPosition::Variant => {
+            (SubtagKind::Region, InvalidSubtagReason::Length)
+        }
+        _ => (SubtagKind::Variant, InvalidSubtagReason::Length),
+    }
+}
+
 pub fn parse_language_identifier_from_iter<'a>(
     iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
     mode: ParserMode,
@@ -59,7 +88,7 @@ pub fn parse_language_identifier_from_iter<'a>(
             } else if mode == ParserMode::Partial {
                 break;
             } else {
-                return Err(ParserError::InvalidSubtag);
+                return Err(invalid_subtag_error(subtag, position));
             }
         } else if position == ParserPosition::Region {
             if let Ok(s) = subtags::Region::from_bytes(subtag) {
@@ -73,7 +102,7 @@ pub fn parse_language_identifier_from_iter<'a>(
             } else if mode == ParserMode::Partial {
                 break;
             } else {
-                return Err(ParserError::InvalidSubtag);
+                return Err(invalid_subtag_error(subtag, position));
             }
         } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
             if let Err(idx) = variants.binary_search(&v) {
@@ -82,7 +111,7 @@ pub fn parse_language_identifier_from_iter<'a>(
         } else if mode == ParserMode::Partial {
             break;
         } else {
-            return Err(ParserError::InvalidSubtag);
+            return Err(invalid_subtag_error(subtag, position));
         }
         iter.next();
     }
@@ -102,3 +131,47 @@ pub fn parse_language_identifier(
     let mut iter = get_subtag_iterator(t).peekable();
     parse_language_identifier_from_iter(&mut iter, mode)
 }
+
+/// Checks the subtags of a language identifier like [`parse_language_identifier_from_iter`]
+/// in [`ParserMode::Locale`], but records every invalid subtag in `errors` instead of
+/// stopping at the first one, going on as if it were of the kind it is reported for.
+pub fn validate_language_identifier_from_iter<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    errors: &mut Vec<ParserError>,
+) {
+    match iter.next() {
+        Some(subtag) => {
+            if let Err(err) = subtags::Language::from_bytes(subtag) {
+                errors.push(err);
+            }
+        }
+        None => errors.push(ParserError::InvalidLanguage),
+    }
+
+    let mut position = ParserPosition::Script;
+    while let Some(subtag) = iter.peek() {
+        if subtag.len() == 1 {
+            break;
+        }
+
+        let kind = if position == ParserPosition::Script
+            && subtags::Script::from_bytes(subtag).is_ok()
+        {
+            SubtagKind::Script
+        } else if position != ParserPosition::Variant && subtags::Region::from_bytes(subtag).is_ok()
+        {
+            SubtagKind::Region
+        } else if subtags::Variant::from_bytes(subtag).is_ok() {
+            SubtagKind::Variant
+        } else {
+            let (subtag, reason) = invalid_subtag_kind(subtag, position);
+            errors.push(ParserError::InvalidSubtag { subtag, reason });
+            subtag
+        };
+        position = match kind {
+            SubtagKind::Script => ParserPosition::Region,
+            _ => ParserPosition::Variant,
+        };
+        iter.next();
+    }
+}
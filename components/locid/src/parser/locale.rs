@@ -3,7 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 use crate::extensions::Extensions;
 use crate::parser::errors::ParserError;
-use crate::parser::{get_subtag_iterator, parse_language_identifier_from_iter, ParserMode};
+use crate::parser::{
+    get_subtag_iterator, parse_language_identifier_from_iter,
+    validate_language_identifier_from_iter, ParserMode,
+};
 use crate::Locale;
 
 pub fn parse_locale(t: &[u8]) -> Result<Locale, ParserError> {
@@ -23,3 +26,20 @@ pub fn parse_locale(t: &[u8]) -> Result<Locale, ParserError> {
         extensions,
     })
 }
+
+pub fn validate_locale(t: &[u8]) -> Result<(), Vec<ParserError>> {
+    let mut iter = get_subtag_iterator(t).peekable();
+
+    let mut errors = vec![];
+    validate_language_identifier_from_iter(&mut iter, &mut errors);
+    if iter.peek().is_some() {
+        if let Err(err) = Extensions::try_from_iter(&mut iter) {
+            errors.push(err);
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
@@ -2,7 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
 use super::script::SCRIPT_LENGTH;
-use crate::parser::errors::ParserError;
+use crate::parser::errors::{InvalidSubtagReason, ParserError, SubtagKind};
 use std::fmt;
 use std::ops::RangeInclusive;
 use std::str::FromStr;
@@ -58,7 +58,10 @@ impl Language {
         let slen = v.len();
 
         if !LANGUAGE_LENGTH.contains(&slen) || slen == SCRIPT_LENGTH {
-            return Err(ParserError::InvalidLanguage);
+            return Err(ParserError::invalid_subtag(
+                SubtagKind::Language,
+                InvalidSubtagReason::Length,
+            ));
         }
 
         let s = TinyStr8::from_bytes(v).map_err(|_| ParserError::InvalidLanguage)?;
@@ -1,7 +1,7 @@
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
-use crate::parser::errors::ParserError;
+use crate::parser::errors::{InvalidSubtagReason, ParserError, SubtagKind};
 use std::str::FromStr;
 use tinystr::TinyStr4;
 
@@ -41,22 +41,29 @@ impl Region {
     /// assert_eq!(region, "FR");
     /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
+        fn invalid_characters() -> ParserError {
+            ParserError::invalid_subtag(SubtagKind::Region, InvalidSubtagReason::Characters)
+        }
+
         match v.len() {
             REGION_ALPHA_LENGTH => {
-                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+                let s = TinyStr4::from_bytes(v).map_err(|_| invalid_characters())?;
                 if !s.is_ascii_alphabetic() {
-                    return Err(ParserError::InvalidSubtag);
+                    return Err(invalid_characters());
                 }
                 Ok(Self(s.to_ascii_uppercase()))
             }
             REGION_NUM_LENGTH => {
-                let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+                let s = TinyStr4::from_bytes(v).map_err(|_| invalid_characters())?;
                 if !s.is_ascii_numeric() {
-                    return Err(ParserError::InvalidSubtag);
+                    return Err(invalid_characters());
                 }
                 Ok(Self(s))
             }
-            _ => Err(ParserError::InvalidSubtag),
+            _ => Err(ParserError::invalid_subtag(
+                SubtagKind::Region,
+                InvalidSubtagReason::Length,
+            )),
         }
     }
 
@@ -1,7 +1,7 @@
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
-use crate::parser::errors::ParserError;
+use crate::parser::errors::{InvalidSubtagReason, ParserError, SubtagKind};
 use std::str::FromStr;
 use tinystr::TinyStr4;
 
@@ -41,12 +41,20 @@ impl Script {
     /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         if v.len() != SCRIPT_LENGTH {
-            return Err(ParserError::InvalidSubtag);
+            return Err(ParserError::invalid_subtag(
+                SubtagKind::Script,
+                InvalidSubtagReason::Length,
+            ));
         }
 
-        let s = TinyStr4::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+        let s = TinyStr4::from_bytes(v).map_err(|_| {
+            ParserError::invalid_subtag(SubtagKind::Script, InvalidSubtagReason::Characters)
+        })?;
         if !s.is_ascii_alphabetic() {
-            return Err(ParserError::InvalidSubtag);
+            return Err(ParserError::invalid_subtag(
+                SubtagKind::Script,
+                InvalidSubtagReason::Characters,
+            ));
         }
         Ok(Self(s.to_ascii_titlecase()))
     }
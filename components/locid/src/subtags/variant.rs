@@ -1,7 +1,7 @@
 // This file is part of ICU4X. For terms of use, please see the file
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/master/LICENSE ).
-use crate::parser::errors::ParserError;
+use crate::parser::errors::{InvalidSubtagReason, ParserError, SubtagKind};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 use tinystr::TinyStr8;
@@ -42,20 +42,31 @@ impl Variant {
     /// assert_eq!(variant, "posix");
     /// ```
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
+        fn invalid_characters() -> ParserError {
+            ParserError::invalid_subtag(SubtagKind::Variant, InvalidSubtagReason::Characters)
+        }
+
         let slen = v.len();
 
         if !VARIANT_LENGTH.contains(&slen) {
-            return Err(ParserError::InvalidSubtag);
+            return Err(ParserError::invalid_subtag(
+                SubtagKind::Variant,
+                InvalidSubtagReason::Length,
+            ));
         }
 
-        let s = TinyStr8::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+        let s = TinyStr8::from_bytes(v).map_err(|_| invalid_characters())?;
 
         if !s.is_ascii_alphanumeric() {
-            return Err(ParserError::InvalidSubtag);
+            return Err(invalid_characters());
         }
 
+        // Variants of four characters must start with a digit, others are too short.
         if slen == VARIANT_NUM_ALPHA_LENGTH && !v[0].is_ascii_digit() {
-            return Err(ParserError::InvalidSubtag);
+            return Err(ParserError::invalid_subtag(
+                SubtagKind::Variant,
+                InvalidSubtagReason::Length,
+            ));
         }
 
         Ok(Self(s.to_ascii_lowercase()))
@@ -8,7 +8,9 @@ use icu_locid::extensions::private;
 use icu_locid::extensions::transform;
 use icu_locid::extensions::unicode;
 use icu_locid::extensions::Extensions;
-use icu_locid::{subtags, LanguageIdentifier, Locale, ParserError};
+use icu_locid::{
+    subtags, InvalidSubtagReason, LanguageIdentifier, Locale, ParserError, SubtagKind,
+};
 use serde::Deserialize;
 
 #[derive(Deserialize, Clone)]
@@ -124,6 +126,8 @@ pub struct LocaleSubtags {
 pub struct LocaleError {
     pub error: String,
     pub text: String,
+    pub subtag: Option<String>,
+    pub reason: Option<String>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -246,7 +250,20 @@ impl From<LocaleError> for ParserError {
     fn from(e: LocaleError) -> Self {
         match e.error.as_str() {
             "InvalidLanguage" => ParserError::InvalidLanguage,
-            "InvalidSubtag" => ParserError::InvalidSubtag,
+            "InvalidSubtag" => ParserError::InvalidSubtag {
+                subtag: match e.subtag.as_deref() {
+                    Some("Language") => SubtagKind::Language,
+                    Some("Script") => SubtagKind::Script,
+                    Some("Region") => SubtagKind::Region,
+                    Some("Variant") => SubtagKind::Variant,
+                    _ => unreachable!("Unknown subtag kind"),
+                },
+                reason: match e.reason.as_deref() {
+                    Some("Length") => InvalidSubtagReason::Length,
+                    Some("Characters") => InvalidSubtagReason::Characters,
+                    _ => unreachable!("Unknown invalid subtag reason"),
+                },
+            },
             "InvalidExtension" => ParserError::InvalidExtension,
             _ => unreachable!("Unknown error name"),
         }
@@ -53,6 +53,35 @@ fn test_langid_invalid() {
     test_langid_fixtures(data);
 }
 
+#[test]
+fn test_locale_validate() {
+    // The first error is the one of the parser.
+    for input in &[
+        "pl-Latn-12",
+        "pl-L4tn",
+        "pl-Latn-1",
+        "en-US-abcdefghi-$",
+        "e-Lat$",
+        "en-US-u-$",
+    ] {
+        let errors = Locale::validate_bytes(input.as_bytes()).unwrap_err();
+        assert_eq!(
+            Err(errors.into_iter().next().unwrap()),
+            Locale::from_bytes(input.as_bytes()),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        Locale::validate_bytes(b"sr-Cyrl-RS-1996-u-ca-buddhist"),
+        Ok(())
+    );
+    assert_eq!(
+        Locale::validate_bytes(b"pl-L4tn-12-abcdefghi").map_err(|errors| errors.len()),
+        Err(3)
+    );
+}
+
 #[test]
 fn test_locale_is_empty() {
     let locale: Locale = Locale::default();
@@ -37,6 +37,20 @@ impl TryFrom<&dyn CldrPaths> for DatesProvider<'_> {
 
             let mut resource: cldr_json::Resource =
                 serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+
+            let path = dir.join("dateFields.json");
+            if path.exists() {
+                let fields: cldr_json::FieldsResource =
+                    serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                for (langid, lang_fields) in fields.main.0 {
+                    if let Some((_, lang_dates)) =
+                        resource.main.0.iter_mut().find(|(l, _)| *l == langid)
+                    {
+                        lang_dates.dates.fields = Some(lang_fields.dates.fields);
+                    }
+                }
+            }
+
             data.append(&mut resource.main.0);
         }
 
@@ -137,6 +151,8 @@ impl From<&cldr_json::Dates> for gregory::DatesV1 {
                 months: (&other.calendars.gregorian.months).into(),
                 weekdays: (&other.calendars.gregorian.days).into(),
                 day_periods: (&other.calendars.gregorian.day_periods).into(),
+                eras: Some((&other.calendars.gregorian.eras).into()),
+                relative_days: other.fields.as_ref().map(|fields| (&fields.day).into()),
             },
             patterns: gregory::PatternsV1 {
                 date: (&other.calendars.gregorian.date_formats).into(),
@@ -147,6 +163,37 @@ impl From<&cldr_json::Dates> for gregory::DatesV1 {
     }
 }
 
+impl From<&cldr_json::Eras> for gregory::eras::ErasV1 {
+    fn from(other: &cldr_json::Eras) -> Self {
+        Self {
+            abbreviated: (&other.abbr).into(),
+            narrow: (&other.narrow).into(),
+            wide: (&other.names).into(),
+        }
+    }
+}
+
+impl From<&cldr_json::EraNames> for gregory::eras::EraNamesV1 {
+    fn from(other: &cldr_json::EraNames) -> Self {
+        Self {
+            bc: other.bc.clone(),
+            ad: other.ad.clone(),
+        }
+    }
+}
+
+impl From<&cldr_json::RelativeDays> for gregory::relative_days::RelativeDaysV1 {
+    fn from(other: &cldr_json::RelativeDays) -> Self {
+        Self {
+            before_yesterday: other.before_yesterday.clone(),
+            yesterday: other.yesterday.clone(),
+            today: other.today.clone(),
+            tomorrow: other.tomorrow.clone(),
+            after_tomorrow: other.after_tomorrow.clone(),
+        }
+    }
+}
+
 macro_rules! symbols_from {
     ([$name: ident, $name2: ident $(,)?], [ $($element: ident),+ $(,)? ] $(,)?) => {
         impl From<&cldr_json::$name::Symbols> for gregory::$name2::SymbolsV1 {
@@ -395,6 +442,7 @@ pub(self) mod cldr_json {
         pub days: days::Contexts,
         #[serde(rename = "dayPeriods")]
         pub day_periods: day_periods::Contexts,
+        pub eras: Eras,
         #[serde(rename = "dateFormats")]
         pub date_formats: StylePatterns,
         #[serde(rename = "timeFormats")]
@@ -403,14 +451,54 @@ pub(self) mod cldr_json {
         pub date_time_formats: StylePatterns,
     }
 
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct EraNames {
+        #[serde(rename = "0")]
+        pub bc: Cow<'static, str>,
+        #[serde(rename = "1")]
+        pub ad: Cow<'static, str>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Eras {
+        #[serde(rename = "eraNames")]
+        pub names: EraNames,
+        #[serde(rename = "eraAbbr")]
+        pub abbr: EraNames,
+        #[serde(rename = "eraNarrow")]
+        pub narrow: EraNames,
+    }
+
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct Calendars {
         pub gregorian: GregoryDates,
     }
 
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RelativeDays {
+        #[serde(rename = "relative-type--2")]
+        pub before_yesterday: Option<Cow<'static, str>>,
+        #[serde(rename = "relative-type--1")]
+        pub yesterday: Cow<'static, str>,
+        #[serde(rename = "relative-type-0")]
+        pub today: Cow<'static, str>,
+        #[serde(rename = "relative-type-1")]
+        pub tomorrow: Cow<'static, str>,
+        #[serde(rename = "relative-type-2")]
+        pub after_tomorrow: Option<Cow<'static, str>>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Fields {
+        pub day: RelativeDays,
+    }
+
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct Dates {
         pub calendars: Calendars,
+        /// Read from `dateFields.json`, which is a separate file in CLDR.
+        #[serde(default)]
+        pub fields: Option<Fields>,
     }
     #[derive(PartialEq, Debug, Deserialize)]
     pub struct LangDates {
@@ -424,6 +512,25 @@ pub(self) mod cldr_json {
     pub struct Resource {
         pub main: LangData,
     }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct FieldsDates {
+        pub fields: Fields,
+    }
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangFields {
+        pub dates: FieldsDates,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct FieldsLangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangFields)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct FieldsResource {
+        pub main: FieldsLangData,
+    }
 }
 
 #[test]
@@ -456,6 +563,11 @@ fn test_basic() {
     );
 
     assert_eq!("d. M. y", cs_dates.patterns.date.medium);
+
+    assert_eq!(
+        "př. n. l.",
+        cs_dates.symbols.eras.as_ref().unwrap().abbreviated.bc
+    );
 }
 
 #[test]